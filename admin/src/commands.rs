@@ -1,6 +1,7 @@
 //! `rustsec-admin` CLI subcommands
 
 mod assign_id;
+mod bundle;
 mod lint;
 mod list_affected_versions;
 mod osv;
@@ -8,8 +9,8 @@ mod version;
 mod web;
 
 use self::{
-    assign_id::AssignIdCmd, lint::LintCmd, list_affected_versions::ListAffectedVersionsCmd,
-    osv::OsvCmd, version::VersionCmd, web::WebCmd,
+    assign_id::AssignIdCmd, bundle::BundleCmd, lint::LintCmd,
+    list_affected_versions::ListAffectedVersionsCmd, osv::OsvCmd, version::VersionCmd, web::WebCmd,
 };
 use crate::config::AppConfig;
 use abscissa_core::{Command, Configurable, Help, Options, Runnable};
@@ -42,6 +43,10 @@ pub enum AdminCmd {
     #[options(help = "export advisories to OSV format")]
     Osv(OsvCmd),
 
+    /// The `bundle` subcommand
+    #[options(help = "export advisory DB to an offline bundle file")]
+    Bundle(BundleCmd),
+
     /// The `version` subcommand
     #[options(help = "list affected crate versions")]
     ListAffectedVersions(ListAffectedVersionsCmd),
@@ -0,0 +1,57 @@
+//! `rustsec-admin bundle` subcommand
+//!
+//! Exports the advisory database to a single offline bundle file which can
+//! be loaded with `cargo audit --db-bundle`.
+
+use std::{path::PathBuf, process::exit};
+
+use abscissa_core::{status_err, status_ok, Command, Options, Runnable};
+use rustsec::{repository::git::Repository, Database};
+
+/// `rustsec-admin bundle` subcommand
+#[derive(Command, Debug, Default, Options)]
+pub struct BundleCmd {
+    /// Path to the advisory database
+    #[options(
+        long = "db",
+        help = "filesystem path to the RustSec advisory DB git repo"
+    )]
+    repo_path: Option<PathBuf>,
+
+    /// Path to the output file
+    #[options(free, help = "filesystem path where the bundle will be written")]
+    path: Vec<PathBuf>,
+}
+
+impl Runnable for BundleCmd {
+    fn run(&self) {
+        let out_path = match self.path.as_slice() {
+            [path] => path,
+            _ => Self::print_usage_and_exit(&[]),
+        };
+
+        let repository = match &self.repo_path {
+            Some(path) => Repository::open(path),
+            None => Repository::fetch_default_repo(),
+        };
+
+        let db = repository
+            .and_then(|repo| Database::load_from_repo(&repo))
+            .unwrap_or_else(|e| {
+                status_err!("failed to load the advisory database: {}", e);
+                exit(1);
+            });
+
+        db.export_bundle(out_path).unwrap_or_else(|e| {
+            status_err!("couldn't export to '{}': {}", out_path.display(), e);
+            exit(1);
+        });
+
+        status_ok!(
+            "Exported",
+            "{} advisories to {}",
+            db.iter().count(),
+            out_path.display()
+        );
+    }
+}
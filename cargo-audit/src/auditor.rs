@@ -1,11 +1,26 @@
 //! Core auditing functionality
 
-use crate::{config::AuditConfig, lockfile, prelude::*, presenter::Presenter};
-use rustsec::{lockfile::Lockfile, registry, report, warning, Error, ErrorKind, Warning};
+use crate::{
+    baseline::{Baseline, Finding},
+    binary,
+    config::{AuditConfig, DatabaseFormat, DatabaseSourceConfig},
+    lockfile,
+    prelude::*,
+    presenter::Presenter,
+    reachability, toolchain,
+};
+use rustsec::{
+    cargo_metadata::CargoMetadata,
+    lockfile::Lockfile,
+    registry,
+    report::{self, Diff},
+    repository::git::{Repository, DEFAULT_URL},
+    warning, Error, ErrorKind, Warning,
+};
 use std::{
-    collections::btree_map as map,
+    collections::{btree_map as map, BTreeSet as Set},
     io::{self, Read},
-    path::Path,
+    path::{Path, PathBuf},
     process::exit,
 };
 
@@ -25,49 +40,111 @@ pub struct Auditor {
 
     /// Audit report settings
     report_settings: report::Settings,
+
+    /// Analyze whether affected functions are referenced?
+    reachability: bool,
+
+    /// `cargo metadata` output to determine dependency kinds from
+    cargo_metadata: Option<PathBuf>,
+
+    /// Baseline of known findings to suppress
+    baseline: Option<Baseline>,
+
+    /// Baseline entries found since stale entries were last reported
+    baseline_found: Set<Finding>,
+
+    /// Audit the Rust toolchain?
+    toolchain: bool,
+
+    /// Version of the Rust toolchain to audit (detected if not configured)
+    rust_version: Option<rustsec::Version>,
+
+    /// Is quiet mode enabled?
+    quiet: bool,
 }
 
 impl Auditor {
-    /// Initialize the auditor
+    /// Initialize the auditor, exiting with an error if the advisory
+    /// database can't be loaded
     pub fn new(config: &AuditConfig) -> Self {
+        Self::try_new(config).unwrap_or_else(|e| {
+            status_err!("{}", e);
+            exit(1);
+        })
+    }
+
+    /// Initialize the auditor, returning an error if the advisory database
+    /// can't be loaded
+    pub fn try_new(config: &AuditConfig) -> rustsec::Result<Self> {
         let advisory_db_url = config
             .database
             .url
             .as_ref()
             .map(AsRef::as_ref)
-            .unwrap_or(rustsec::repository::git::DEFAULT_URL);
+            .unwrap_or(DEFAULT_URL);
 
         let advisory_db_path = config
             .database
             .path
             .as_ref()
             .cloned()
-            .unwrap_or_else(rustsec::repository::git::Repository::default_path);
+            .unwrap_or_else(Repository::default_path);
+
+        let keyring = config.database.keyring.as_deref();
 
-        let database = if config.database.fetch {
+        let database = if let Some(bundle_path) = &config.database.bundle {
+            // Bundles are unsigned, so they can't satisfy a keyring
+            if keyring.is_some() {
+                return Err(Error::new(
+                    ErrorKind::BadParam,
+                    &"advisory database bundles can't be verified with a keyring \
+                      (remove `keyring` or `bundle` from the database configuration)",
+                ));
+            }
+
+            let database = rustsec::Database::open_bundle(bundle_path)
+                .map_err(|e| with_context(e, "error loading advisory database"))?;
+
+            if !config.database.stale {
+                let commit = database.latest_commit().unwrap();
+
+                if !commit.is_fresh() {
+                    return Err(Error::new(
+                        ErrorKind::Repo,
+                        &format!(
+                            "advisory database bundle {} is stale (last commit: {:?})",
+                            bundle_path.display(),
+                            commit.timestamp
+                        ),
+                    ));
+                }
+            }
+
+            database
+        } else if config.database.fetch {
             if !config.output.is_quiet() {
                 status_ok!("Fetching", "advisory database from `{}`", advisory_db_url);
             }
 
-            let advisory_db_repo = rustsec::repository::git::Repository::fetch(
+            let advisory_db_repo = fetch_repository(
                 advisory_db_url,
                 &advisory_db_path,
                 !config.database.stale,
+                keyring,
             )
-            .unwrap_or_else(|e| {
-                status_err!("couldn't fetch advisory database: {}", e);
-                exit(1);
-            });
-
-            rustsec::Database::load_from_repo(&advisory_db_repo).unwrap_or_else(|e| {
-                status_err!("error loading advisory database: {}", e);
-                exit(1);
-            })
+            .map_err(|e| with_context(e, "couldn't fetch advisory database"))?;
+
+            rustsec::Database::load_from_repo(&advisory_db_repo)
+                .map_err(|e| with_context(e, "error loading advisory database"))?
         } else {
-            rustsec::Database::open(&advisory_db_path).unwrap_or_else(|e| {
-                status_err!("error loading advisory database: {}", e);
-                exit(1);
-            })
+            if let Some(keyring) = keyring {
+                Repository::open(&advisory_db_path)
+                    .and_then(|repo| verify_repository(&repo, keyring))
+                    .map_err(|e| with_context(e, "couldn't verify advisory database"))?;
+            }
+
+            rustsec::Database::open(&advisory_db_path)
+                .map_err(|e| with_context(e, "error loading advisory database"))?
         };
 
         if !config.output.is_quiet() {
@@ -75,12 +152,55 @@ impl Auditor {
                 "Loaded",
                 "{} security advisories (from {})",
                 database.iter().count(),
-                advisory_db_path.display()
+                config
+                    .database
+                    .bundle
+                    .as_ref()
+                    .unwrap_or(&advisory_db_path)
+                    .display()
             );
         }
 
+        let database = if config.database.sources.is_empty() {
+            database
+        } else {
+            let database_name = match &config.database.bundle {
+                Some(bundle_path) => bundle_path.display().to_string(),
+                None if config.database.fetch => advisory_db_url.to_owned(),
+                None => advisory_db_path.display().to_string(),
+            };
+
+            let mut merged = rustsec::Database::default();
+            merged.merge(database_name, database);
+
+            for source in &config.database.sources {
+                let name = source.name();
+                let source_db = load_database_source(source, config)?;
+
+                if !config.output.is_quiet() {
+                    status_ok!(
+                        "Loaded",
+                        "{} security advisories (from {})",
+                        source_db.iter().count(),
+                        source.path.display()
+                    );
+                }
+
+                for id in merged.merge(name.clone(), source_db) {
+                    if !config.output.is_quiet() {
+                        status_warn!("skipping duplicate advisory {} from {}", id, name);
+                    }
+                }
+            }
+
+            merged
+        };
+
         let registry_index = if config.yanked.enabled {
-            if config.yanked.update_index && config.database.fetch {
+            if config.yanked.update_index
+                && config.database.fetch
+                && config.database.bundle.is_none()
+            {
                 if !config.output.is_quiet() {
                     status_ok!("Updating", "crates.io index");
                 }
@@ -111,12 +231,24 @@ impl Auditor {
             None
         };
 
-        Self {
+        Ok(Self {
             database,
             registry_index,
             presenter: Presenter::new(&config.output),
             report_settings: config.report_settings(),
-        }
+            reachability: config.reachability.enabled,
+            cargo_metadata: config.packages.cargo_metadata.clone(),
+            baseline: config
+                .advisories
+                .baseline
+                .as_deref()
+                .map(Baseline::load)
+                .transpose()?,
+            baseline_found: Set::new(),
+            toolchain: config.toolchain.enabled,
+            rust_version: config.toolchain.version.clone(),
+            quiet: config.output.is_quiet(),
+        })
     }
 
     /// Perform audit
@@ -124,32 +256,175 @@ impl Auditor {
         &mut self,
         maybe_lockfile_path: Option<&Path>,
     ) -> rustsec::Result<rustsec::Report> {
-        let lockfile_path = match maybe_lockfile_path {
-            Some(p) => p,
+        let lockfile_path = Self::lockfile_path(maybe_lockfile_path)?;
+        let lockfile = self.open_lockfile(lockfile_path)?;
+
+        self.presenter.before_report(lockfile_path, &lockfile);
+
+        let report = self.generate_report(lockfile_path, &lockfile)?;
+        self.warn_stale_baseline_entries(&[lockfile_path]);
+        self.present_report(&report, &lockfile);
+
+        Ok(report)
+    }
+
+    /// Audit several lockfiles against the same advisory database, returning
+    /// the report for each of them
+    pub fn audit_lockfiles(
+        &mut self,
+        lockfile_paths: &[PathBuf],
+    ) -> rustsec::Result<Vec<(PathBuf, rustsec::Report)>> {
+        let self_advisories = self.self_advisories();
+        let mut reports = vec![];
+
+        for (i, lockfile_path) in lockfile_paths.iter().enumerate() {
+            let lockfile = self.open_lockfile(lockfile_path)?;
+
+            self.presenter.before_report(lockfile_path, &lockfile);
+
+            let report = self.generate_report(lockfile_path, &lockfile)?;
+
+            // Only show self-advisories once, after the last report
+            let self_advisories = if i + 1 == lockfile_paths.len() {
+                self_advisories.as_slice()
+            } else {
+                &[]
+            };
+
+            self.presenter
+                .print_lockfile_report(&report, self_advisories, &lockfile);
+
+            reports.push((lockfile_path.clone(), report));
+        }
+
+        let lockfile_paths: Vec<&Path> = lockfile_paths.iter().map(PathBuf::as_path).collect();
+        self.warn_stale_baseline_entries(&lockfile_paths);
+
+        self.presenter.print_summary(&reports);
+        Ok(reports)
+    }
+
+    /// Compare the reports for two lockfiles, e.g. before and after a change
+    pub fn diff(
+        &mut self,
+        old_lockfile_path: &Path,
+        new_lockfile_path: &Path,
+    ) -> rustsec::Result<Diff> {
+        let old_lockfile = self.open_lockfile(old_lockfile_path)?;
+        let new_lockfile = self.open_lockfile(new_lockfile_path)?;
+
+        self.presenter
+            .before_report(old_lockfile_path, &old_lockfile);
+        let old_report = self.generate_report(old_lockfile_path, &old_lockfile)?;
+
+        self.presenter
+            .before_report(new_lockfile_path, &new_lockfile);
+        let new_report = self.generate_report(new_lockfile_path, &new_lockfile)?;
+        self.warn_stale_baseline_entries(&[old_lockfile_path, new_lockfile_path]);
+
+        let diff = Diff::new(&old_report, &old_lockfile, &new_report, &new_lockfile);
+        self.presenter.print_diff(&diff, &new_lockfile);
+
+        Ok(diff)
+    }
+
+    /// Get the path of the lockfile to audit: `Cargo.lock` (generating it if
+    /// needed) unless another path is given
+    pub fn lockfile_path(maybe_lockfile_path: Option<&Path>) -> rustsec::Result<&Path> {
+        match maybe_lockfile_path {
+            Some(p) => Ok(p),
             None => {
                 let path = Path::new(CARGO_LOCK_FILE);
                 if !path.exists() && Path::new("Cargo.toml").exists() {
                     lockfile::generate()?;
                 }
-                path
+                Ok(path)
             }
+        }
+    }
+
+    /// Generate a report for the lockfile at the given path, without
+    /// presenting it
+    pub fn report(&mut self, lockfile_path: &Path) -> rustsec::Result<rustsec::Report> {
+        let lockfile = self.open_lockfile(lockfile_path)?;
+        let report = self.generate_report(lockfile_path, &lockfile)?;
+        self.warn_stale_baseline_entries(&[lockfile_path]);
+        Ok(report)
+    }
+
+    /// Generate a report for the given lockfile
+    fn generate_report(
+        &mut self,
+        lockfile_path: &Path,
+        lockfile: &Lockfile,
+    ) -> rustsec::Result<rustsec::Report> {
+        let workspace_root = match lockfile_path.parent() {
+            Some(parent) if lockfile_path != Path::new("-") && parent != Path::new("") => parent,
+            _ => Path::new("."),
         };
 
-        let lockfile = match self.load_lockfile(lockfile_path) {
-            Ok(l) => l,
-            Err(e) => {
-                return Err(Error::new(
-                    ErrorKind::NotFound,
-                    &format!("Couldn't load {}: {}", lockfile_path.display(), e),
-                ))
+        if self.toolchain {
+            let rust_version = match &self.rust_version {
+                Some(version) => version.clone(),
+                None => toolchain::detect(workspace_root)?,
+            };
+
+            if !self.quiet {
+                status_ok!(
+                    "Scanning",
+                    "Rust toolchain {} for vulnerabilities",
+                    rust_version
+                );
             }
+
+            self.report_settings.rust_version = Some(rust_version);
+        }
+
+        let mut report = if self.report_settings.exclude_dev || self.report_settings.exclude_build {
+            let metadata = match &self.cargo_metadata {
+                Some(path) => CargoMetadata::load_file(path)?,
+                None => lockfile::metadata(workspace_root)?,
+            };
+
+            rustsec::Report::generate_with_metadata(
+                &self.database,
+                lockfile,
+                &metadata,
+                &self.report_settings,
+            )
+        } else {
+            rustsec::Report::generate(&self.database, lockfile, &self.report_settings)
         };
 
-        self.presenter.before_report(&lockfile_path, &lockfile);
+        if self.reachability {
+            reachability::analyze(&mut report, lockfile, workspace_root);
+        }
+
+        self.finish_report(report, lockfile, lockfile_path)
+    }
 
-        let mut report =
-            rustsec::Report::generate(&self.database, &lockfile, &self.report_settings);
+    /// Audit the dependencies embedded in a binary built with `cargo auditable`
+    pub fn audit_binary(&mut self, binary_path: &Path) -> rustsec::Result<rustsec::Report> {
+        let lockfile = binary::load(binary_path)?;
 
+        self.presenter.before_report(binary_path, &lockfile);
+
+        let report = rustsec::Report::generate(&self.database, &lockfile, &self.report_settings);
+        let report = self.finish_report(report, &lockfile, binary_path)?;
+        self.warn_stale_baseline_entries(&[binary_path]);
+        self.present_report(&report, &lockfile);
+
+        Ok(report)
+    }
+
+    /// Add warnings for yanked crates to the report and apply the baseline
+    /// (if any)
+    fn finish_report(
+        &mut self,
+        mut report: rustsec::Report,
+        lockfile: &Lockfile,
+        lockfile_path: &Path,
+    ) -> rustsec::Result<rustsec::Report> {
         // Warn for yanked crates
         // TODO(tarcieri): move this logic into the `rustsec` crate?
         if let Some(index) = &self.registry_index {
@@ -168,12 +443,59 @@ impl Auditor {
             }
         }
 
+        if let Some(baseline) = &self.baseline {
+            let found = baseline.apply(&mut report, lockfile_path);
+            self.baseline_found.extend(found);
+        }
+
+        Ok(report)
+    }
+
+    /// Warn about the baseline entries for any of the given lockfiles which
+    /// weren't found in their reports
+    fn warn_stale_baseline_entries(&mut self, lockfile_paths: &[&Path]) {
+        let found = std::mem::take(&mut self.baseline_found);
+
+        let baseline = match &self.baseline {
+            Some(baseline) if !self.quiet => baseline,
+            _ => return,
+        };
+
+        for finding in baseline.stale(lockfile_paths, &found) {
+            match &finding.lockfile {
+                Some(path) => status_warn!(
+                    "baseline entry {} for {} {} in {} is no longer found",
+                    finding.id,
+                    finding.package,
+                    finding.version,
+                    path.display()
+                ),
+                None => status_warn!(
+                    "baseline entry {} for {} {} is no longer found",
+                    finding.id,
+                    finding.package,
+                    finding.version
+                ),
+            }
+        }
+    }
+
+    /// Present the given report, along with any self-advisories
+    fn present_report(&mut self, report: &rustsec::Report, lockfile: &Lockfile) {
         let self_advisories = self.self_advisories();
 
         self.presenter
-            .print_report(&report, self_advisories.as_slice(), &lockfile);
+            .print_report(report, self_advisories.as_slice(), lockfile);
+    }
 
-        Ok(report)
+    /// Load the lockfile to be audited, reporting which one couldn't be loaded
+    fn open_lockfile(&self, lockfile_path: &Path) -> rustsec::Result<Lockfile> {
+        self.load_lockfile(lockfile_path).map_err(|e| {
+            Error::new(
+                ErrorKind::NotFound,
+                &format!("Couldn't load {}: {}", lockfile_path.display(), e),
+            )
+        })
     }
 
     /// Load the lockfile to be audited
@@ -198,7 +520,11 @@ impl Auditor {
         ] {
             let package: rustsec::package::Name = package_str.parse().unwrap();
             let version: rustsec::Version = version_str.parse().unwrap();
-            let query = rustsec::database::Query::crate_scope();
+            let query = rustsec::database::Query::crate_scope().package_source(
+                format!("registry+{}", rustsec::package::source::CRATES_IO_INDEX)
+                    .parse()
+                    .unwrap(),
+            );
 
             for advisory in self
                 .database
@@ -211,3 +537,106 @@ impl Auditor {
         results
     }
 }
+
+/// Fetch an advisory database repository, requiring its latest commit to be
+/// signed by a key from the given keyring (if any)
+fn fetch_repository(
+    url: &str,
+    path: &Path,
+    ensure_fresh: bool,
+    keyring: Option<&Path>,
+) -> Result<Repository, Error> {
+    match keyring {
+        #[cfg(feature = "signatures")]
+        Some(keyring) => {
+            let keyring = rustsec::repository::signature::Keyring::load_file(keyring)?;
+            Repository::fetch_verified(url, path, ensure_fresh, &keyring)
+        }
+        #[cfg(not(feature = "signatures"))]
+        Some(_) => Err(signatures_unsupported()),
+        None => Repository::fetch(url, path, ensure_fresh),
+    }
+}
+
+/// Verify the latest commit to an advisory database repository is signed by
+/// a key from the given keyring
+#[cfg(feature = "signatures")]
+fn verify_repository(repo: &Repository, keyring: &Path) -> Result<(), Error> {
+    let keyring = rustsec::repository::signature::Keyring::load_file(keyring)?;
+    repo.latest_commit()?.verify_signature(&keyring)?;
+    Ok(())
+}
+
+/// Verify the latest commit to an advisory database repository is signed by
+/// a key from the given keyring
+#[cfg(not(feature = "signatures"))]
+fn verify_repository(_repo: &Repository, _keyring: &Path) -> Result<(), Error> {
+    Err(signatures_unsupported())
+}
+
+/// Error for keyrings configured without signature verification support
+#[cfg(not(feature = "signatures"))]
+fn signatures_unsupported() -> Error {
+    Error::new(
+        ErrorKind::BadParam,
+        &"verifying signatures requires cargo-audit to be built with the `signatures` feature",
+    )
+}
+
+/// Load an additional advisory database, fetching it first if configured
+fn load_database_source(
+    source: &DatabaseSourceConfig,
+    config: &AuditConfig,
+) -> rustsec::Result<rustsec::Database> {
+    let keyring = config.database.keyring.as_deref();
+
+    let result = match (source.format, &source.url) {
+        // OSV advisories aren't kept in git, so there are no signatures to check
+        (DatabaseFormat::Osv, _) if keyring.is_some() => Err(Error::new(
+            ErrorKind::BadParam,
+            &"OSV advisory databases can't be verified against `keyring`",
+        )),
+        (DatabaseFormat::Osv, _) => {
+            rustsec::Database::open_osv(&source.path).map(|(db, skipped)| {
+                if !config.output.is_quiet() {
+                    for id in &skipped {
+                        status_warn!(
+                            "skipping {} in advisory database {}: affects more than one crate",
+                            id,
+                            source.name()
+                        );
+                    }
+                }
+
+                db
+            })
+        }
+        (DatabaseFormat::Git, Some(url)) if config.database.fetch => {
+            if !config.output.is_quiet() {
+                status_ok!("Fetching", "advisory database from `{}`", url);
+            }
+
+            fetch_repository(url, &source.path, !config.database.stale, keyring)
+                .and_then(|repo| rustsec::Database::load_from_repo(&repo))
+        }
+        (DatabaseFormat::Git, _) => Repository::open(&source.path).and_then(|repo| {
+            if let Some(keyring) = keyring {
+                verify_repository(&repo, keyring)?;
+            }
+
+            rustsec::Database::load_from_repo(&repo)
+        }),
+    };
+
+    result.map_err(|e| {
+        with_context(
+            e,
+            &format!("error loading advisory database {}", source.name()),
+        )
+    })
+}
+
+/// Prefix the message of an error with what was being done when it occurred
+fn with_context(error: Error, context: &str) -> Error {
+    Error::new(error.kind(), &format!("{}: {}", context, error))
+}
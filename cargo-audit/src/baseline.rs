@@ -0,0 +1,292 @@
+//! Baseline files: known findings which shouldn't fail the audit.
+//!
+//! A baseline records the (advisory ID, package, version, lockfile) of each
+//! finding in a report. Auditing against a baseline suppresses the findings
+//! it contains, so only new ones are reported, and lists the entries which
+//! are no longer found (e.g. because the package has been upgraded).
+//!
+//! Lockfile paths are written relative to the directory containing the
+//! baseline file, and compared after resolving them, so the baseline applies
+//! regardless of the working directory or how the lockfile path is spelled.
+//! Entries without a lockfile (as written by older versions) apply to every
+//! lockfile. Warnings without an advisory (i.e. yanked crates) aren't
+//! recorded.
+
+use rustsec::{
+    advisory,
+    package::{self, Package},
+    report::VulnerabilityInfo,
+    Error, ErrorKind, Report, Version,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeSet as Set,
+    env, fs,
+    path::{Component, Path, PathBuf},
+};
+
+/// Set of known findings
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Baseline {
+    /// Findings in the baseline
+    pub findings: Set<Finding>,
+}
+
+impl Baseline {
+    /// Create a baseline containing all findings in the given report for the
+    /// lockfile at the given path
+    pub fn from_report(report: &Report, lockfile_path: &Path) -> Self {
+        Self {
+            findings: findings(report)
+                .map(|finding| finding.in_lockfile(lockfile_path))
+                .collect(),
+        }
+    }
+
+    /// Load a baseline from the file at the given path
+    pub fn load(path: &Path) -> rustsec::Result<Self> {
+        let json = fs::read_to_string(path).map_err(|e| {
+            Error::new(
+                ErrorKind::Io,
+                &format!("couldn't open {}: {}", path.display(), e),
+            )
+        })?;
+
+        let baseline: Self = serde_json::from_str(&json).map_err(|e| {
+            Error::new(
+                ErrorKind::Parse,
+                &format!("error parsing {}: {}", path.display(), e),
+            )
+        })?;
+
+        let dir = parent_dir(path);
+        Ok(baseline.map_lockfiles(|lockfile| normalize(&dir.join(lockfile))))
+    }
+
+    /// Save this baseline to the file at the given path
+    pub fn save(&self, path: &Path) -> rustsec::Result<()> {
+        let dir = parent_dir(path);
+        let baseline = self
+            .clone()
+            .map_lockfiles(|lockfile| relative_to(&normalize(lockfile), &dir));
+
+        let mut json = serde_json::to_string_pretty(&baseline).unwrap();
+        json.push('\n');
+
+        fs::write(path, json).map_err(|e| {
+            Error::new(
+                ErrorKind::Io,
+                &format!("couldn't write {}: {}", path.display(), e),
+            )
+        })
+    }
+
+    /// Remove the findings in this baseline from the given report for the
+    /// lockfile at the given path.
+    ///
+    /// Returns the entries of the baseline which were found in the report.
+    pub fn apply(&self, report: &mut Report, lockfile_path: &Path) -> Set<Finding> {
+        let mut found = Set::new();
+
+        let mut is_known =
+            |id: &advisory::Id, package: &Package| match self.entry(id, package, lockfile_path) {
+                Some(entry) => {
+                    found.insert(entry.clone());
+                    true
+                }
+                None => false,
+            };
+
+        let vulnerabilities = report
+            .vulnerabilities
+            .list
+            .drain(..)
+            .filter(|vuln| !is_known(&vuln.advisory.id, &vuln.package))
+            .collect();
+
+        report.vulnerabilities = VulnerabilityInfo::new(vulnerabilities);
+
+        for warnings in report.warnings.values_mut() {
+            warnings.retain(|warning| match &warning.advisory {
+                Some(advisory) => !is_known(&advisory.id, &warning.package),
+                None => true,
+            });
+        }
+
+        let kinds: Vec<_> = report
+            .warnings
+            .iter()
+            .filter(|(_, warnings)| warnings.is_empty())
+            .map(|(kind, _)| *kind)
+            .collect();
+
+        for kind in kinds {
+            report.warnings.remove(&kind);
+        }
+
+        found
+    }
+
+    /// Get the entries of this baseline which apply to any of the given
+    /// lockfiles, but weren't found in any of their reports (i.e. weren't
+    /// returned by [`Baseline::apply`] for them)
+    pub fn stale(&self, lockfile_paths: &[&Path], found: &Set<Finding>) -> Vec<Finding> {
+        let lockfile_paths: Vec<PathBuf> = lockfile_paths.iter().map(|p| normalize(p)).collect();
+
+        self.findings
+            .iter()
+            .filter(|finding| match &finding.lockfile {
+                Some(path) => lockfile_paths.contains(path),
+                None => true,
+            })
+            .filter(|finding| !found.contains(finding))
+            .cloned()
+            .collect()
+    }
+
+    /// Get the entry of this baseline for the given finding in the lockfile
+    /// at the given path, if any
+    fn entry(
+        &self,
+        id: &advisory::Id,
+        package: &Package,
+        lockfile_path: &Path,
+    ) -> Option<&Finding> {
+        let finding = Finding::new(id, package);
+
+        self.findings
+            .get(&finding.clone().in_lockfile(lockfile_path))
+            .or_else(|| self.findings.get(&finding))
+    }
+
+    /// Replace the lockfile paths of the findings in this baseline
+    fn map_lockfiles(self, f: impl Fn(&Path) -> PathBuf) -> Self {
+        let findings = self
+            .findings
+            .into_iter()
+            .map(|mut finding| {
+                finding.lockfile = finding.lockfile.as_deref().map(&f);
+                finding
+            })
+            .collect();
+
+        Self { findings }
+    }
+}
+
+/// Finding of an advisory for a particular version of a package
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Finding {
+    /// Advisory ID
+    pub id: advisory::Id,
+
+    /// Name of the affected package
+    pub package: package::Name,
+
+    /// Version of the affected package
+    pub version: Version,
+
+    /// Path of the lockfile containing the package (if unset, the finding
+    /// applies to every lockfile). Absolute once loaded, and relative to the
+    /// baseline file when saved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lockfile: Option<PathBuf>,
+}
+
+impl Finding {
+    /// Create a finding of the given advisory for the given package
+    pub fn new(id: &advisory::Id, package: &Package) -> Self {
+        Self {
+            id: id.clone(),
+            package: package.name.clone(),
+            version: package.version.clone(),
+            lockfile: None,
+        }
+    }
+
+    /// Record the path of the lockfile containing the package
+    pub fn in_lockfile(mut self, lockfile_path: &Path) -> Self {
+        self.lockfile = Some(normalize(lockfile_path));
+        self
+    }
+}
+
+/// Get the absolute path of the given path, with symlinks and `.`/`..`
+/// components resolved (lexically, if the path doesn't exist)
+fn normalize(path: &Path) -> PathBuf {
+    if let Ok(path) = fs::canonicalize(path) {
+        return path;
+    }
+
+    let path = match env::current_dir() {
+        Ok(dir) => dir.join(path),
+        Err(_) => path.to_owned(),
+    };
+
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => (),
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+
+    normalized
+}
+
+/// Get the normalized directory containing the file at the given path
+fn parent_dir(path: &Path) -> PathBuf {
+    match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => normalize(dir),
+        _ => normalize(Path::new(".")),
+    }
+}
+
+/// Get the given absolute path relative to the given absolute directory, or
+/// the path itself if they don't share a root (e.g. are on different drives)
+fn relative_to(path: &Path, dir: &Path) -> PathBuf {
+    let mut path_components = path.components().peekable();
+    let mut dir_components = dir.components().peekable();
+    let mut shared = 0;
+
+    while let (Some(a), Some(b)) = (path_components.peek(), dir_components.peek()) {
+        if a != b {
+            break;
+        }
+
+        path_components.next();
+        dir_components.next();
+        shared += 1;
+    }
+
+    if shared == 0 {
+        return path.to_owned();
+    }
+
+    dir_components
+        .map(|_| Component::ParentDir)
+        .chain(path_components)
+        .collect()
+}
+
+/// Iterate over the findings in the given report
+fn findings(report: &Report) -> impl Iterator<Item = Finding> + '_ {
+    let vulnerabilities = report
+        .vulnerabilities
+        .list
+        .iter()
+        .map(|vuln| Finding::new(&vuln.advisory.id, &vuln.package));
+
+    let warnings = report.warnings.values().flatten().filter_map(|warning| {
+        warning
+            .advisory
+            .as_ref()
+            .map(|advisory| Finding::new(&advisory.id, &warning.package))
+    });
+
+    vulnerabilities.chain(warnings)
+}
@@ -0,0 +1,160 @@
+//! Auditing compiled binaries using the dependency information embedded in
+//! them by `cargo auditable`.
+//!
+//! Binaries built with `cargo auditable` contain a `.dep-v0` section with the
+//! zlib-compressed JSON list of the packages they were built from. This is
+//! converted into a [`Lockfile`] so it can be audited like any other.
+
+use flate2::read::ZlibDecoder;
+use object::{Object, ObjectSection};
+use rustsec::{
+    cargo_lock::{
+        dependency::Dependency,
+        package::{source::CRATES_IO_INDEX, SourceId},
+        Lockfile, Package, ResolveVersion,
+    },
+    package, Error, ErrorKind, Version,
+};
+use serde::Deserialize;
+use std::{fs, io::Read, path::Path};
+
+/// Name of the section containing the dependency information
+pub const SECTION_NAME: &str = ".dep-v0";
+
+/// Maximum size of the decompressed dependency information
+const MAX_DECOMPRESSED_SIZE: u64 = 8 * 1024 * 1024;
+
+/// Load the dependencies embedded in the binary at the given path
+pub fn load(path: &Path) -> rustsec::Result<Lockfile> {
+    let data = fs::read(path).map_err(|e| {
+        Error::new(
+            ErrorKind::Io,
+            &format!("couldn't open {}: {}", path.display(), e),
+        )
+    })?;
+
+    extract(&data).map_err(|e| {
+        Error::new(
+            e.kind(),
+            &format!("error reading {}: {}", path.display(), e),
+        )
+    })
+}
+
+/// Extract the embedded dependencies from the contents of a binary
+pub fn extract(data: &[u8]) -> rustsec::Result<Lockfile> {
+    let file = object::File::parse(data)
+        .map_err(|e| Error::new(ErrorKind::Parse, &format!("invalid binary: {}", e)))?;
+
+    let section = file.section_by_name(SECTION_NAME).ok_or_else(|| {
+        Error::new(
+            ErrorKind::NotFound,
+            &format!(
+                "no `{}` section (was it built with `cargo auditable`?)",
+                SECTION_NAME
+            ),
+        )
+    })?;
+
+    let compressed = section
+        .data()
+        .map_err(|e| Error::new(ErrorKind::Parse, &format!("invalid section: {}", e)))?;
+
+    let mut json = String::new();
+    ZlibDecoder::new(compressed)
+        .take(MAX_DECOMPRESSED_SIZE)
+        .read_to_string(&mut json)
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::Parse,
+                &format!("invalid dependency information: {}", e),
+            )
+        })?;
+
+    parse(&json)
+}
+
+/// Convert the JSON dependency information embedded in a binary into a
+/// [`Lockfile`]
+pub fn parse(json: &str) -> rustsec::Result<Lockfile> {
+    let info: VersionInfo = serde_json::from_str(json).map_err(|e| {
+        Error::new(
+            ErrorKind::Parse,
+            &format!("invalid dependency information: {}", e),
+        )
+    })?;
+
+    let sources: Vec<_> = info
+        .packages
+        .iter()
+        .map(|package| package.source_id())
+        .collect();
+
+    let mut packages = vec![];
+
+    for (package, source) in info.packages.iter().zip(&sources) {
+        let mut dependencies = vec![];
+
+        for &index in &package.dependencies {
+            let dependency = info.packages.get(index).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::Parse,
+                    &format!("invalid dependency of {}: {}", package.name, index),
+                )
+            })?;
+
+            dependencies.push(Dependency {
+                name: dependency.name.clone(),
+                version: dependency.version.clone(),
+                source: sources[index].clone(),
+            });
+        }
+
+        packages.push(Package {
+            name: package.name.clone(),
+            version: package.version.clone(),
+            source: source.clone(),
+            checksum: None,
+            dependencies,
+            replace: None,
+        });
+    }
+
+    Ok(Lockfile {
+        version: ResolveVersion::default(),
+        packages,
+        root: None,
+        metadata: Default::default(),
+        patch: Default::default(),
+    })
+}
+
+/// Dependency information embedded in a binary
+#[derive(Debug, Deserialize)]
+struct VersionInfo {
+    packages: Vec<PackageInfo>,
+}
+
+/// Package in the embedded dependency information
+#[derive(Debug, Deserialize)]
+struct PackageInfo {
+    name: package::Name,
+    version: Version,
+    source: String,
+    #[serde(default)]
+    dependencies: Vec<usize>,
+}
+
+impl PackageInfo {
+    /// Get the source of this package.
+    ///
+    /// Only crates.io is identified: packages from other sources are treated
+    /// as local packages, as their location isn't recorded.
+    fn source_id(&self) -> Option<SourceId> {
+        if self.source == "crates.io" {
+            Some(format!("registry+{}", CRATES_IO_INDEX).parse().unwrap())
+        } else {
+            None
+        }
+    }
+}
@@ -1,28 +1,40 @@
 //! The `cargo audit` subcommand
 
+mod bin;
+mod diff;
 #[cfg(feature = "fix")]
 mod fix;
 
 use super::CargoAuditCommand;
 use crate::{
     auditor::Auditor,
+    baseline::Baseline,
     config::{AuditConfig, DenyOption, OutputFormat},
+    lockfile,
     prelude::*,
+    watch,
 };
 use abscissa_core::{config::Override, terminal::ColorChoice, FrameworkError};
 use gumdrop::Options;
-use rustsec::database::scope;
-use rustsec::platforms::target::{Arch, OS};
-use std::{path::PathBuf, process::exit};
+use rustsec::platforms::{
+    target::{Arch, OS},
+    PlatformReq,
+};
+use rustsec::{database::scope, report::IgnoreRule};
+use std::{
+    path::{Path, PathBuf},
+    process::exit,
+};
 
 #[cfg(feature = "fix")]
 use self::fix::FixCommand;
+use self::{bin::BinCommand, diff::DiffCommand};
 
 /// The `cargo audit` subcommand
 #[derive(Command, Default, Debug, Options)]
 pub struct AuditCommand {
-    /// Optional subcommand (used for `cargo audit fix`)
-    #[cfg(feature = "fix")]
+    /// Optional subcommand (used for `cargo audit bin`, `cargo audit diff` and
+    /// `cargo audit fix`)
     #[options(command)]
     subcommand: Option<AuditSubcommand>,
 
@@ -49,6 +61,15 @@ pub struct AuditCommand {
     )]
     db: Option<PathBuf>,
 
+    /// Offline advisory database bundle
+    #[options(
+        no_short,
+        long = "db-bundle",
+        meta = "FILE",
+        help = "load the advisory database from an offline bundle instead of git"
+    )]
+    db_bundle: Option<PathBuf>,
+
     /// Deny flag
     #[options(
         short = "D",
@@ -65,13 +86,30 @@ pub struct AuditCommand {
     )]
     deny_warnings: bool,
 
-    /// Path to `Cargo.lock`
+    /// Paths to `Cargo.lock` files
     #[options(
         short = "f",
         long = "file",
-        help = "Cargo lockfile to inspect (or `-` for STDIN, default: Cargo.lock)"
+        help = "Cargo lockfile to inspect (or `-` for STDIN, default: Cargo.lock), may be repeated"
     )]
-    file: Option<PathBuf>,
+    file: Vec<PathBuf>,
+
+    /// Directory to find `Cargo.lock` files in
+    #[options(
+        no_short,
+        long = "discover",
+        meta = "DIR",
+        help = "audit every Cargo.lock in this directory and its subdirectories"
+    )]
+    discover: Option<PathBuf>,
+
+    /// Keyring of OpenPGP keys trusted to sign the advisory database
+    #[options(
+        no_short,
+        long = "keyring",
+        help = "require advisory DB commits to be signed by a key in this OpenPGP keyring"
+    )]
+    keyring: Option<PathBuf>,
 
     /// Advisory IDs to ignore
     #[options(
@@ -82,6 +120,22 @@ pub struct AuditCommand {
     )]
     ignore: Vec<String>,
 
+    /// Scan sources for references to affected functions
+    #[options(
+        no_short,
+        long = "reachability",
+        help = "check whether functions affected by vulnerabilities are referenced"
+    )]
+    reachability: bool,
+
+    /// Audit the Rust toolchain
+    #[options(
+        no_short,
+        long = "toolchain",
+        help = "audit the active Rust toolchain against advisories for std, rustdoc, etc"
+    )]
+    toolchain: bool,
+
     /// Skip fetching the advisory database git repository
     #[options(
         short = "n",
@@ -110,6 +164,15 @@ pub struct AuditCommand {
     )]
     target_os: Option<OS>,
 
+    /// Target platforms to find vulnerabilities for
+    #[options(
+        no_short,
+        long = "target",
+        meta = "TRIPLE",
+        help = "filter vulnerabilities by target triple (can be specified multiple times)"
+    )]
+    target: Vec<PlatformReq>,
+
     /// URL to the advisory database git repository
     #[options(short = "u", long = "url", help = "URL for advisory database git repo")]
     url: Option<String>,
@@ -122,10 +185,69 @@ pub struct AuditCommand {
     )]
     quiet: bool,
 
+    /// Re-audit whenever the lockfile or advisory database changes
+    #[options(
+        no_short,
+        long = "watch",
+        help = "re-audit when Cargo.lock or the advisory DB changes, printing what changed"
+    )]
+    watch: bool,
+
     /// Output reports as JSON
     #[options(no_short, long = "json", help = "Output report in JSON format")]
     output_json: bool,
 
+    /// Output format
+    #[options(
+        no_short,
+        long = "format",
+        help = "output format: cyclonedx, json, junit, markdown, sarif, terminal (default: terminal)"
+    )]
+    format: Option<OutputFormat>,
+
+    /// Ignore vulnerabilities in dev-only dependencies
+    #[options(
+        no_short,
+        long = "exclude-dev",
+        help = "ignore vulnerabilities in packages which are only dev-dependencies"
+    )]
+    exclude_dev: bool,
+
+    /// Ignore vulnerabilities in build-only dependencies
+    #[options(
+        no_short,
+        long = "exclude-build",
+        help = "ignore vulnerabilities in packages which are only build-dependencies"
+    )]
+    exclude_build: bool,
+
+    /// `cargo metadata` output to determine dependency kinds from
+    #[options(
+        no_short,
+        long = "cargo-metadata",
+        meta = "FILE",
+        help = "`cargo metadata` JSON used by --exclude-dev/--exclude-build (default: run cargo)"
+    )]
+    cargo_metadata: Option<PathBuf>,
+
+    /// Baseline of known findings to suppress
+    #[options(
+        no_short,
+        long = "baseline",
+        meta = "FILE",
+        help = "only fail on findings which aren't in this baseline file"
+    )]
+    baseline: Option<PathBuf>,
+
+    /// Write a baseline of the current findings
+    #[options(
+        no_short,
+        long = "write-baseline",
+        meta = "FILE",
+        help = "record the current findings in a baseline file"
+    )]
+    write_baseline: Option<PathBuf>,
+
     /// Vulnerability querying does not consider local crates
     #[options(
         no_short,
@@ -139,12 +261,55 @@ pub struct AuditCommand {
 #[cfg(feature = "fix")]
 #[derive(Command, Debug, Options, Runnable)]
 pub enum AuditSubcommand {
+    /// `cargo audit bin` subcommand
+    #[options(help = "audit binaries built with `cargo auditable`")]
+    Bin(BinCommand),
+
+    /// `cargo audit diff` subcommand
+    #[options(help = "compare the findings for two lockfiles")]
+    Diff(DiffCommand),
+
     /// `cargo audit fix` subcommand
     #[options(help = "automatically upgrade vulnerable dependencies")]
     Fix(FixCommand),
 }
 
+/// Subcommands of `cargo audit`
+#[cfg(not(feature = "fix"))]
+#[derive(Command, Debug, Options, Runnable)]
+pub enum AuditSubcommand {
+    /// `cargo audit bin` subcommand
+    #[options(help = "audit binaries built with `cargo auditable`")]
+    Bin(BinCommand),
+
+    /// `cargo audit diff` subcommand
+    #[options(help = "compare the findings for two lockfiles")]
+    Diff(DiffCommand),
+}
+
 impl AuditCommand {
+    /// Get the paths of the lockfiles to audit, including any found with
+    /// `--discover`. Empty if only the default lockfile should be audited.
+    fn lockfile_paths(&self) -> Vec<PathBuf> {
+        let mut lockfile_paths = self.file.clone();
+
+        if let Some(dir) = &self.discover {
+            match lockfile::find_all(dir) {
+                Ok(paths) if paths.is_empty() => {
+                    status_err!("no Cargo.lock files found in {}", dir.display());
+                    exit(2);
+                }
+                Ok(paths) => lockfile_paths.extend(paths),
+                Err(e) => {
+                    status_err!("{}", e);
+                    exit(2);
+                }
+            }
+        }
+
+        lockfile_paths
+    }
+
     /// Get the color configuration
     pub fn color_config(&self) -> Option<ColorChoice> {
         self.color.as_ref().map(|colors| match colors.as_ref() {
@@ -162,18 +327,28 @@ impl Override<AuditConfig> for AuditCommand {
             config.database.path = Some(db.into());
         }
 
+        if let Some(bundle) = &self.db_bundle {
+            config.database.bundle = Some(bundle.into());
+        }
+
         for advisory_id in &self.ignore {
             config
                 .advisories
                 .ignore
-                .push(advisory_id.parse().unwrap_or_else(|e| {
+                .push(IgnoreRule::new(advisory_id.parse().unwrap_or_else(|e| {
                     status_err!("error parsing {}: {}", advisory_id, e);
                     exit(1);
-                }));
+                })));
+        }
+
+        if let Some(keyring) = &self.keyring {
+            config.database.keyring = Some(keyring.into());
         }
 
         config.database.fetch |= !self.no_fetch;
         config.database.stale |= self.stale;
+        config.reachability.enabled |= self.reachability;
+        config.toolchain.enabled |= self.toolchain;
 
         if let Some(target_arch) = self.target_arch {
             config.target.arch = Some(target_arch);
@@ -183,6 +358,8 @@ impl Override<AuditConfig> for AuditCommand {
             config.target.os = Some(target_os);
         }
 
+        config.target.platforms.extend(self.target.iter().cloned());
+
         if let Some(url) = &self.url {
             config.database.url = Some(url.clone())
         }
@@ -203,10 +380,30 @@ impl Override<AuditConfig> for AuditCommand {
 
         config.output.quiet |= self.quiet;
 
+        if let Some(format) = self.format {
+            config.output.format = format;
+        }
+
         if self.output_json {
             config.output.format = OutputFormat::Json;
         }
 
+        config.packages.exclude_dev |= self.exclude_dev;
+        config.packages.exclude_build |= self.exclude_build;
+
+        if let Some(cargo_metadata) = &self.cargo_metadata {
+            config.packages.cargo_metadata = Some(cargo_metadata.into());
+        }
+
+        if let Some(baseline) = &self.baseline {
+            config.advisories.baseline = Some(baseline.into());
+        }
+
+        // Baselines are written from all current findings
+        if self.write_baseline.is_some() {
+            config.advisories.baseline = None;
+        }
+
         if self.no_local_crates {
             config.packages.source = Some(scope::Registry::Public)
         }
@@ -217,8 +414,16 @@ impl Override<AuditConfig> for AuditCommand {
 
 impl Runnable for AuditCommand {
     fn run(&self) {
-        #[cfg(feature = "fix")]
         match &self.subcommand {
+            Some(AuditSubcommand::Bin(bin)) => {
+                bin.run();
+                exit(0)
+            }
+            Some(AuditSubcommand::Diff(diff)) => {
+                diff.run();
+                exit(0)
+            }
+            #[cfg(feature = "fix")]
             Some(AuditSubcommand::Fix(fix)) => {
                 fix.run();
                 exit(0)
@@ -235,16 +440,59 @@ impl Runnable for AuditCommand {
             exit(0);
         }
 
-        let lockfile_path = self.file.as_deref();
-        let report = self.auditor().audit(lockfile_path);
+        let lockfile_paths = self.lockfile_paths();
 
-        match report {
-            Ok(report) => {
-                if report.vulnerabilities.found {
-                    exit(1);
+        if self.watch {
+            if lockfile_paths.len() > 1 {
+                status_err!("--watch only supports a single lockfile");
+                exit(2);
+            }
+
+            match Auditor::lockfile_path(lockfile_paths.first().map(PathBuf::as_path)) {
+                Ok(path) => watch::watch(&app_config(), path),
+                Err(e) => {
+                    status_err!("{}", e);
+                    exit(2);
                 }
-                exit(0);
             }
+        }
+
+        if let Some(path) = &self.write_baseline {
+            self.write_baseline(path, &lockfile_paths);
+        }
+
+        let format = app_config().output.format;
+
+        if lockfile_paths.len() > 1 && !format.supports_multiple_lockfiles() {
+            status_err!(
+                "the {} output format only supports a single lockfile",
+                format.as_str()
+            );
+            exit(2);
+        }
+
+        // Discovered lockfiles are always reported together, even if there's
+        // only one of them, so the output format doesn't depend on how many
+        // were found
+        let vulnerabilities_found = if self.discover.is_some() || lockfile_paths.len() > 1 {
+            self.auditor()
+                .audit_lockfiles(&lockfile_paths)
+                .map(|reports| {
+                    reports
+                        .iter()
+                        .any(|(_, report)| report.vulnerabilities.found)
+                })
+        } else {
+            let lockfile_path = lockfile_paths.first().map(PathBuf::as_path);
+
+            self.auditor()
+                .audit(lockfile_path)
+                .map(|report| report.vulnerabilities.found)
+        };
+
+        match vulnerabilities_found {
+            Ok(true) => exit(1),
+            Ok(false) => exit(0),
             Err(e) => {
                 status_err!("{}", e);
                 exit(2);
@@ -259,4 +507,45 @@ impl AuditCommand {
         let config = app_config();
         Auditor::new(&config)
     }
+
+    /// Write the findings for the given lockfiles to a baseline file and
+    /// exit, without presenting the reports (which could exit early)
+    fn write_baseline(&self, path: &Path, lockfile_paths: &[PathBuf]) -> ! {
+        let mut auditor = self.auditor();
+        let mut baseline = Baseline::default();
+
+        let lockfile_paths: Vec<Option<&Path>> = if lockfile_paths.is_empty() {
+            vec![None]
+        } else {
+            lockfile_paths.iter().map(|p| Some(p.as_path())).collect()
+        };
+
+        for lockfile_path in lockfile_paths {
+            let result = Auditor::lockfile_path(lockfile_path).and_then(|lockfile_path| {
+                let report = auditor.report(lockfile_path)?;
+                Ok(Baseline::from_report(&report, lockfile_path))
+            });
+
+            match result {
+                Ok(lockfile_baseline) => baseline.findings.extend(lockfile_baseline.findings),
+                Err(e) => {
+                    status_err!("{}", e);
+                    exit(2);
+                }
+            }
+        }
+
+        if let Err(e) = baseline.save(path) {
+            status_err!("{}", e);
+            exit(2);
+        }
+
+        status_ok!(
+            "Wrote",
+            "baseline of {} findings to {}",
+            baseline.findings.len(),
+            path.display()
+        );
+        exit(0);
+    }
 }
@@ -0,0 +1,85 @@
+//! The `cargo audit bin` subcommand
+
+use crate::{auditor::Auditor, config::AuditConfig, prelude::*};
+use abscissa_core::{Command, Runnable};
+use gumdrop::Options;
+use std::{path::PathBuf, process::exit};
+
+/// The `cargo audit bin` subcommand.
+///
+/// Binaries don't record how each dependency was used, and they aren't
+/// audited alongside their sources or toolchain, so `--exclude-dev`,
+/// `--exclude-build`, `--reachability` and `--toolchain` (or the equivalent
+/// settings in `audit.toml`) are rejected.
+#[derive(Command, Default, Debug, Options)]
+pub struct BinCommand {
+    /// Get help information
+    #[options(short = "h", long = "help", help = "output help information and exit")]
+    help: bool,
+
+    /// Paths to the binaries to audit
+    #[options(
+        free,
+        help = "binaries built with `cargo auditable` to audit (--exclude-dev, --exclude-build, --reachability and --toolchain are unsupported)"
+    )]
+    binary_paths: Vec<PathBuf>,
+}
+
+impl BinCommand {
+    /// Initialize `Auditor`
+    pub fn auditor(&self) -> Auditor {
+        let config = app_config();
+        Auditor::new(&config)
+    }
+}
+
+impl Runnable for BinCommand {
+    fn run(&self) {
+        if self.help || self.binary_paths.is_empty() {
+            Self::print_usage_and_exit(&[]);
+        }
+
+        let unsupported = unsupported_options(&app_config());
+
+        if !unsupported.is_empty() {
+            status_err!(
+                "{} can't be used when auditing binaries",
+                unsupported.join(", ")
+            );
+            exit(2);
+        }
+
+        let mut auditor = self.auditor();
+        let mut vulnerabilities_found = false;
+
+        for binary_path in &self.binary_paths {
+            match auditor.audit_binary(binary_path) {
+                Ok(report) => vulnerabilities_found |= report.vulnerabilities.found,
+                Err(e) => {
+                    status_err!("{}", e);
+                    exit(2);
+                }
+            }
+        }
+
+        if vulnerabilities_found {
+            exit(1);
+        }
+
+        exit(0);
+    }
+}
+
+/// Get the options which are set but can't be used when auditing binaries
+fn unsupported_options(config: &AuditConfig) -> Vec<&'static str> {
+    [
+        (config.packages.exclude_dev, "--exclude-dev"),
+        (config.packages.exclude_build, "--exclude-build"),
+        (config.reachability.enabled, "--reachability"),
+        (config.toolchain.enabled, "--toolchain"),
+    ]
+    .iter()
+    .filter(|(enabled, _)| *enabled)
+    .map(|(_, option)| *option)
+    .collect()
+}
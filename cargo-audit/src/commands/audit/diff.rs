@@ -0,0 +1,49 @@
+//! The `cargo audit diff` subcommand
+
+use crate::{auditor::Auditor, prelude::*};
+use abscissa_core::{Command, Runnable};
+use gumdrop::Options;
+use std::{path::PathBuf, process::exit};
+
+#[derive(Command, Default, Debug, Options)]
+pub struct DiffCommand {
+    /// Get help information
+    #[options(short = "h", long = "help", help = "output help information and exit")]
+    help: bool,
+
+    /// Paths to the old and new lockfiles
+    #[options(free, help = "old and new Cargo lockfiles to compare")]
+    lockfile_paths: Vec<PathBuf>,
+}
+
+impl DiffCommand {
+    /// Initialize `Auditor`
+    pub fn auditor(&self) -> Auditor {
+        let config = app_config();
+        Auditor::new(&config)
+    }
+}
+
+impl Runnable for DiffCommand {
+    fn run(&self) {
+        if self.help || self.lockfile_paths.len() != 2 {
+            Self::print_usage_and_exit(&[]);
+        }
+
+        match self
+            .auditor()
+            .diff(&self.lockfile_paths[0], &self.lockfile_paths[1])
+        {
+            Ok(diff) => {
+                if !diff.added.vulnerabilities.is_empty() {
+                    exit(1);
+                }
+                exit(0);
+            }
+            Err(e) => {
+                status_err!("{}", e);
+                exit(2);
+            }
+        }
+    }
+}
@@ -3,8 +3,11 @@
 use crate::{auditor::Auditor, lockfile, prelude::*};
 use abscissa_core::{Command, Runnable};
 use gumdrop::Options;
-use rustsec::Fixer;
+use rustsec::{
+    cargo_lock::dependency::Dependency, fs, lockfile::Lockfile, planner::Planner, registry, Fixer,
+};
 use std::{
+    collections::BTreeMap,
     path::{Path, PathBuf},
     process::exit,
 };
@@ -31,7 +34,27 @@ impl FixCommand {
         Auditor::new(&config)
     }
 
-    /// Locate `Cargo.toml`
+    /// Open the crates.io index, fetching it if configured to do so
+    pub fn registry_index(&self) -> registry::Index {
+        let config = app_config();
+
+        let result = if config.database.fetch {
+            if !config.output.is_quiet() {
+                status_ok!("Updating", "crates.io index");
+            }
+
+            registry::Index::fetch()
+        } else {
+            registry::Index::open()
+        };
+
+        result.unwrap_or_else(|e| {
+            status_err!("couldn't open crates.io index: {}", e);
+            exit(1);
+        })
+    }
+
+    /// Locate the root `Cargo.toml` (members of its workspace are fixed too)
     // TODO(tarcieri): ability to specify path
     pub fn cargo_toml_path(&self) -> PathBuf {
         PathBuf::from("Cargo.toml")
@@ -64,7 +87,25 @@ impl Runnable for FixCommand {
             }
         };
 
-        let mut fixer = Fixer::new(self.cargo_toml_path()).unwrap_or_else(|e| {
+        let lockfile_path = self
+            .cargo_lock_path()
+            .unwrap_or_else(|| Path::new("Cargo.lock"));
+
+        let mut cargo_lock = Lockfile::load(lockfile_path).unwrap_or_else(|e| {
+            status_err!("couldn't load {}: {}", lockfile_path.display(), e);
+            exit(1);
+        });
+
+        let registry_index = self.registry_index();
+
+        let plan = Planner::new(&cargo_lock, &registry_index)
+            .map(|planner| planner.plan(&report.vulnerabilities.list))
+            .unwrap_or_else(|e| {
+                status_err!("couldn't plan lockfile updates: {}", e);
+                exit(1);
+            });
+
+        let mut fixer = Fixer::new(self.cargo_toml_path(), registry_index).unwrap_or_else(|e| {
             status_err!(
                 "couldn't load manifest from {}: {}",
                 self.cargo_toml_path().display(),
@@ -79,14 +120,95 @@ impl Runnable for FixCommand {
         status_ok!(
             "Fixing",
             "vulnerable dependencies in `{}`{}",
-            self.cargo_toml_path().display(),
+            lockfile_path.display(),
             dry_run_info
         );
 
-        for vulnerability in &report.vulnerabilities.list {
-            if let Err(e) = fixer.fix(vulnerability, dry_run) {
-                status_warn!("{}", e);
+        for update in &plan.updates {
+            status_ok!("Updating", "{}{}", update, dry_run_info);
+        }
+
+        let pending_updates = if dry_run {
+            plan.updates
+                .iter()
+                .filter(|update| !update.rewritable)
+                .collect()
+        } else {
+            let pending_updates = plan.apply(&mut cargo_lock);
+
+            // Don't re-encode the lockfile unless it's actually been changed
+            let rewritten = pending_updates.len() < plan.updates.len();
+
+            if rewritten {
+                if let Err(e) = fs::write(lockfile_path, cargo_lock.to_string()) {
+                    status_err!("couldn't write {}: {}", lockfile_path.display(), e);
+                    exit(2);
+                }
+            }
+
+            pending_updates
+        };
+
+        for update in pending_updates {
+            status_warn!(
+                "{} can't be updated in place, run: {}",
+                update.package.name,
+                update.cargo_update_command()
+            );
+        }
+
+        // Upgrades performed in each manifest, for the per-manifest summary
+        let mut manifest_upgrades: BTreeMap<PathBuf, Vec<String>> = BTreeMap::new();
+
+        for unfixable in &plan.unfixable {
+            if !unfixable.reason.requires_manifest_change() {
+                status_warn!(
+                    "can't fix {} {}: {}",
+                    unfixable.package.name,
+                    unfixable.package.version,
+                    unfixable.reason
+                );
+                continue;
             }
+
+            let vulnerabilities = report
+                .vulnerabilities
+                .list
+                .iter()
+                .filter(|vuln| Dependency::from(&vuln.package) == unfixable.package);
+
+            for vulnerability in vulnerabilities {
+                match fixer.fix(vulnerability, dry_run) {
+                    Ok(fix) => {
+                        let upgrade = format!(
+                            "{} {} -> {}",
+                            fix.package, vulnerability.package.version, fix.version
+                        );
+
+                        for manifest in fix.manifests {
+                            manifest_upgrades
+                                .entry(manifest)
+                                .or_default()
+                                .push(upgrade.clone());
+                        }
+                    }
+                    Err(e) => status_warn!("{}", e),
+                }
+            }
+        }
+
+        for (manifest, upgrades) in &manifest_upgrades {
+            status_ok!(
+                "Upgraded",
+                "`{}`{}: {}",
+                manifest.display(),
+                dry_run_info,
+                upgrades.join(", ")
+            );
+        }
+
+        if manifest_upgrades.is_empty() || dry_run {
+            return;
         }
 
         if let Err(e) = lockfile::generate() {
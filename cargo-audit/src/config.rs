@@ -4,7 +4,10 @@ use rustsec::warning;
 use rustsec::{
     advisory,
     database::scope,
-    platforms::target::{Arch, OS},
+    platforms::{
+        target::{Arch, OS},
+        PlatformReq,
+    },
     report, Error, ErrorKind,
 };
 use serde::{Deserialize, Serialize};
@@ -40,19 +43,37 @@ pub struct AuditConfig {
     /// Configuration for auditing for yanked crates
     #[serde(default)]
     pub yanked: YankedConfig,
+
+    /// Configuration for reachability analysis of affected functions
+    #[serde(default)]
+    pub reachability: ReachabilityConfig,
+
+    /// Configuration for auditing the Rust toolchain
+    #[serde(default)]
+    pub toolchain: ToolchainConfig,
 }
 
 impl AuditConfig {
     /// Get audit report settings from the configuration
     pub fn report_settings(&self) -> report::Settings {
         let mut settings = rustsec::report::Settings {
-            ignore: self.advisories.ignore.clone(),
             severity: self.advisories.severity_threshold,
             target_arch: self.target.arch,
             target_os: self.target.os,
+            target_platforms: self.target.platforms.clone(),
+            exclude_dev: self.packages.exclude_dev,
+            exclude_build: self.packages.exclude_build,
             ..Default::default()
         };
 
+        for rule in &self.advisories.ignore {
+            if rule.is_unconditional() {
+                settings.ignore.push(rule.id.clone());
+            } else {
+                settings.ignore_rules.push(rule.clone());
+            }
+        }
+
         if let Some(source) = &self.packages.source {
             settings.package_scope = Some(source.clone().into());
         }
@@ -100,9 +121,10 @@ impl AuditConfig {
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct AdvisoryConfig {
-    /// Ignore advisories for the given IDs
+    /// Ignore advisories for the given IDs, or according to the given rules
+    /// (with a reason, expiry date and/or package scope)
     #[serde(default)]
-    pub ignore: Vec<advisory::Id>,
+    pub ignore: Vec<report::IgnoreRule>,
 
     /// Warn for the given types of informational advisories
     pub informational_warnings: Option<Vec<advisory::Informational>>,
@@ -112,6 +134,10 @@ pub struct AdvisoryConfig {
     /// Vulnerabilities with explicit CVSS info which have a severity below
     /// this threshold will be ignored.
     pub severity_threshold: Option<advisory::Severity>,
+
+    /// Baseline file of known findings: only findings which aren't in it
+    /// are reported
+    pub baseline: Option<PathBuf>,
 }
 
 /// Advisory Database configuration.
@@ -132,6 +158,58 @@ pub struct DatabaseConfig {
 
     /// Allow a stale advisory database? (i.e. one which hasn't been updated in 90 days)
     pub stale: bool,
+
+    /// Path to a keyring of OpenPGP keys trusted to sign the latest commit
+    /// to the advisory database's git repo, and to those of any git `sources`
+    /// (default: signatures aren't verified)
+    pub keyring: Option<PathBuf>,
+
+    /// Path to an offline advisory database bundle to load instead of the
+    /// git repo (see `rustsec-admin bundle`)
+    pub bundle: Option<PathBuf>,
+
+    /// Additional advisory databases to merge with the one above, in order
+    /// of precedence (advisories with the same ID as an earlier one are skipped)
+    #[serde(default)]
+    pub sources: Vec<DatabaseSourceConfig>,
+}
+
+/// Additional advisory database
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct DatabaseSourceConfig {
+    /// Path to the local copy of the database
+    pub path: PathBuf,
+
+    /// URL to the database's git repo (if unset, the local copy isn't fetched)
+    pub url: Option<String>,
+
+    /// Format of the database (default: git)
+    #[serde(default)]
+    pub format: DatabaseFormat,
+}
+
+impl DatabaseSourceConfig {
+    /// Name of this database to display in reports
+    pub fn name(&self) -> String {
+        match &self.url {
+            Some(url) => url.clone(),
+            None => self.path.display().to_string(),
+        }
+    }
+}
+
+/// Advisory database format
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum DatabaseFormat {
+    /// Git repository in the RustSec advisory database layout
+    #[default]
+    #[serde(rename = "git")]
+    Git,
+
+    /// Directory containing advisories in the OSV JSON format
+    #[serde(rename = "osv")]
+    Osv,
 }
 
 /// Output configuration
@@ -156,7 +234,7 @@ pub struct OutputConfig {
 impl OutputConfig {
     /// Is quiet mode enabled?
     pub fn is_quiet(&self) -> bool {
-        self.quiet || self.format == OutputFormat::Json
+        self.quiet || self.format != OutputFormat::Terminal
     }
 }
 
@@ -221,21 +299,77 @@ impl FromStr for DenyOption {
 /// Output format
 #[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum OutputFormat {
+    /// Display a CycloneDX v1.4 SBOM annotated with vulnerabilities
+    #[serde(rename = "cyclonedx")]
+    CycloneDx,
+
     /// Display JSON
     #[serde(rename = "json")]
     Json,
 
+    /// Display JUnit XML (e.g. for CI test reporting)
+    #[serde(rename = "junit")]
+    Junit,
+
+    /// Display Markdown (e.g. for pull request comments)
+    #[serde(rename = "markdown")]
+    Markdown,
+
+    /// Display SARIF v2.1.0 (e.g. for code scanning dashboards)
+    #[serde(rename = "sarif")]
+    Sarif,
+
     /// Display human-readable output to the terminal
     #[serde(rename = "terminal")]
     Terminal,
 }
 
+impl OutputFormat {
+    /// Get the name of this format, as given to `--format`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OutputFormat::CycloneDx => "cyclonedx",
+            OutputFormat::Json => "json",
+            OutputFormat::Junit => "junit",
+            OutputFormat::Markdown => "markdown",
+            OutputFormat::Sarif => "sarif",
+            OutputFormat::Terminal => "terminal",
+        }
+    }
+
+    /// Can the reports for several lockfiles be output in this format?
+    ///
+    /// The other formats are single documents about one lockfile.
+    pub fn supports_multiple_lockfiles(self) -> bool {
+        matches!(self, OutputFormat::Json | OutputFormat::Terminal)
+    }
+}
+
 impl Default for OutputFormat {
     fn default() -> Self {
         OutputFormat::Terminal
     }
 }
 
+impl FromStr for OutputFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "cyclonedx" => Ok(OutputFormat::CycloneDx),
+            "json" => Ok(OutputFormat::Json),
+            "junit" => Ok(OutputFormat::Junit),
+            "markdown" => Ok(OutputFormat::Markdown),
+            "sarif" => Ok(OutputFormat::Sarif),
+            "terminal" => Ok(OutputFormat::Terminal),
+            other => Err(Error::new(
+                ErrorKind::Parse,
+                &format!("invalid output format: {}", other),
+            )),
+        }
+    }
+}
+
 /// Target configuration
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -245,6 +379,12 @@ pub struct TargetConfig {
 
     /// Target OS to find vulnerabilities for
     pub os: Option<OS>,
+
+    /// Target platforms (i.e. target triples, e.g. `x86_64-unknown-linux-gnu`)
+    /// to find vulnerabilities for. Wildcards such as `*-apple-darwin` expand
+    /// to all matching platforms.
+    #[serde(default)]
+    pub platforms: Vec<PlatformReq>,
 }
 
 /// Packages configuration
@@ -253,6 +393,18 @@ pub struct TargetConfig {
 pub struct PackageConfig {
     /// Package scope which should be considered for querying for vulnerabilities.
     pub source: Option<scope::Registry>,
+
+    /// Ignore vulnerabilities in packages which are only dev-dependencies
+    #[serde(default)]
+    pub exclude_dev: bool,
+
+    /// Ignore vulnerabilities in packages which are only build-dependencies
+    #[serde(default)]
+    pub exclude_build: bool,
+
+    /// Path to `cargo metadata --format-version 1` output used to determine
+    /// dependency kinds (default: run `cargo metadata`)
+    pub cargo_metadata: Option<PathBuf>,
 }
 
 /// Configuration for auditing for yanked crates
@@ -277,6 +429,29 @@ impl Default for YankedConfig {
     }
 }
 
+/// Configuration for reachability analysis of affected functions
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReachabilityConfig {
+    /// Scan the sources of the workspace and its dependencies for references
+    /// to the functions affected by vulnerabilities?
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+/// Configuration for auditing the Rust toolchain itself
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToolchainConfig {
+    /// Audit the Rust toolchain against advisories for `std`, `rustdoc`, etc?
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Version of the toolchain to audit (default: detect it from the
+    /// `rust-toolchain` file of the workspace, or `rustc -V`)
+    pub version: Option<rustsec::Version>,
+}
+
 /// Helper function for returning a default of `true`
 fn default_true() -> bool {
     true
@@ -22,12 +22,17 @@
 
 pub mod application;
 pub mod auditor;
+pub mod baseline;
+pub mod binary;
 pub mod commands;
 pub mod config;
 pub mod error;
 pub mod lockfile;
 mod prelude;
 pub mod presenter;
+pub mod reachability;
+pub mod toolchain;
+pub mod watch;
 
 /// Current version of the `cargo-audit` crate
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -1,7 +1,14 @@
 //! Cargo.lock-related utilities
 
-use rustsec::{Error, ErrorKind};
-use std::process::Command;
+use rustsec::{cargo_metadata::CargoMetadata, Error, ErrorKind};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Name of the lockfile
+const CARGO_LOCK_FILE: &str = "Cargo.lock";
 
 /// Run `cargo generate-lockfile`
 pub fn generate() -> rustsec::Result<()> {
@@ -28,3 +35,68 @@ pub fn generate() -> rustsec::Result<()> {
     }
     Ok(())
 }
+
+/// Run `cargo metadata` for the workspace in the given directory, without
+/// modifying its `Cargo.lock`
+pub fn metadata(workspace_root: &Path) -> rustsec::Result<CargoMetadata> {
+    let output = Command::new("cargo")
+        .arg("metadata")
+        .arg("--format-version")
+        .arg("1")
+        .arg("--locked")
+        .current_dir(workspace_root)
+        .output()
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::Io,
+                &format!("couldn't run `cargo metadata`: {}", e),
+            )
+        })?;
+
+    if !output.status.success() {
+        return Err(Error::new(
+            ErrorKind::Io,
+            &format!(
+                "error running `cargo metadata`: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        ));
+    }
+
+    String::from_utf8_lossy(&output.stdout).parse()
+}
+
+/// Find every `Cargo.lock` in the given directory and its subdirectories,
+/// skipping hidden and `target` directories. Paths are returned sorted.
+pub fn find_all(dir: &Path) -> rustsec::Result<Vec<PathBuf>> {
+    let mut lockfiles = vec![];
+    let mut pending = vec![dir.to_owned()];
+
+    while let Some(current) = pending.pop() {
+        let entries = fs::read_dir(&current).map_err(|e| {
+            Error::new(
+                ErrorKind::Io,
+                &format!("couldn't read {}: {}", current.display(), e),
+            )
+        })?;
+
+        for entry in entries {
+            let path = entry?.path();
+            let file_name = match path.file_name() {
+                Some(name) => name.to_string_lossy(),
+                None => continue,
+            };
+
+            if path.is_dir() {
+                if !file_name.starts_with('.') && file_name != "target" {
+                    pending.push(path);
+                }
+            } else if file_name == CARGO_LOCK_FILE {
+                lockfiles.push(path);
+            }
+        }
+    }
+
+    lockfiles.sort();
+    Ok(lockfiles)
+}
@@ -1,5 +1,10 @@
 //! Presenter for `rustsec::Report` information.
 
+pub mod junit;
+pub mod markdown;
+pub mod sarif;
+
+use self::{junit::JunitReport, markdown::MarkdownReport, sarif::SarifLog};
 use crate::{
     config::{DenyOption, OutputConfig, OutputFormat},
     prelude::*,
@@ -8,11 +13,19 @@ use abscissa_core::terminal::{
     self,
     Color::{self, Red, Yellow},
 };
-use rustsec::cargo_lock::{
-    dependency::{self, graph::EdgeDirection, Dependency},
-    Lockfile, Package,
+use rustsec::{
+    cargo_lock::{
+        dependency::{self, graph::EdgeDirection, Dependency},
+        Lockfile, Package,
+    },
+    cyclonedx::Bom,
+    report::Diff,
+};
+use std::{
+    collections::{BTreeMap as Map, BTreeSet as Set},
+    io,
+    path::{Path, PathBuf},
 };
-use std::{collections::BTreeSet as Set, io, path::Path};
 
 use std::io::Write as _;
 use std::string::ToString as _;
@@ -29,6 +42,12 @@ pub struct Presenter {
 
     /// Output configuration
     config: OutputConfig,
+
+    /// Path to the lockfile being audited (for locating results)
+    lockfile_path: PathBuf,
+
+    /// Did a printed report contain denied warnings?
+    exit_with_failure: bool,
 }
 
 impl Presenter {
@@ -42,11 +61,16 @@ impl Presenter {
                 .filter_map(|k| k.get_warning_kind())
                 .collect(),
             config: config.clone(),
+            lockfile_path: PathBuf::from("Cargo.lock"),
+            exit_with_failure: false,
         }
     }
 
     /// Information to display before a report is generated
     pub fn before_report(&mut self, lockfile_path: &Path, lockfile: &Lockfile) {
+        self.lockfile_path = lockfile_path.to_owned();
+        self.displayed_packages.clear();
+
         if !self.config.is_quiet() {
             status_ok!(
                 "Scanning",
@@ -72,15 +96,230 @@ impl Presenter {
         self_advisories: &[rustsec::Advisory],
         lockfile: &Lockfile,
     ) {
+        self.present(report, self_advisories, lockfile);
+
+        // TODO(tarcieri): better unify this with vulnerabilities handling
+        if self.exit_with_failure {
+            std::process::exit(1);
+        }
+    }
+
+    /// Print the report for one of several lockfiles audited together.
+    ///
+    /// JSON reports are combined into a single object keyed by lockfile path,
+    /// so they're only printed by [`Presenter::print_summary`], which must be
+    /// called once all of the lockfiles have been audited.
+    pub fn print_lockfile_report(
+        &mut self,
+        report: &rustsec::Report,
+        self_advisories: &[rustsec::Advisory],
+        lockfile: &Lockfile,
+    ) {
+        if self.config.format != OutputFormat::Json {
+            self.present(report, self_advisories, lockfile);
+        } else if self.is_denied(report, self_advisories) {
+            self.exit_with_failure = true;
+        }
+    }
+
+    /// Print the combined results of auditing several lockfiles
+    pub fn print_summary(&mut self, reports: &[(PathBuf, rustsec::Report)]) {
+        match self.config.format {
+            OutputFormat::Json => {
+                let reports: Map<String, &rustsec::Report> = reports
+                    .iter()
+                    .map(|(path, report)| (path.display().to_string(), report))
+                    .collect();
+
+                serde_json::to_writer(io::stdout(), &reports).unwrap();
+                io::stdout().flush().unwrap();
+            }
+            OutputFormat::Terminal => {
+                for (path, report) in reports {
+                    let count = report.vulnerabilities.count;
+                    let warnings = report.warnings.values().map(Vec::len).sum::<usize>() as u64;
+
+                    if report.vulnerabilities.found {
+                        status_err!(
+                            "{}: {} {}, {} {}",
+                            path.display(),
+                            count,
+                            if count == 1 {
+                                "vulnerability"
+                            } else {
+                                "vulnerabilities"
+                            },
+                            warnings,
+                            self.warning_word(warnings)
+                        );
+                    } else if warnings > 0 {
+                        status_warn!(
+                            "{}: {} {}",
+                            path.display(),
+                            warnings,
+                            self.warning_word(warnings)
+                        );
+                    } else if !self.config.is_quiet() {
+                        status_ok!("Success", "{}: no vulnerabilities found", path.display());
+                    }
+                }
+            }
+            _ => (),
+        }
+
+        if self.exit_with_failure {
+            std::process::exit(1);
+        }
+    }
+
+    /// Print the differences between the reports for two lockfiles.
+    ///
+    /// Added findings are shown in full, along with the dependency tree which
+    /// pulls them into the new lockfile. Only JSON and terminal output are
+    /// supported: other formats are shown as terminal output.
+    pub fn print_diff(&mut self, diff: &Diff, new_lockfile: &Lockfile) {
+        if self.config.format == OutputFormat::Json {
+            serde_json::to_writer(io::stdout(), diff).unwrap();
+            io::stdout().flush().unwrap();
+            return;
+        }
+
+        let tree = new_lockfile
+            .dependency_tree()
+            .expect("invalid Cargo.lock dependency tree");
+
+        for vulnerability in &diff.added.vulnerabilities {
+            self.print_vulnerability(vulnerability, &tree);
+        }
+
+        for warning in &diff.added.warnings {
+            self.print_warning(warning, &tree);
+        }
+
+        for vulnerability in &diff.added.vulnerabilities {
+            status_err!(
+                "introduces {} via {}{}",
+                vulnerability.advisory.id,
+                package_description(&vulnerability.package),
+                added_package_note(diff, &vulnerability.package)
+            );
+        }
+
+        for warning in &diff.added.warnings {
+            let description = format!(
+                "introduces {} warning{} via {}{}",
+                warning.kind.as_str(),
+                advisory_id_note(warning),
+                package_description(&warning.package),
+                added_package_note(diff, &warning.package)
+            );
+
+            if self.deny_warning_kinds.contains(&warning.kind) {
+                status_err!(description);
+                self.exit_with_failure = true;
+            } else {
+                status_warn!(description);
+            }
+        }
+
+        for vulnerability in &diff.removed.vulnerabilities {
+            status_ok!(
+                "Resolved",
+                "{} in {}",
+                vulnerability.advisory.id,
+                package_description(&vulnerability.package)
+            );
+        }
+
+        for warning in &diff.removed.warnings {
+            status_ok!(
+                "Resolved",
+                "{} warning{} in {}",
+                warning.kind.as_str(),
+                advisory_id_note(warning),
+                package_description(&warning.package)
+            );
+        }
+
+        if !self.config.is_quiet() {
+            let unchanged = diff.unchanged.vulnerabilities.len() + diff.unchanged.warnings.len();
+
+            if unchanged > 0 {
+                status_ok!("Unchanged", "{} existing findings", unchanged);
+            }
+
+            status_ok!(
+                "Dependencies",
+                "{} added, {} removed",
+                diff.added_packages.len(),
+                diff.removed_packages.len()
+            );
+
+            if diff.added.is_empty() {
+                status_ok!("Success", "no new vulnerabilities or warnings");
+            }
+        }
+
+        if self.exit_with_failure {
+            std::process::exit(1);
+        }
+    }
+
+    /// Print a report, recording whether it should cause the audit to fail
+    fn present(
+        &mut self,
+        report: &rustsec::Report,
+        self_advisories: &[rustsec::Advisory],
+        lockfile: &Lockfile,
+    ) {
+        // Denied warnings fail the audit regardless of the output format.
+        // Once we've printed the whole report(s), we'll bail out of the whole program.
+        if self.is_denied(report, self_advisories) {
+            self.exit_with_failure = true;
+        }
+
         if self.config.format == OutputFormat::Json {
             serde_json::to_writer(io::stdout(), &report).unwrap();
             io::stdout().flush().unwrap();
             return;
         }
 
-        // We'll set this to true if (e.g.) we see a warning and have deny-warnings enabled.
-        // Once we've printed the whole report, we'll bail out of the whole program.
-        let mut exit_with_failure = false;
+        if self.config.format == OutputFormat::CycloneDx {
+            let bom = Bom::new(lockfile, report);
+            serde_json::to_writer_pretty(io::stdout(), &bom).unwrap();
+            println!();
+            return;
+        }
+
+        if self.config.format == OutputFormat::Junit {
+            let junit = JunitReport::generate(
+                report,
+                lockfile,
+                &self.lockfile_path,
+                &self.deny_warning_kinds,
+            );
+            print!("{}", junit);
+            return;
+        }
+
+        if self.config.format == OutputFormat::Markdown {
+            let markdown = MarkdownReport::generate(
+                report,
+                lockfile,
+                &self.lockfile_path,
+                &self.deny_warning_kinds,
+                self.config.show_tree.unwrap_or(true),
+            );
+            print!("{}", markdown);
+            return;
+        }
+
+        if self.config.format == OutputFormat::Sarif {
+            let sarif = SarifLog::generate(report, &self.lockfile_path, &self.deny_warning_kinds);
+            serde_json::to_writer_pretty(io::stdout(), &sarif).unwrap();
+            println!();
+            return;
+        }
 
         let tree = lockfile
             .dependency_tree()
@@ -116,6 +355,17 @@ impl Presenter {
             println!();
         }
 
+        // Ignore rules which have expired no longer suppress their advisories
+        for rule in &report.expired_ignores {
+            let expires = rule.expires.as_ref().unwrap();
+
+            if self.config.deny.contains(&DenyOption::Warnings) {
+                status_err!("ignore rule for {} expired on {}", rule.id, expires);
+            } else {
+                status_warn!("ignore rule for {} expired on {}", rule.id, expires);
+            }
+        }
+
         if report.vulnerabilities.found {
             if report.vulnerabilities.count == 1 {
                 status_err!("1 vulnerability found!");
@@ -143,7 +393,6 @@ impl Presenter {
                     num_denied,
                     self.warning_word(num_denied)
                 );
-                exit_with_failure = true;
             }
             if num_not_denied > 0 {
                 status_warn!(
@@ -160,16 +409,21 @@ impl Presenter {
 
             if self.config.deny.contains(&DenyOption::Warnings) {
                 status_err!(upgrade_msg);
-                exit_with_failure = true;
             } else {
                 status_warn!(upgrade_msg);
             }
         }
+    }
 
-        // TODO(tarcieri): better unify this with vulnerabilities handling
-        if exit_with_failure {
-            std::process::exit(1);
-        }
+    /// Does the given report contain anything denied by the `--deny` options
+    /// (e.g. a warning with deny-warnings enabled)?
+    fn is_denied(&self, report: &rustsec::Report, self_advisories: &[rustsec::Advisory]) -> bool {
+        let deny_warnings = self.config.deny.contains(&DenyOption::Warnings);
+
+        (deny_warnings && (!report.expired_ignores.is_empty() || !self_advisories.is_empty()))
+            || report.warnings.iter().any(|(kind, warnings)| {
+                !warnings.is_empty() && self.deny_warning_kinds.contains(kind)
+            })
     }
 
     /// Print information about the given vulnerability
@@ -186,6 +440,14 @@ impl Presenter {
         );
         self.print_metadata(&vulnerability.advisory, Red);
 
+        if !vulnerability.platforms.is_empty() {
+            self.print_attr(Red, "Platforms:    ", vulnerability.platforms.join(", "));
+        }
+
+        if let Some(reachability) = vulnerability.reachability {
+            self.print_attr(Red, "Reachability: ", reachability.as_str());
+        }
+
         if vulnerability.versions.patched().is_empty() {
             self.print_attr(Red, "Solution:     ", "No safe upgrade is available!");
         } else {
@@ -245,6 +507,10 @@ impl Presenter {
         self.print_attr(color, "Date:         ", &metadata.date);
         self.print_attr(color, "ID:           ", &metadata.id);
 
+        if let Some(source) = &metadata.source {
+            self.print_attr(color, "Registry:     ", source.display_registry_name());
+        }
+
         if let Some(url) = metadata.id.url() {
             self.print_attr(color, "URL:          ", &url);
         } else if let Some(url) = &metadata.url {
@@ -273,6 +539,12 @@ impl Presenter {
             return;
         }
 
+        // Toolchain components (e.g. `std`) aren't in the lockfile
+        let package_node = match tree.nodes().get(&Dependency::from(package)) {
+            Some(node) => *node,
+            None => return,
+        };
+
         terminal::status::Status::new()
             .bold()
             .color(color)
@@ -280,8 +552,29 @@ impl Presenter {
             .print_stdout("")
             .unwrap();
 
-        let package_node = tree.nodes()[&Dependency::from(package)];
         tree.render(&mut io::stdout(), package_node, EdgeDirection::Incoming)
             .unwrap();
     }
 }
+
+/// Describe a package by its name and version
+fn package_description(package: &Package) -> String {
+    format!("{} {}", package.name, package.version)
+}
+
+/// Note containing the ID of the advisory for a warning, if it has one
+fn advisory_id_note(warning: &rustsec::Warning) -> String {
+    match &warning.advisory {
+        Some(advisory) => format!(" ({})", advisory.id),
+        None => String::new(),
+    }
+}
+
+/// Note for findings in packages which were newly added to the lockfile
+fn added_package_note(diff: &Diff, package: &Package) -> &'static str {
+    if diff.is_added_package(package) {
+        " (new dependency)"
+    } else {
+        ""
+    }
+}
@@ -0,0 +1,224 @@
+//! JUnit XML output (as understood by e.g. Jenkins and GitLab CI)
+//!
+//! Every package in the lockfile is reported as a test case, which fails if
+//! the package has any vulnerabilities or denied warnings.
+
+use rustsec::{
+    advisory,
+    cargo_lock::{Dependency, Lockfile},
+    warning, Report,
+};
+use std::{
+    collections::{BTreeMap as Map, BTreeSet as Set},
+    fmt::{self, Write as _},
+    path::Path,
+};
+
+/// JUnit XML report
+#[derive(Debug)]
+pub struct JunitReport {
+    /// Name of the test suite (i.e. the path to the lockfile)
+    name: String,
+
+    /// Test cases (i.e. packages), along with their failures
+    test_cases: Vec<(Dependency, Vec<Failure>)>,
+}
+
+impl JunitReport {
+    /// Generate a JUnit report for the given lockfile.
+    ///
+    /// Warnings of the given denied kinds are reported as failures.
+    pub fn generate(
+        report: &Report,
+        lockfile: &Lockfile,
+        lockfile_path: &Path,
+        deny_warning_kinds: &Set<warning::Kind>,
+    ) -> Self {
+        let mut failures: Map<Dependency, Vec<Failure>> = Map::new();
+
+        for vulnerability in &report.vulnerabilities.list {
+            failures
+                .entry(Dependency::from(&vulnerability.package))
+                .or_default()
+                .push(Failure::new(
+                    "vulnerability",
+                    &vulnerability.advisory,
+                    Some(&vulnerability.versions),
+                ));
+        }
+
+        for warning in report.warnings.values().flatten() {
+            if !deny_warning_kinds.contains(&warning.kind) {
+                continue;
+            }
+
+            let failure = match &warning.advisory {
+                Some(advisory) => {
+                    Failure::new(warning.kind.as_str(), advisory, warning.versions.as_ref())
+                }
+                None => Failure {
+                    kind: warning.kind.as_str().to_owned(),
+                    message: format!("{} crate", warning.kind.as_str()),
+                    details: format!(
+                        "{} {} is {}",
+                        warning.package.name,
+                        warning.package.version,
+                        warning.kind.as_str()
+                    ),
+                },
+            };
+
+            failures
+                .entry(Dependency::from(&warning.package))
+                .or_default()
+                .push(failure);
+        }
+
+        let mut test_cases: Vec<_> = lockfile
+            .packages
+            .iter()
+            .map(|package| {
+                let dependency = Dependency::from(package);
+                let failures = failures.remove(&dependency).unwrap_or_default();
+                (dependency, failures)
+            })
+            .collect();
+
+        // Packages which aren't in the lockfile (i.e. toolchain components)
+        test_cases.extend(failures);
+
+        JunitReport {
+            name: lockfile_path.display().to_string(),
+            test_cases,
+        }
+    }
+}
+
+impl fmt::Display for JunitReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tests = self.test_cases.len();
+        let failures = self
+            .test_cases
+            .iter()
+            .filter(|(_, failures)| !failures.is_empty())
+            .count();
+
+        writeln!(f, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            f,
+            r#"<testsuites name="cargo-audit" tests="{}" failures="{}">"#,
+            tests, failures
+        )?;
+        writeln!(
+            f,
+            r#"  <testsuite name="{}" tests="{}" failures="{}" errors="0" skipped="0">"#,
+            escape(&self.name),
+            tests,
+            failures
+        )?;
+
+        for (dependency, failures) in &self.test_cases {
+            write!(
+                f,
+                r#"    <testcase classname="{}" name="{} {}""#,
+                escape(&self.name),
+                escape(dependency.name.as_str()),
+                dependency.version
+            )?;
+
+            if failures.is_empty() {
+                writeln!(f, "/>")?;
+                continue;
+            }
+
+            writeln!(f, ">")?;
+
+            for failure in failures {
+                writeln!(
+                    f,
+                    r#"      <failure type="{}" message="{}">{}</failure>"#,
+                    failure.kind,
+                    escape(&failure.message),
+                    escape(&failure.details)
+                )?;
+            }
+
+            writeln!(f, "    </testcase>")?;
+        }
+
+        writeln!(f, "  </testsuite>")?;
+        writeln!(f, "</testsuites>")
+    }
+}
+
+/// Failure of a test case (i.e. a vulnerability or denied warning)
+#[derive(Debug)]
+struct Failure {
+    /// Kind of failure (`vulnerability` or the warning kind)
+    kind: String,
+
+    /// Summary of the failure
+    message: String,
+
+    /// Detailed description of the failure
+    details: String,
+}
+
+impl Failure {
+    fn new(
+        kind: &str,
+        metadata: &advisory::Metadata,
+        versions: Option<&advisory::Versions>,
+    ) -> Self {
+        let mut details = format!("ID: {}\nTitle: {}\n", metadata.id, metadata.title);
+
+        if let Some(url) = metadata
+            .id
+            .url()
+            .or_else(|| metadata.url.as_ref().map(ToString::to_string))
+        {
+            writeln!(details, "URL: {}", url).unwrap();
+        }
+
+        let patched = versions.map(advisory::Versions::patched).unwrap_or(&[]);
+
+        if patched.is_empty() {
+            writeln!(details, "Solution: No safe upgrade is available!").unwrap();
+        } else {
+            writeln!(
+                details,
+                "Solution: Upgrade to {}",
+                patched
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" OR ")
+            )
+            .unwrap();
+        }
+
+        Failure {
+            kind: kind.to_owned(),
+            message: format!("{}: {}", metadata.id, metadata.title),
+            details,
+        }
+    }
+}
+
+/// Escape XML special characters
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
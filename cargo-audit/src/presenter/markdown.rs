@@ -0,0 +1,302 @@
+//! Markdown output (e.g. for posting as a pull request comment)
+
+use rustsec::{
+    advisory,
+    cargo_lock::{
+        dependency::{graph::EdgeDirection, Tree},
+        Dependency, Lockfile, Package,
+    },
+    warning, Report,
+};
+use std::{collections::BTreeSet as Set, fmt, path::Path};
+
+/// Markdown report
+#[derive(Debug)]
+pub struct MarkdownReport {
+    /// Path to the audited lockfile
+    lockfile_path: String,
+
+    /// Number of packages in the lockfile
+    dependency_count: usize,
+
+    /// Vulnerabilities and warnings, grouped by kind
+    sections: Vec<Section>,
+
+    /// Inverse dependency trees of the affected packages
+    trees: Vec<(Dependency, String)>,
+}
+
+impl MarkdownReport {
+    /// Generate a Markdown report for the given lockfile.
+    ///
+    /// Dependency trees are included if `show_tree` is set.
+    pub fn generate(
+        report: &Report,
+        lockfile: &Lockfile,
+        lockfile_path: &Path,
+        deny_warning_kinds: &Set<warning::Kind>,
+        show_tree: bool,
+    ) -> Self {
+        let mut sections = vec![];
+        let mut packages = vec![];
+
+        if !report.vulnerabilities.list.is_empty() {
+            sections.push(Section {
+                title: "Vulnerabilities".to_owned(),
+                rows: report
+                    .vulnerabilities
+                    .list
+                    .iter()
+                    .map(|vuln| Row::new(&vuln.package, Some(&vuln.advisory), Some(&vuln.versions)))
+                    .collect(),
+            });
+
+            packages.extend(report.vulnerabilities.list.iter().map(|vuln| &vuln.package));
+        }
+
+        for (kind, warnings) in &report.warnings {
+            if warnings.is_empty() {
+                continue;
+            }
+
+            let denied = if deny_warning_kinds.contains(kind) {
+                " (denied)"
+            } else {
+                ""
+            };
+
+            sections.push(Section {
+                title: format!("Warnings: {}{}", kind.as_str(), denied),
+                rows: warnings
+                    .iter()
+                    .map(|warning| {
+                        Row::new(
+                            &warning.package,
+                            warning.advisory.as_ref(),
+                            warning.versions.as_ref(),
+                        )
+                    })
+                    .collect(),
+            });
+
+            packages.extend(warnings.iter().map(|warning| &warning.package));
+        }
+
+        let mut trees = vec![];
+
+        if show_tree && !packages.is_empty() {
+            let tree = lockfile
+                .dependency_tree()
+                .expect("invalid Cargo.lock dependency tree");
+
+            for package in packages {
+                let dependency = Dependency::from(package);
+
+                // Toolchain components (e.g. `std`) aren't in the lockfile
+                if !tree.nodes().contains_key(&dependency) {
+                    continue;
+                }
+
+                if trees.iter().all(|(dep, _)| *dep != dependency) {
+                    let rendered = render_tree(&tree, &dependency);
+                    trees.push((dependency, rendered));
+                }
+            }
+        }
+
+        MarkdownReport {
+            lockfile_path: lockfile_path.display().to_string(),
+            dependency_count: lockfile.packages.len(),
+            sections,
+            trees,
+        }
+    }
+}
+
+impl fmt::Display for MarkdownReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "## `cargo audit` report")?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "Scanned `{}` ({} crate dependencies)",
+            self.lockfile_path, self.dependency_count
+        )?;
+        writeln!(f)?;
+
+        if self.sections.is_empty() {
+            writeln!(f, "No vulnerabilities or warnings found.")?;
+            return Ok(());
+        }
+
+        for section in &self.sections {
+            writeln!(f, "### {}", section.title)?;
+            writeln!(f)?;
+            writeln!(
+                f,
+                "| Crate | Version | Advisory | Severity | Title | Patched versions |"
+            )?;
+            writeln!(f, "|---|---|---|---|---|---|")?;
+
+            for row in &section.rows {
+                writeln!(
+                    f,
+                    "| `{}` | {} | {} | {} | {} | {} |",
+                    row.name, row.version, row.advisory, row.severity, row.title, row.patched
+                )?;
+            }
+
+            writeln!(f)?;
+        }
+
+        for (dependency, tree) in &self.trees {
+            writeln!(f, "<details>")?;
+            writeln!(
+                f,
+                "<summary>Dependency tree for <code>{} {}</code></summary>",
+                dependency.name, dependency.version
+            )?;
+            writeln!(f)?;
+            writeln!(f, "```")?;
+            write!(f, "{}", tree)?;
+            writeln!(f, "```")?;
+            writeln!(f)?;
+            writeln!(f, "</details>")?;
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Table of vulnerabilities or warnings of a particular kind
+#[derive(Debug)]
+struct Section {
+    /// Title of the section
+    title: String,
+
+    /// Rows of the table
+    rows: Vec<Row>,
+}
+
+/// Table row describing a vulnerability or warning, with every cell
+/// already formatted as Markdown
+#[derive(Debug)]
+struct Row {
+    name: String,
+    version: String,
+    advisory: String,
+    severity: String,
+    title: String,
+    patched: String,
+}
+
+impl Row {
+    fn new(
+        package: &Package,
+        metadata: Option<&advisory::Metadata>,
+        versions: Option<&advisory::Versions>,
+    ) -> Self {
+        let advisory = match metadata {
+            Some(metadata) => match metadata.id.url() {
+                Some(url) => format!("[{}]({})", metadata.id, url),
+                None => escape(metadata.id.as_str()),
+            },
+            None => "-".to_owned(),
+        };
+
+        let severity = metadata
+            .and_then(|metadata| metadata.cvss.as_ref())
+            .map(|cvss| format!("{} ({:.1})", cvss.severity().as_str(), cvss.score()))
+            .unwrap_or_else(|| "-".to_owned());
+
+        let title = metadata
+            .map(|metadata| escape(&metadata.title))
+            .unwrap_or_else(|| "-".to_owned());
+
+        let patched = match versions.map(advisory::Versions::patched) {
+            Some(patched) if !patched.is_empty() => patched
+                .iter()
+                .map(|req| format!("`{}`", req))
+                .collect::<Vec<_>>()
+                .join(" OR "),
+            _ => "none".to_owned(),
+        };
+
+        Row {
+            name: package.name.to_string(),
+            version: package.version.to_string(),
+            advisory,
+            severity,
+            title,
+            patched,
+        }
+    }
+}
+
+/// Render the inverse dependency tree for the given package
+fn render_tree(tree: &Tree, dependency: &Dependency) -> String {
+    let mut rendered = vec![];
+
+    tree.render(
+        &mut rendered,
+        tree.nodes()[dependency],
+        EdgeDirection::Incoming,
+    )
+    .unwrap();
+
+    code_block_contents(&String::from_utf8_lossy(&rendered))
+}
+
+/// Prepare text for inclusion in a fenced code block
+fn code_block_contents(s: &str) -> String {
+    let mut contents = s.to_owned();
+
+    if !contents.ends_with('\n') {
+        contents.push('\n');
+    }
+
+    // Don't allow the text to close the code block early
+    contents.replace("```", "` ` `")
+}
+
+/// Escape text for use in a Markdown table cell
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c {
+            '|' => escaped.push_str("\\|"),
+            '\n' | '\r' => escaped.push(' '),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{code_block_contents, escape};
+
+    #[test]
+    fn escape_table_cell() {
+        assert_eq!(escape("a | b"), "a \\| b");
+        assert_eq!(escape("<script>"), "&lt;script&gt;");
+        assert_eq!(escape("multi\nline\r\ntitle"), "multi line  title");
+        assert_eq!(escape("`code` & more"), "`code` & more");
+    }
+
+    #[test]
+    fn code_block_fences() {
+        assert_eq!(code_block_contents("tree"), "tree\n");
+        assert_eq!(code_block_contents("tree\n"), "tree\n");
+        assert_eq!(
+            code_block_contents("a\n```\n# heading\n"),
+            "a\n` ` `\n# heading\n"
+        );
+        assert!(!code_block_contents("````").contains("```"));
+    }
+}
@@ -0,0 +1,384 @@
+//! SARIF (Static Analysis Results Interchange Format) v2.1.0 output
+//!
+//! <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+
+use rustsec::{advisory, cargo_lock::Package, warning, Report, Vulnerability, Warning};
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap as Map, BTreeSet as Set},
+    fs,
+    path::Path,
+};
+
+/// URI of the SARIF 2.1.0 JSON schema
+const SCHEMA_URI: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// SARIF version generated
+const SARIF_VERSION: &str = "2.1.0";
+
+/// Root object of a SARIF file
+#[derive(Debug, Serialize)]
+pub struct SarifLog {
+    /// JSON schema
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+
+    /// SARIF version
+    version: &'static str,
+
+    /// Runs of analysis tools (`cargo audit` only ever has one)
+    runs: Vec<Run>,
+}
+
+impl SarifLog {
+    /// Generate a SARIF log from the given report.
+    ///
+    /// Warnings of the given denied kinds are reported as errors.
+    pub fn generate(
+        report: &Report,
+        lockfile_path: &Path,
+        deny_warning_kinds: &Set<warning::Kind>,
+    ) -> Self {
+        let mut run = Run::new(lockfile_path);
+
+        for vulnerability in &report.vulnerabilities.list {
+            run.add_vulnerability(vulnerability);
+        }
+
+        for warnings in report.warnings.values() {
+            for warning in warnings {
+                run.add_warning(warning, deny_warning_kinds.contains(&warning.kind));
+            }
+        }
+
+        SarifLog {
+            schema: SCHEMA_URI,
+            version: SARIF_VERSION,
+            runs: vec![run],
+        }
+    }
+}
+
+/// Single run of `cargo audit`
+#[derive(Debug, Serialize)]
+struct Run {
+    /// Information about `cargo audit` and the rules (i.e. advisories) it
+    /// checked for
+    tool: Tool,
+
+    /// Results (i.e. vulnerabilities and warnings)
+    results: Vec<SarifResult>,
+
+    /// Lockfile the results refer to
+    #[serde(skip)]
+    lockfile: Lockfile,
+
+    /// Indexes of the rules in `tool.driver.rules`
+    #[serde(skip)]
+    rule_indexes: Map<String, usize>,
+}
+
+impl Run {
+    fn new(lockfile_path: &Path) -> Self {
+        Run {
+            tool: Tool {
+                driver: Driver {
+                    name: "cargo-audit",
+                    version: crate::VERSION,
+                    information_uri: "https://rustsec.org",
+                    rules: vec![],
+                },
+            },
+            results: vec![],
+            lockfile: Lockfile::new(lockfile_path),
+            rule_indexes: Map::new(),
+        }
+    }
+
+    fn add_vulnerability(&mut self, vulnerability: &Vulnerability) {
+        let rule_index = self.advisory_rule(&vulnerability.advisory, Level::Error);
+
+        let message = format!(
+            "{} {}: {}",
+            vulnerability.package.name, vulnerability.package.version, vulnerability.advisory.title
+        );
+
+        self.add_result(rule_index, Level::Error, message, &vulnerability.package);
+    }
+
+    fn add_warning(&mut self, warning: &Warning, denied: bool) {
+        let level = if denied { Level::Error } else { Level::Warning };
+
+        let (rule_index, message) = match &warning.advisory {
+            Some(advisory) => (
+                self.advisory_rule(advisory, level),
+                format!(
+                    "{} {} is {}: {}",
+                    warning.package.name,
+                    warning.package.version,
+                    warning.kind.as_str(),
+                    advisory.title
+                ),
+            ),
+            None => (
+                self.warning_rule(warning.kind, level),
+                format!(
+                    "{} {} is {}",
+                    warning.package.name,
+                    warning.package.version,
+                    warning.kind.as_str()
+                ),
+            ),
+        };
+
+        self.add_result(rule_index, level, message, &warning.package);
+    }
+
+    fn add_result(&mut self, rule_index: usize, level: Level, message: String, package: &Package) {
+        let rule_id = self.tool.driver.rules[rule_index].id.clone();
+
+        let location = Location {
+            physical_location: PhysicalLocation {
+                artifact_location: ArtifactLocation {
+                    uri: self.lockfile.uri.clone(),
+                },
+                region: self
+                    .lockfile
+                    .line(package)
+                    .map(|start_line| Region { start_line }),
+            },
+        };
+
+        self.results.push(SarifResult {
+            rule_id,
+            rule_index,
+            level,
+            message: Message { text: message },
+            locations: vec![location],
+        });
+    }
+
+    /// Get the index of the rule for the given advisory, adding it if needed
+    fn advisory_rule(&mut self, metadata: &advisory::Metadata, level: Level) -> usize {
+        let id = metadata.id.to_string();
+
+        if let Some(index) = self.rule_indexes.get(&id) {
+            return *index;
+        }
+
+        let help_uri = metadata
+            .id
+            .url()
+            .or_else(|| metadata.url.as_ref().map(ToString::to_string));
+
+        let rule = Rule {
+            id: id.clone(),
+            name: metadata.package.to_string(),
+            short_description: Message {
+                text: metadata.title.clone(),
+            },
+            full_description: Message {
+                text: metadata.description.clone(),
+            },
+            help_uri,
+            default_configuration: Configuration { level },
+            properties: RuleProperties {
+                security_severity: metadata
+                    .cvss
+                    .as_ref()
+                    .map(|cvss| format!("{:.1}", cvss.score())),
+                cvss: metadata.cvss.as_ref().map(ToString::to_string),
+                tags: vec!["security"],
+            },
+        };
+
+        self.add_rule(id, rule)
+    }
+
+    /// Get the index of the rule for warnings of the given kind which aren't
+    /// associated with an advisory (e.g. yanked crates), adding it if needed
+    fn warning_rule(&mut self, kind: warning::Kind, level: Level) -> usize {
+        let id = kind.as_str().to_owned();
+
+        if let Some(index) = self.rule_indexes.get(&id) {
+            return *index;
+        }
+
+        let rule = Rule {
+            id: id.clone(),
+            name: id.clone(),
+            short_description: Message {
+                text: format!("{} crate", kind.as_str()),
+            },
+            full_description: Message {
+                text: format!("Dependency on a {} crate", kind.as_str()),
+            },
+            help_uri: None,
+            default_configuration: Configuration { level },
+            properties: RuleProperties {
+                security_severity: None,
+                cvss: None,
+                tags: vec!["security"],
+            },
+        };
+
+        self.add_rule(id, rule)
+    }
+
+    fn add_rule(&mut self, id: String, rule: Rule) -> usize {
+        let index = self.tool.driver.rules.len();
+        self.tool.driver.rules.push(rule);
+        self.rule_indexes.insert(id, index);
+        index
+    }
+}
+
+/// Analysis tool
+#[derive(Debug, Serialize)]
+struct Tool {
+    driver: Driver,
+}
+
+/// Tool component which performed the analysis
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<Rule>,
+}
+
+/// Rule (i.e. advisory or warning kind) results are reported for
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Rule {
+    id: String,
+    name: String,
+    short_description: Message,
+    full_description: Message,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<String>,
+    default_configuration: Configuration,
+    properties: RuleProperties,
+}
+
+/// Default configuration of a rule
+#[derive(Debug, Serialize)]
+struct Configuration {
+    level: Level,
+}
+
+/// Additional rule properties
+#[derive(Debug, Serialize)]
+struct RuleProperties {
+    /// CVSS score, as understood by GitHub code scanning
+    #[serde(rename = "security-severity", skip_serializing_if = "Option::is_none")]
+    security_severity: Option<String>,
+
+    /// CVSS vector string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cvss: Option<String>,
+
+    tags: Vec<&'static str>,
+}
+
+/// Result (i.e. a vulnerability or warning) of the analysis
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: String,
+    rule_index: usize,
+    level: Level,
+    message: Message,
+    locations: Vec<Location>,
+}
+
+/// Severity level of a result
+#[derive(Copy, Clone, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Level {
+    Warning,
+    Error,
+}
+
+/// Plain text message
+#[derive(Debug, Serialize)]
+struct Message {
+    text: String,
+}
+
+/// Location of a result
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+}
+
+/// Location of a result in a file
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<Region>,
+}
+
+/// File containing a result
+#[derive(Debug, Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+/// Region of a file containing a result
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: usize,
+}
+
+/// Lockfile results are located in
+#[derive(Debug)]
+struct Lockfile {
+    /// URI of the lockfile (relative to the current directory)
+    uri: String,
+
+    /// Contents of the lockfile, if it could be read
+    contents: Option<String>,
+}
+
+impl Lockfile {
+    fn new(path: &Path) -> Self {
+        // Lockfiles read from STDIN can't be located
+        if path == Path::new("-") {
+            return Lockfile {
+                uri: "Cargo.lock".to_owned(),
+                contents: None,
+            };
+        }
+
+        Lockfile {
+            uri: path.to_string_lossy().replace('\\', "/"),
+            contents: fs::read_to_string(path).ok(),
+        }
+    }
+
+    /// Find the (1-based) line number of the `[[package]]` entry for the
+    /// given package
+    fn line(&self, package: &Package) -> Option<usize> {
+        let name = format!("name = \"{}\"", package.name);
+        let version = format!("version = \"{}\"", package.version);
+        let lines: Vec<&str> = self.contents.as_ref()?.lines().collect();
+
+        lines
+            .windows(2)
+            .position(|window| window[0].trim() == name && window[1].trim() == version)
+            .map(|i| {
+                if i > 0 && lines[i - 1].trim() == "[[package]]" {
+                    i
+                } else {
+                    i + 1
+                }
+            })
+    }
+}
@@ -0,0 +1,423 @@
+//! Reachability analysis: determine whether the functions affected by a
+//! vulnerability are referenced by the crates which depend on it.
+//!
+//! This is a purely syntactic analysis of the Rust sources of the workspace
+//! and of the (already downloaded) dependents of each vulnerable package.
+//! Paths are resolved against `use` declarations within each file, and calls
+//! to methods with the same name as an affected function are considered
+//! references if the file also references the vulnerable crate.
+//!
+//! Dependencies renamed in a dependent's `Cargo.toml`, and modules of the
+//! vulnerable crate which a dependent publicly re-exports, aren't followed:
+//! the reachability is unknown in those cases.
+
+use rustsec::{
+    advisory::affected::FunctionPath,
+    cargo_lock::{Lockfile, Package},
+    Reachability, Report, Vulnerability,
+};
+use std::{
+    collections::{BTreeMap as Map, BTreeSet as Set},
+    fs,
+    path::{Path, PathBuf},
+};
+use syn::{
+    parse::Parser, punctuated::Punctuated, visit::Visit, Expr, ExprMethodCall, ItemExternCrate,
+    ItemUse, Macro, Token, UseTree, Visibility,
+};
+
+/// Directories in the sources of a dependency which aren't part of the library
+const EXCLUDED_DEPENDENCY_DIRS: &[&str] = &["benches", "examples", "tests"];
+
+/// Analyze the reachability of all vulnerabilities in the given report
+pub fn analyze(report: &mut Report, lockfile: &Lockfile, workspace_root: &Path) {
+    let mut analyzer = Analyzer::new(workspace_root);
+
+    for vulnerability in &mut report.vulnerabilities.list {
+        vulnerability.reachability = Some(analyzer.reachability(vulnerability, lockfile));
+    }
+}
+
+/// Reachability analyzer which caches the references found in each crate
+struct Analyzer {
+    /// Root directory of the workspace being audited
+    workspace_root: PathBuf,
+
+    /// Cargo's directory of unpacked registry sources (i.e. `~/.cargo/registry/src`)
+    registry_src: Option<PathBuf>,
+
+    /// References found in the workspace (`None` if its sources couldn't be parsed)
+    workspace: Option<Option<References>>,
+
+    /// References found in registry dependencies, keyed by `name-version`
+    dependencies: Map<String, Option<References>>,
+}
+
+impl Analyzer {
+    /// Create a new analyzer for the workspace at the given path
+    fn new(workspace_root: &Path) -> Self {
+        Self {
+            workspace_root: workspace_root.to_owned(),
+            registry_src: home::cargo_home()
+                .ok()
+                .map(|cargo_home| cargo_home.join("registry").join("src")),
+            workspace: None,
+            dependencies: Map::new(),
+        }
+    }
+
+    /// Determine the reachability of the given vulnerability
+    fn reachability(&mut self, vulnerability: &Vulnerability, lockfile: &Lockfile) -> Reachability {
+        let functions = match vulnerability.affected_functions() {
+            Some(functions) if !functions.is_empty() => functions,
+            _ => return Reachability::Unknown,
+        };
+
+        let dependents: Vec<&Package> = lockfile
+            .packages
+            .iter()
+            .filter(|package| {
+                package
+                    .dependencies
+                    .iter()
+                    .any(|dependency| dependency.matches(&vulnerability.package))
+            })
+            .collect();
+
+        // Packages without dependents are workspace members themselves
+        if dependents.is_empty() {
+            return Reachability::Unknown;
+        }
+
+        let mut result = Reachability::NotReferenced;
+
+        for dependent in dependents {
+            match self.references(dependent) {
+                Some(references) => {
+                    if functions
+                        .iter()
+                        .any(|function| references.contains(function))
+                    {
+                        return Reachability::Reachable;
+                    }
+
+                    // Paths under another name, or used by the dependent's
+                    // own dependents, can't be resolved
+                    if references
+                        .renamed
+                        .contains(vulnerability.package.name.as_str())
+                        || functions
+                            .iter()
+                            .any(|function| references.reexports(function))
+                    {
+                        result = Reachability::Unknown;
+                    }
+                }
+                None => result = Reachability::Unknown,
+            }
+        }
+
+        result
+    }
+
+    /// Get the references made by the given package, if its sources are available
+    fn references(&mut self, package: &Package) -> Option<&References> {
+        match &package.source {
+            None => {
+                let workspace_root = &self.workspace_root;
+                self.workspace
+                    .get_or_insert_with(|| References::scan(workspace_root, &[]))
+                    .as_ref()
+            }
+            Some(source) if source.is_registry() => {
+                let dir_name = format!("{}-{}", package.name, package.version);
+                let registry_src = self.registry_src.as_ref();
+
+                self.dependencies
+                    .entry(dir_name)
+                    .or_insert_with_key(|dir_name| {
+                        let index_dirs = fs::read_dir(registry_src?).ok()?;
+
+                        index_dirs
+                            .filter_map(|entry| entry.ok())
+                            .map(|entry| entry.path().join(dir_name))
+                            .find(|path| path.is_dir())
+                            .and_then(|path| References::scan(&path, EXCLUDED_DEPENDENCY_DIRS))
+                    })
+                    .as_ref()
+            }
+            Some(_) => None,
+        }
+    }
+}
+
+/// References to paths and methods found in the sources of a crate
+#[derive(Debug, Default)]
+struct References {
+    /// Paths, resolved against the `use` declarations of the file they occur in
+    paths: Set<Vec<String>>,
+
+    /// Prefixes of glob imports (e.g. `foo::bar` for `use foo::bar::*`)
+    globs: Set<Vec<String>>,
+
+    /// Paths as written, which may refer to items in glob imports
+    unresolved: Set<Vec<String>>,
+
+    /// Names of called methods
+    methods: Set<String>,
+
+    /// Paths publicly re-exported by `pub use` (including glob prefixes)
+    /// and `pub extern crate`
+    reexported: Set<Vec<String>>,
+
+    /// Names of packages which dependencies are renamed from in `Cargo.toml`
+    renamed: Set<String>,
+}
+
+impl References {
+    /// Scan all `.rs` files in the given directory, skipping hidden and
+    /// `target` directories as well as any of the given top-level directories.
+    ///
+    /// Dependencies renamed in any `Cargo.toml` file are recorded too.
+    ///
+    /// Returns `None` if any of the files couldn't be read or parsed.
+    fn scan(dir: &Path, excluded_dirs: &[&str]) -> Option<Self> {
+        let mut references = Self::default();
+        let mut pending = vec![dir.to_owned()];
+
+        while let Some(current) = pending.pop() {
+            for entry in fs::read_dir(&current).ok()? {
+                let path = entry.ok()?.path();
+                let file_name = path.file_name()?.to_string_lossy();
+
+                if path.is_dir() {
+                    let excluded = file_name.starts_with('.')
+                        || file_name == "target"
+                        || (current == dir && excluded_dirs.contains(&file_name.as_ref()));
+
+                    if !excluded {
+                        pending.push(path);
+                    }
+                } else if path.extension() == Some("rs".as_ref()) {
+                    references.add_file(&path)?;
+                } else if file_name == "Cargo.toml" {
+                    references.add_manifest(&path)?;
+                }
+            }
+        }
+
+        Some(references)
+    }
+
+    /// Add the references in the given Rust source file
+    fn add_file(&mut self, path: &Path) -> Option<()> {
+        let file = syn::parse_file(&fs::read_to_string(path).ok()?).ok()?;
+
+        let mut visitor = FileVisitor::default();
+        visitor.visit_file(&file);
+
+        for path in visitor.paths {
+            if let Some(target) = visitor.aliases.get(&path[0]) {
+                self.paths
+                    .insert(target.iter().chain(&path[1..]).cloned().collect());
+            }
+
+            self.paths.insert(path.clone());
+            self.unresolved.insert(path);
+        }
+
+        self.globs.extend(visitor.globs);
+        self.methods.extend(visitor.methods);
+        self.reexported.extend(visitor.reexported);
+        Some(())
+    }
+
+    /// Add the renamed dependencies (i.e. `name = { package = "..." }`) in
+    /// the given Cargo manifest
+    fn add_manifest(&mut self, path: &Path) -> Option<()> {
+        let manifest: toml::Value = toml::from_str(&fs::read_to_string(path).ok()?).ok()?;
+        let mut pending = vec![&manifest];
+
+        while let Some(value) = pending.pop() {
+            if let Some(table) = value.as_table() {
+                if let Some(package) = table.get("package").and_then(|p| p.as_str()) {
+                    self.renamed.insert(package.to_owned());
+                }
+
+                pending.extend(table.values());
+            }
+        }
+
+        Some(())
+    }
+
+    /// Is a module (or the crate) containing the given function re-exported?
+    fn reexports(&self, function: &FunctionPath) -> bool {
+        let segments: Vec<&str> = function.iter().map(|segment| segment.as_str()).collect();
+
+        self.reexported.iter().any(|path| {
+            path.len() < segments.len() && path.iter().zip(&segments).all(|(a, b)| a == b)
+        })
+    }
+
+    /// Is the given function referenced?
+    fn contains(&self, function: &FunctionPath) -> bool {
+        let segments: Vec<&str> = function.iter().map(|segment| segment.as_str()).collect();
+
+        let path_matches = |path: &[String]| {
+            path.len() == segments.len() && path.iter().zip(&segments).all(|(a, b)| a == b)
+        };
+
+        if self.paths.iter().any(|path| path_matches(path)) {
+            return true;
+        }
+
+        for glob in &self.globs {
+            if glob.len() < segments.len() {
+                let (prefix, rest) = segments.split_at(glob.len());
+
+                if glob.iter().zip(prefix).all(|(a, b)| a == b)
+                    && self.unresolved.iter().any(|path| {
+                        path.len() == rest.len() && path.iter().zip(rest).all(|(a, b)| a == b)
+                    })
+                {
+                    return true;
+                }
+            }
+        }
+
+        // Methods (e.g. `krate::Type::method`) are matched by name in crates
+        // which reference the vulnerable crate
+        let crate_name = segments[0];
+        segments.len() > 2
+            && self.methods.contains(segments[segments.len() - 1])
+            && self
+                .paths
+                .iter()
+                .chain(&self.globs)
+                .any(|path| path[0] == crate_name)
+    }
+}
+
+/// Syntax tree visitor which collects the paths and methods used in a file
+#[derive(Debug, Default)]
+struct FileVisitor {
+    /// Names brought into scope by `use` declarations, and what they refer to
+    aliases: Map<String, Vec<String>>,
+
+    /// Prefixes of glob imports
+    globs: Set<Vec<String>>,
+
+    /// Paths used in the file
+    paths: Vec<Vec<String>>,
+
+    /// Names of called methods
+    methods: Set<String>,
+
+    /// Paths publicly re-exported from the file
+    reexported: Vec<Vec<String>>,
+}
+
+impl FileVisitor {
+    /// Add the names imported by the given `use` tree, which are re-exported
+    /// if `public` is set
+    fn add_use_tree(&mut self, prefix: &mut Vec<String>, tree: &UseTree, public: bool) {
+        let path = match tree {
+            UseTree::Path(use_path) => {
+                prefix.push(use_path.ident.to_string());
+                self.add_use_tree(prefix, &use_path.tree, public);
+                prefix.pop();
+                return;
+            }
+            UseTree::Name(use_name) if use_name.ident == "self" => {
+                let name = match prefix.last() {
+                    Some(name) => name.clone(),
+                    None => return,
+                };
+
+                self.aliases.insert(name, prefix.clone());
+                self.paths.push(prefix.clone());
+                prefix.clone()
+            }
+            UseTree::Name(use_name) => {
+                let mut path = prefix.clone();
+                path.push(use_name.ident.to_string());
+                self.aliases
+                    .insert(use_name.ident.to_string(), path.clone());
+                self.paths.push(path.clone());
+                path
+            }
+            UseTree::Rename(use_rename) => {
+                let mut path = prefix.clone();
+                path.push(use_rename.ident.to_string());
+                self.aliases
+                    .insert(use_rename.rename.to_string(), path.clone());
+                self.paths.push(path.clone());
+                path
+            }
+            UseTree::Glob(_) => {
+                if prefix.is_empty() {
+                    return;
+                }
+
+                self.globs.insert(prefix.clone());
+                prefix.clone()
+            }
+            UseTree::Group(use_group) => {
+                for tree in &use_group.items {
+                    self.add_use_tree(prefix, tree, public);
+                }
+                return;
+            }
+        };
+
+        if public {
+            self.reexported.push(path);
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for FileVisitor {
+    fn visit_item_use(&mut self, item: &'ast ItemUse) {
+        let public = matches!(item.vis, Visibility::Public(_));
+        self.add_use_tree(&mut vec![], &item.tree, public);
+    }
+
+    fn visit_item_extern_crate(&mut self, item: &'ast ItemExternCrate) {
+        if matches!(item.vis, Visibility::Public(_)) {
+            self.reexported.push(vec![item.ident.to_string()]);
+        }
+    }
+
+    fn visit_path(&mut self, path: &'ast syn::Path) {
+        let segments: Vec<String> = path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect();
+
+        if !segments.is_empty() {
+            self.paths.push(segments);
+        }
+
+        syn::visit::visit_path(self, path);
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        self.methods.insert(call.method.to_string());
+        syn::visit::visit_expr_method_call(self, call);
+    }
+
+    fn visit_macro(&mut self, mac: &'ast Macro) {
+        // Macro arguments are opaque tokens, but are usually expressions
+        let parser = Punctuated::<Expr, Token![,]>::parse_terminated;
+
+        if let Ok(args) = parser.parse2(mac.tokens.clone()) {
+            for arg in &args {
+                self.visit_expr(arg);
+            }
+        }
+
+        syn::visit::visit_macro(self, mac);
+    }
+}
@@ -0,0 +1,52 @@
+//! Detecting the version of the Rust toolchain used by a workspace
+
+use rustsec::{toolchain, Error, ErrorKind, Version};
+use std::{fs, path::Path, process::Command};
+
+/// Files which can pin the toolchain of a workspace, in order of precedence
+const TOOLCHAIN_FILES: &[&str] = &["rust-toolchain", "rust-toolchain.toml"];
+
+/// Detect the version of the Rust toolchain used by the workspace in the
+/// given directory.
+///
+/// Uses the release pinned by its `rust-toolchain` file if there is one, and
+/// otherwise runs `rustc -V` in the workspace (so `rustup` overrides apply).
+pub fn detect(workspace_root: &Path) -> rustsec::Result<Version> {
+    for file_name in TOOLCHAIN_FILES {
+        let path = workspace_root.join(file_name);
+
+        if let Ok(contents) = fs::read_to_string(&path) {
+            let version = toolchain::parse_toolchain_file(&contents).map_err(|e| {
+                Error::new(
+                    e.kind(),
+                    &format!("error parsing {}: {}", path.display(), e),
+                )
+            })?;
+
+            if let Some(version) = version {
+                return Ok(version);
+            }
+
+            // The file names a channel, which `rustc` resolves below
+            break;
+        }
+    }
+
+    let output = Command::new("rustc")
+        .arg("-V")
+        .current_dir(workspace_root)
+        .output()
+        .map_err(|e| Error::new(ErrorKind::Io, &format!("couldn't run `rustc -V`: {}", e)))?;
+
+    if !output.status.success() {
+        return Err(Error::new(
+            ErrorKind::Io,
+            &format!(
+                "error running `rustc -V`: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        ));
+    }
+
+    toolchain::parse_rustc_version(&String::from_utf8_lossy(&output.stdout))
+}
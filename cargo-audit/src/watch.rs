@@ -0,0 +1,249 @@
+//! Watch mode: re-audit whenever the lockfile or advisory database changes.
+//!
+//! The [`Auditor`] (and with it the advisory database and crates.io index)
+//! is kept in memory between audits, and only reloaded when the advisory
+//! database (or any of the additional `database.sources`) changes. Changes
+//! are detected by polling modification times. If reloading fails, e.g.
+//! because the database is being updated, the previous one is kept.
+//!
+//! Rather than re-printing the whole report, each audit prints the findings
+//! which are new or have been resolved since the previous one.
+
+use crate::{
+    auditor::Auditor,
+    baseline::Finding,
+    config::{AuditConfig, DatabaseFormat},
+    prelude::*,
+};
+use rustsec::{repository::git::Repository, Report};
+use std::{
+    collections::BTreeMap as Map,
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// How often to check for changes
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Files within a git repository which change when it's fetched or updated
+const REPOSITORY_FILES: &[&str] = &[".git/FETCH_HEAD", ".git/index"];
+
+/// Audit the given lockfile, and again whenever it or the advisory database
+/// changes. Never returns.
+pub fn watch(config: &AuditConfig, lockfile_path: &Path) -> ! {
+    let mut auditor = Auditor::new(config);
+
+    // Reloading the database after it has changed shouldn't fetch it again
+    let mut reload_config = config.clone();
+    reload_config.database.fetch = false;
+
+    let mut lockfile_stamp = modified(&[lockfile_path.to_owned()]);
+    let mut database_stamp = modified(&database_paths(config));
+    let mut previous = Findings::new();
+
+    loop {
+        if let Some(findings) = audit(&mut auditor, lockfile_path) {
+            print_changes(&previous, &findings);
+            previous = findings;
+        }
+
+        status_ok!("Watching", "{} for changes", lockfile_path.display());
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+
+            // OSV sources may gain or lose files, so list the paths each time
+            let new_database_stamp = modified(&database_paths(config));
+
+            if new_database_stamp != database_stamp {
+                database_stamp = new_database_stamp;
+                status_ok!("Reloading", "advisory database");
+
+                match Auditor::try_new(&reload_config) {
+                    Ok(new_auditor) => {
+                        auditor = new_auditor;
+                        break;
+                    }
+                    Err(e) => {
+                        status_err!("{} (keeping the previous advisory database)", e);
+                        continue;
+                    }
+                }
+            }
+
+            let new_lockfile_stamp = modified(&[lockfile_path.to_owned()]);
+
+            if new_lockfile_stamp != lockfile_stamp {
+                lockfile_stamp = new_lockfile_stamp;
+                break;
+            }
+        }
+    }
+}
+
+/// Findings in a report, along with their descriptions
+pub type Findings = Map<Finding, String>;
+
+/// Change in the findings since the previous audit
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Change<'a> {
+    /// Finding which wasn't in the previous audit, with its description
+    New(&'a Finding, &'a str),
+
+    /// Finding from the previous audit which is no longer found, with its
+    /// description
+    Resolved(&'a Finding, &'a str),
+}
+
+/// Audit the lockfile, returning its findings (or `None` if it couldn't be
+/// audited, e.g. because it's being written)
+fn audit(auditor: &mut Auditor, lockfile_path: &Path) -> Option<Findings> {
+    match auditor.report(lockfile_path) {
+        Ok(report) => {
+            status_ok!(
+                "Scanned",
+                "{}: {} vulnerabilities, {} warnings",
+                lockfile_path.display(),
+                report.vulnerabilities.count,
+                report.warnings.values().map(Vec::len).sum::<usize>()
+            );
+
+            Some(findings(&report))
+        }
+        Err(e) => {
+            status_err!("{}", e);
+            None
+        }
+    }
+}
+
+/// Get the findings in the given report
+pub fn findings(report: &Report) -> Findings {
+    let mut findings = Findings::new();
+
+    for vuln in &report.vulnerabilities.list {
+        findings.insert(
+            Finding::new(&vuln.advisory.id, &vuln.package),
+            format!("vulnerability: {}", vuln.advisory.title),
+        );
+    }
+
+    for warning in report.warnings.values().flatten() {
+        // Warnings about yanked crates don't have advisories
+        if let Some(advisory) = &warning.advisory {
+            findings.insert(
+                Finding::new(&advisory.id, &warning.package),
+                format!("{} warning: {}", warning.kind.as_str(), advisory.title),
+            );
+        }
+    }
+
+    findings
+}
+
+/// Get the findings which have been added or removed, new ones first
+pub fn changes<'a>(previous: &'a Findings, current: &'a Findings) -> Vec<Change<'a>> {
+    let new = current
+        .iter()
+        .filter(|(finding, _)| !previous.contains_key(finding))
+        .map(|(finding, description)| Change::New(finding, description));
+
+    let resolved = previous
+        .iter()
+        .filter(|(finding, _)| !current.contains_key(finding))
+        .map(|(finding, description)| Change::Resolved(finding, description));
+
+    new.chain(resolved).collect()
+}
+
+/// Print the findings which have been added or removed
+fn print_changes(previous: &Findings, current: &Findings) {
+    for change in changes(previous, current) {
+        match change {
+            Change::New(finding, description) => status_err!(
+                "new {} ({} in {} {})",
+                description,
+                finding.id,
+                finding.package,
+                finding.version
+            ),
+            Change::Resolved(finding, description) => status_ok!(
+                "Resolved",
+                "{} ({} in {} {})",
+                description,
+                finding.id,
+                finding.package,
+                finding.version
+            ),
+        }
+    }
+}
+
+/// Paths which change when the advisory database, or any of the additional
+/// databases in `database.sources`, is updated
+pub fn database_paths(config: &AuditConfig) -> Vec<PathBuf> {
+    let mut paths = match &config.database.bundle {
+        Some(bundle_path) => vec![bundle_path.clone()],
+        None => repository_paths(
+            &config
+                .database
+                .path
+                .clone()
+                .unwrap_or_else(Repository::default_path),
+        ),
+    };
+
+    for source in &config.database.sources {
+        match source.format {
+            DatabaseFormat::Git => paths.extend(repository_paths(&source.path)),
+            DatabaseFormat::Osv => directory_paths(&source.path, &mut paths),
+        }
+    }
+
+    paths
+}
+
+/// Paths which change when the git repository at the given path is updated
+fn repository_paths(path: &Path) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = REPOSITORY_FILES
+        .iter()
+        .map(|file| path.join(file))
+        .collect();
+
+    paths.push(path.to_owned());
+    paths
+}
+
+/// Add the given directory and everything within it to `paths`
+fn directory_paths(dir: &Path, paths: &mut Vec<PathBuf>) {
+    paths.push(dir.to_owned());
+
+    let mut entries: Vec<PathBuf> = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .collect(),
+        Err(_) => return,
+    };
+
+    // Keep the order stable between polls
+    entries.sort();
+
+    for path in entries {
+        if path.is_dir() {
+            directory_paths(&path, paths);
+        } else {
+            paths.push(path);
+        }
+    }
+}
+
+/// Get the modification times of the given paths
+fn modified(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| fs::metadata(path).and_then(|meta| meta.modified()).ok())
+        .collect()
+}
@@ -9,7 +9,10 @@
 
 use abscissa_core::testing::prelude::*;
 use once_cell::sync::Lazy;
-use std::{io::BufRead, path::PathBuf};
+use std::{
+    io::{BufRead, Read},
+    path::PathBuf,
+};
 use tempfile::TempDir;
 
 /// Directory containing the advisory database.
@@ -170,3 +173,44 @@ fn advisories_found_but_ignored_json() {
         0
     );
 }
+
+#[test]
+fn sarif_with_multiple_lockfiles_exit_error() {
+    let mut runner = secure_cmd_runner();
+    runner.arg("--file").arg("Cargo.lock");
+    runner.arg("--format").arg("sarif");
+
+    let mut process = runner.run();
+    let mut stderr = String::new();
+    process.stderr().read_to_string(&mut stderr).unwrap();
+    process.wait().unwrap().expect_code(2);
+
+    assert!(stderr.contains("the sarif output format only supports a single lockfile"));
+}
+
+#[test]
+fn bin_with_reachability_exit_error() {
+    let mut runner = RUNNER.clone();
+    runner.arg("--reachability").arg("bin").arg("auditable-bin");
+
+    let mut process = runner.run();
+    let mut stderr = String::new();
+    process.stderr().read_to_string(&mut stderr).unwrap();
+    process.wait().unwrap().expect_code(2);
+
+    assert!(stderr.contains("--reachability can't be used when auditing binaries"));
+}
+
+#[test]
+fn bundle_with_keyring_exit_error() {
+    let mut runner = secure_cmd_runner();
+    runner.arg("--db-bundle").arg("advisory-db.bundle");
+    runner.arg("--keyring").arg("keyring.asc");
+
+    let mut process = runner.run();
+    let mut stderr = String::new();
+    process.stderr().read_to_string(&mut stderr).unwrap();
+    process.wait().unwrap().expect_code(1);
+
+    assert!(stderr.contains("bundles can't be verified with a keyring"));
+}
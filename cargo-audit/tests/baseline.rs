@@ -0,0 +1,132 @@
+//! Baseline file tests
+
+use cargo_audit::baseline::{Baseline, Finding};
+use rustsec::{database::scope, lockfile::Lockfile, report, Database, Report};
+use std::{fs, path::Path};
+
+/// Workspace with vulnerable dependencies (shared with the reachability tests)
+const WORKSPACE_PATH: &str = "./tests/support/reachability";
+
+fn lockfile_path() -> &'static Path {
+    Path::new("./tests/support/reachability/Cargo.lock")
+}
+
+fn generate_report() -> Report {
+    let workspace = Path::new(WORKSPACE_PATH);
+    let db = Database::open(&workspace.join("advisory-db")).unwrap();
+    let lockfile = Lockfile::load(lockfile_path()).unwrap();
+
+    let settings = report::Settings {
+        package_scope: Some(scope::Registry::All.into()),
+        ..Default::default()
+    };
+
+    Report::generate(&db, &lockfile, &settings)
+}
+
+#[test]
+fn save_and_load() {
+    let baseline = Baseline::from_report(&generate_report(), lockfile_path());
+    assert_eq!(baseline.findings.len(), 4);
+
+    let lockfile = fs::canonicalize(lockfile_path()).unwrap();
+    assert!(baseline
+        .findings
+        .iter()
+        .all(|finding| finding.lockfile.as_ref() == Some(&lockfile)));
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit-baseline.json");
+    baseline.save(&path).unwrap();
+
+    assert_eq!(Baseline::load(&path).unwrap(), baseline);
+
+    // Lockfile paths are saved relative to the baseline file
+    let saved: Baseline = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    let saved_path = saved
+        .findings
+        .iter()
+        .next()
+        .unwrap()
+        .lockfile
+        .as_ref()
+        .unwrap();
+    assert!(saved_path.is_relative());
+    assert_eq!(
+        fs::canonicalize(dir.path().join(saved_path)).unwrap(),
+        lockfile
+    );
+}
+
+/// Lockfile paths match regardless of how they're spelled
+#[test]
+fn normalize_lockfile_paths() {
+    let baseline = Baseline::from_report(&generate_report(), lockfile_path());
+    let other_spelling = Path::new("tests/support/../support/reachability/Cargo.lock");
+
+    let mut report = generate_report();
+    let found = baseline.apply(&mut report, other_spelling);
+    assert_eq!(found.len(), 4);
+    assert!(!report.vulnerabilities.found);
+    assert!(baseline.stale(&[other_spelling], &found).is_empty());
+}
+
+#[test]
+fn apply_baseline() {
+    let mut baseline = Baseline::from_report(&generate_report(), lockfile_path());
+
+    let removed = baseline.findings.iter().next().unwrap().clone();
+    baseline.findings.remove(&removed);
+
+    let mut stale = removed.clone();
+    stale.version = "0.0.1".parse().unwrap();
+    baseline.findings.insert(stale.clone());
+
+    let mut report = generate_report();
+    let found = baseline.apply(&mut report, lockfile_path());
+    assert_eq!(found.len(), 3);
+    assert_eq!(baseline.stale(&[lockfile_path()], &found), [stale]);
+
+    assert!(report.vulnerabilities.found);
+    assert_eq!(report.vulnerabilities.count, 1);
+    assert_eq!(report.vulnerabilities.list[0].advisory.id, removed.id);
+}
+
+/// Entries for other lockfiles don't apply, and aren't stale
+#[test]
+fn apply_other_lockfile() {
+    let other_path = Path::new("other/Cargo.lock");
+    let baseline = Baseline::from_report(&generate_report(), other_path);
+
+    let mut report = generate_report();
+    let found = baseline.apply(&mut report, lockfile_path());
+    assert!(found.is_empty());
+    assert_eq!(report.vulnerabilities.count, 4);
+
+    assert!(baseline.stale(&[lockfile_path()], &found).is_empty());
+    assert_eq!(
+        baseline.stale(&[lockfile_path(), other_path], &found).len(),
+        4
+    );
+}
+
+/// Entries without a lockfile (from older baselines) apply to every lockfile
+#[test]
+fn apply_legacy_entries() {
+    let baseline: Baseline = serde_json::from_str(
+        r#"{"findings": [
+            {"id": "RUSTSEC-2021-9001", "package": "vulnlib", "version": "0.1.0"},
+            {"id": "RUSTSEC-2021-9001", "package": "vulnlib", "version": "0.0.1"}
+        ]}"#,
+    )
+    .unwrap();
+
+    let mut report = generate_report();
+    let found = baseline.apply(&mut report, lockfile_path());
+    assert_eq!(report.vulnerabilities.count, 3);
+
+    let stale: Vec<Finding> = baseline.stale(&[lockfile_path()], &found);
+    assert_eq!(stale.len(), 1);
+    assert_eq!(stale[0].version.to_string(), "0.0.1");
+    assert_eq!(stale[0].lockfile, None);
+}
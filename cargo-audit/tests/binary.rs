@@ -0,0 +1,142 @@
+//! Tests for auditing binaries built with `cargo auditable`
+
+use cargo_audit::binary;
+use flate2::{write::ZlibEncoder, Compression};
+use rustsec::{database::scope, report, Database, ErrorKind, Report};
+use std::{fs, io::Write, path::Path};
+
+/// Dependency information for `app` and its dependencies, as embedded by
+/// `cargo auditable`
+const DEPENDENCIES_JSON: &str = r#"{"packages":[
+    {"name":"app","version":"0.1.0","source":"local","dependencies":[1,2,3],"root":true},
+    {"name":"vulnlib","version":"0.1.0","source":"crates.io"},
+    {"name":"otherlib","version":"1.0.0","source":"crates.io","dependencies":[3]},
+    {"name":"buildlib","version":"0.1.0","source":"crates.io","kind":"build"}
+]}"#;
+
+/// Advisory database with advisories for `vulnlib` and `otherlib`
+const ADVISORY_DB_PATH: &str = "./tests/support/reachability/advisory-db";
+
+/// Build a minimal 64-bit little-endian ELF executable whose only sections
+/// are `.dep-v0` (containing the zlib-compressed JSON) and `.shstrtab`
+fn elf_binary(json: &str) -> Vec<u8> {
+    let mut encoder = ZlibEncoder::new(vec![], Compression::default());
+    encoder.write_all(json.as_bytes()).unwrap();
+    let section = encoder.finish().unwrap();
+
+    let names = b"\0.dep-v0\0.shstrtab\0";
+    let section_offset = 64;
+    let names_offset = section_offset + section.len();
+    let headers_offset = (names_offset + names.len() + 7) & !7;
+
+    let mut elf = vec![];
+
+    // ELF header
+    elf.extend_from_slice(b"\x7fELF\x02\x01\x01\0\0\0\0\0\0\0\0\0");
+    elf.extend_from_slice(&2u16.to_le_bytes()); // e_type: executable
+    elf.extend_from_slice(&62u16.to_le_bytes()); // e_machine: x86-64
+    elf.extend_from_slice(&1u32.to_le_bytes()); // e_version
+    elf.extend_from_slice(&0u64.to_le_bytes()); // e_entry
+    elf.extend_from_slice(&0u64.to_le_bytes()); // e_phoff
+    elf.extend_from_slice(&(headers_offset as u64).to_le_bytes()); // e_shoff
+    elf.extend_from_slice(&0u32.to_le_bytes()); // e_flags
+    elf.extend_from_slice(&64u16.to_le_bytes()); // e_ehsize
+    elf.extend_from_slice(&56u16.to_le_bytes()); // e_phentsize
+    elf.extend_from_slice(&0u16.to_le_bytes()); // e_phnum
+    elf.extend_from_slice(&64u16.to_le_bytes()); // e_shentsize
+    elf.extend_from_slice(&3u16.to_le_bytes()); // e_shnum
+    elf.extend_from_slice(&2u16.to_le_bytes()); // e_shstrndx
+
+    elf.extend_from_slice(&section);
+    elf.extend_from_slice(names);
+    elf.resize(headers_offset, 0);
+
+    // Section headers: null, `.dep-v0` (PROGBITS) and `.shstrtab` (STRTAB)
+    for &(name, kind, offset, size) in &[
+        (0u32, 0u32, 0, 0),
+        (1, 1, section_offset, section.len()),
+        (9, 3, names_offset, names.len()),
+    ] {
+        elf.extend_from_slice(&name.to_le_bytes());
+        elf.extend_from_slice(&kind.to_le_bytes());
+        elf.extend_from_slice(&0u64.to_le_bytes()); // sh_flags
+        elf.extend_from_slice(&0u64.to_le_bytes()); // sh_addr
+        elf.extend_from_slice(&(offset as u64).to_le_bytes());
+        elf.extend_from_slice(&(size as u64).to_le_bytes());
+        elf.extend_from_slice(&0u32.to_le_bytes()); // sh_link
+        elf.extend_from_slice(&0u32.to_le_bytes()); // sh_info
+        elf.extend_from_slice(&1u64.to_le_bytes()); // sh_addralign
+        elf.extend_from_slice(&0u64.to_le_bytes()); // sh_entsize
+    }
+
+    elf
+}
+
+#[test]
+fn load_dependencies() {
+    let dir = tempfile::tempdir().unwrap();
+    let binary_path = dir.path().join("app");
+    fs::write(&binary_path, elf_binary(DEPENDENCIES_JSON)).unwrap();
+
+    let lockfile = binary::load(&binary_path).unwrap();
+
+    let packages: Vec<_> = lockfile
+        .packages
+        .iter()
+        .map(|package| {
+            (
+                package.name.as_str(),
+                package.version.to_string(),
+                package.source.is_some(),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        packages,
+        [
+            ("app", "0.1.0".to_owned(), false),
+            ("vulnlib", "0.1.0".to_owned(), true),
+            ("otherlib", "1.0.0".to_owned(), true),
+            ("buildlib", "0.1.0".to_owned(), true),
+        ]
+    );
+
+    let app = &lockfile.packages[0];
+    assert_eq!(app.dependencies.len(), 3);
+    assert!(lockfile.dependency_tree().is_ok());
+}
+
+#[test]
+fn audit_binary() {
+    let db = Database::open(Path::new(ADVISORY_DB_PATH)).unwrap();
+    let lockfile = binary::extract(&elf_binary(DEPENDENCIES_JSON)).unwrap();
+
+    let settings = report::Settings {
+        package_scope: Some(scope::Registry::All.into()),
+        ..Default::default()
+    };
+
+    let report = Report::generate(&db, &lockfile, &settings);
+
+    let vulnerable: Vec<_> = report
+        .vulnerabilities
+        .list
+        .iter()
+        .map(|vuln| vuln.package.name.as_str())
+        .collect();
+    assert_eq!(vulnerable, ["vulnlib"]);
+}
+
+#[test]
+fn invalid_binary() {
+    let err = binary::extract(b"not a binary").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Parse);
+
+    let err = binary::extract(&elf_binary("not json")).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Parse);
+
+    let err = binary::parse(r#"{"packages": [{"name": "app", "version": "0.1.0", "source": "local", "dependencies": [1]}]}"#)
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Parse);
+}
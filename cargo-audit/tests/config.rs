@@ -1,6 +1,6 @@
 //! Configuration file tests
 
-use cargo_audit::config::AuditConfig;
+use cargo_audit::config::{AuditConfig, DatabaseFormat};
 use std::{fs, path::Path};
 
 /// Ensure `audit.toml.example` parses as a valid config file
@@ -17,4 +17,70 @@ fn parse_audit_toml_example() {
         config.database.url.unwrap(),
         "https://github.com/RustSec/advisory-db.git"
     );
+
+    let formats: Vec<_> = config
+        .database
+        .sources
+        .iter()
+        .map(|source| source.format)
+        .collect();
+    assert_eq!(formats, [DatabaseFormat::Git, DatabaseFormat::Osv]);
+
+    let platforms: Vec<_> = config
+        .target
+        .platforms
+        .iter()
+        .map(|platform| platform.as_str())
+        .collect();
+    assert_eq!(platforms, ["x86_64-unknown-linux-gnu", "*-apple-darwin"]);
+
+    assert_eq!(
+        config.advisories.baseline.unwrap(),
+        Path::new("audit-baseline.json")
+    );
+
+    assert!(!config.toolchain.enabled);
+    assert_eq!(
+        config.toolchain.version.unwrap(),
+        rustsec::Version::new(1, 52, 1)
+    );
+}
+
+/// Ensure ignore entries can be advisory IDs or rules
+#[test]
+fn parse_ignore_rules() {
+    let config: AuditConfig = toml::from_str(
+        r#"
+        [advisories]
+        ignore = [
+            "RUSTSEC-2019-0001",
+            { id = "RUSTSEC-2020-0002", reason = "not exploitable", expires = "2022-06-30" },
+            { id = "RUSTSEC-2020-0003", package = "foo", version = "< 1.2" },
+        ]
+        "#,
+    )
+    .unwrap();
+
+    let settings = config.report_settings();
+    assert_eq!(settings.ignore, ["RUSTSEC-2019-0001".parse().unwrap()]);
+    assert_eq!(settings.ignore_rules.len(), 2);
+
+    let rule = &settings.ignore_rules[0];
+    assert_eq!(rule.id.as_str(), "RUSTSEC-2020-0002");
+    assert_eq!(rule.reason.as_deref(), Some("not exploitable"));
+    assert_eq!(rule.expires.as_ref().unwrap().as_str(), "2022-06-30");
+    assert!(rule.is_expired(&"2022-06-30".parse().unwrap()));
+    assert!(!rule.is_expired(&"2022-06-29".parse().unwrap()));
+
+    let rule = &settings.ignore_rules[1];
+    assert_eq!(rule.package.as_ref().unwrap().as_str(), "foo");
+    assert_eq!(rule.version.as_ref().unwrap().to_string(), "<1.2");
+
+    let err = toml::from_str::<AuditConfig>(
+        r#"
+        [advisories]
+        ignore = [{ id = "RUSTSEC-2020-0002", reasons = "typo" }]
+        "#,
+    );
+    assert!(err.is_err());
 }
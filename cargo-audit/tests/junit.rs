@@ -0,0 +1,73 @@
+//! JUnit XML output tests
+
+use cargo_audit::presenter::junit::JunitReport;
+use rustsec::{database::scope, lockfile::Lockfile, report, warning, Database, Report};
+use std::{collections::BTreeSet as Set, path::Path};
+
+/// Lockfile with two vulnerable versions of `vulnlib` and an unmaintained
+/// `oldlib` (shared with the other presenter tests)
+const LOCKFILE_PATH: &str = "./tests/support/presenter/Cargo.lock";
+
+/// Advisory database for the above lockfile
+const DB_PATH: &str = "./tests/support/presenter/advisory-db";
+
+fn generate_junit(deny_warning_kinds: &[warning::Kind]) -> String {
+    let db = Database::open(Path::new(DB_PATH)).unwrap();
+    let lockfile = Lockfile::load(LOCKFILE_PATH).unwrap();
+
+    let settings = report::Settings {
+        package_scope: Some(scope::Registry::All.into()),
+        informational_warnings: vec![rustsec::advisory::Informational::Unmaintained],
+        ..Default::default()
+    };
+
+    let report = Report::generate(&db, &lockfile, &settings);
+    let deny_warning_kinds: Set<_> = deny_warning_kinds.iter().cloned().collect();
+
+    JunitReport::generate(
+        &report,
+        &lockfile,
+        Path::new(LOCKFILE_PATH),
+        &deny_warning_kinds,
+    )
+    .to_string()
+}
+
+#[test]
+fn counts() {
+    let junit = generate_junit(&[]);
+
+    assert!(junit.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#));
+    assert!(junit.contains(r#"<testsuites name="cargo-audit" tests="4" failures="2">"#));
+    assert!(junit.contains(&format!(
+        r#"<testsuite name="{}" tests="4" failures="2" errors="0" skipped="0">"#,
+        LOCKFILE_PATH
+    )));
+
+    // Allowed warnings don't fail the test case
+    assert!(junit.contains(r#"name="oldlib 0.3.0"/>"#));
+}
+
+#[test]
+fn denied_warnings() {
+    let junit = generate_junit(&[warning::Kind::Unmaintained]);
+
+    assert!(junit.contains(r#"<testsuites name="cargo-audit" tests="4" failures="3">"#));
+    assert_eq!(junit.matches("<failure ").count(), 3);
+    assert_eq!(junit.matches(r#"<failure type="vulnerability""#).count(), 2);
+    assert!(junit.contains(r#"name="oldlib 0.3.0">"#));
+    assert!(junit.contains(r#"<failure type="unmaintained""#));
+}
+
+#[test]
+fn escaping() {
+    let junit = generate_junit(&[warning::Kind::Unmaintained]);
+
+    assert!(junit.contains(r#"message="RUSTSEC-2021-0001: Overflow in &lt;Parser&gt; | `parse`""#));
+    assert!(junit.contains(
+        r#"message="RUSTSEC-2021-0002: oldlib is &quot;unmaintained&quot; &amp; &lt;abandoned&gt;""#
+    ));
+    assert!(junit.contains("Title: oldlib is &quot;unmaintained&quot; &amp; &lt;abandoned&gt;\n"));
+    assert!(!junit.contains("<Parser>"));
+    assert!(!junit.contains("<abandoned>"));
+}
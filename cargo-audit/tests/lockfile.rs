@@ -0,0 +1,32 @@
+//! Lockfile discovery tests
+
+use cargo_audit::lockfile;
+use std::fs;
+
+#[test]
+fn find_all_lockfiles() {
+    let dir = tempfile::tempdir().unwrap();
+
+    for workspace in &["b", "a", "a/nested", "a/target", ".hidden", "empty"] {
+        fs::create_dir_all(dir.path().join(workspace)).unwrap();
+    }
+
+    for workspace in &["b", "a", "a/nested", "a/target", ".hidden"] {
+        fs::write(dir.path().join(workspace).join("Cargo.lock"), "").unwrap();
+    }
+
+    assert_eq!(
+        lockfile::find_all(dir.path()).unwrap(),
+        vec![
+            dir.path().join("a/Cargo.lock"),
+            dir.path().join("a/nested/Cargo.lock"),
+            dir.path().join("b/Cargo.lock"),
+        ]
+    );
+}
+
+#[test]
+fn find_all_missing_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(lockfile::find_all(&dir.path().join("missing")).is_err());
+}
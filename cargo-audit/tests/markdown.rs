@@ -0,0 +1,83 @@
+//! Markdown output tests
+
+use cargo_audit::presenter::markdown::MarkdownReport;
+use rustsec::{database::scope, lockfile::Lockfile, report, warning, Database, Report};
+use std::{collections::BTreeSet as Set, path::Path};
+
+/// Lockfile with two vulnerable versions of `vulnlib` and an unmaintained
+/// `oldlib` (shared with the other presenter tests)
+const LOCKFILE_PATH: &str = "./tests/support/presenter/Cargo.lock";
+
+/// Advisory database for the above lockfile
+const DB_PATH: &str = "./tests/support/presenter/advisory-db";
+
+fn generate_markdown(show_tree: bool, ignore: &[&str]) -> String {
+    let db = Database::open(Path::new(DB_PATH)).unwrap();
+    let lockfile = Lockfile::load(LOCKFILE_PATH).unwrap();
+
+    let settings = report::Settings {
+        package_scope: Some(scope::Registry::All.into()),
+        informational_warnings: vec![rustsec::advisory::Informational::Unmaintained],
+        ignore: ignore.iter().map(|id| id.parse().unwrap()).collect(),
+        ..Default::default()
+    };
+
+    let report = Report::generate(&db, &lockfile, &settings);
+    let deny_warning_kinds: Set<_> = [warning::Kind::Unmaintained].iter().cloned().collect();
+
+    MarkdownReport::generate(
+        &report,
+        &lockfile,
+        Path::new(LOCKFILE_PATH),
+        &deny_warning_kinds,
+        show_tree,
+    )
+    .to_string()
+}
+
+#[test]
+fn report_tables() {
+    assert_eq!(
+        generate_markdown(false, &[]),
+        r#"## `cargo audit` report
+
+Scanned `./tests/support/presenter/Cargo.lock` (4 crate dependencies)
+
+### Vulnerabilities
+
+| Crate | Version | Advisory | Severity | Title | Patched versions |
+|---|---|---|---|---|---|
+| `vulnlib` | 0.1.0 | [RUSTSEC-2021-0001](https://rustsec.org/advisories/RUSTSEC-2021-0001) | critical (9.8) | Overflow in &lt;Parser&gt; \| `parse` | `>=1.0.0` |
+| `vulnlib` | 0.2.0 | [RUSTSEC-2021-0001](https://rustsec.org/advisories/RUSTSEC-2021-0001) | critical (9.8) | Overflow in &lt;Parser&gt; \| `parse` | `>=1.0.0` |
+
+### Warnings: unmaintained (denied)
+
+| Crate | Version | Advisory | Severity | Title | Patched versions |
+|---|---|---|---|---|---|
+| `oldlib` | 0.3.0 | [RUSTSEC-2021-0002](https://rustsec.org/advisories/RUSTSEC-2021-0002) | - | oldlib is "unmaintained" & &lt;abandoned&gt; | none |
+
+"#
+    );
+}
+
+#[test]
+fn dependency_trees() {
+    let markdown = generate_markdown(true, &[]);
+
+    assert_eq!(markdown.matches("<details>").count(), 3);
+    assert!(markdown.contains(
+        "<summary>Dependency tree for <code>vulnlib 0.2.0</code></summary>\n\n\
+         ```\nvulnlib 0.2.0\n└── app 0.1.0\n```\n\n</details>\n"
+    ));
+
+    // Every code block is closed
+    assert_eq!(markdown.matches("```").count() % 2, 0);
+}
+
+#[test]
+fn no_findings() {
+    let markdown = generate_markdown(true, &["RUSTSEC-2021-0001", "RUSTSEC-2021-0002"]);
+
+    assert!(markdown.ends_with("No vulnerabilities or warnings found.\n"));
+    assert!(!markdown.contains("<details>"));
+}
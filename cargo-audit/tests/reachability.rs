@@ -0,0 +1,81 @@
+//! Reachability analysis tests
+
+use cargo_audit::reachability;
+use rustsec::{database::scope, lockfile::Lockfile, report, Database, Reachability, Report};
+use std::path::Path;
+
+/// Workspace containing a binary which uses some of the affected functions
+const WORKSPACE_PATH: &str = "./tests/support/reachability";
+
+/// Library which renames one vulnerable dependency and re-exports others
+const API_WORKSPACE_PATH: &str = "./tests/support/reachability_api";
+
+/// Analyze the workspace at the given path, returning the reachability of
+/// each advisory sorted by ID
+fn analyze(workspace_path: &str) -> Vec<(String, Option<Reachability>)> {
+    let workspace = Path::new(workspace_path);
+    let db = Database::open(&workspace.join("advisory-db")).unwrap();
+    let lockfile = Lockfile::load(workspace.join("Cargo.lock")).unwrap();
+
+    let settings = report::Settings {
+        package_scope: Some(scope::Registry::All.into()),
+        ..Default::default()
+    };
+
+    let mut report = Report::generate(&db, &lockfile, &settings);
+    reachability::analyze(&mut report, &lockfile, workspace);
+
+    let mut results: Vec<_> = report
+        .vulnerabilities
+        .list
+        .iter()
+        .map(|vuln| (vuln.advisory.id.as_str().to_owned(), vuln.reachability))
+        .collect();
+
+    results.sort_by(|a, b| a.0.cmp(&b.0));
+    results
+}
+
+#[test]
+fn analyze_reachability() {
+    assert_eq!(
+        analyze(WORKSPACE_PATH),
+        [
+            (
+                "RUSTSEC-2021-9001".to_owned(),
+                Some(Reachability::Reachable)
+            ),
+            (
+                "RUSTSEC-2021-9002".to_owned(),
+                Some(Reachability::NotReferenced)
+            ),
+            (
+                "RUSTSEC-2021-9003".to_owned(),
+                Some(Reachability::Reachable)
+            ),
+            ("RUSTSEC-2021-9004".to_owned(), Some(Reachability::Unknown)),
+        ]
+    );
+}
+
+/// Renamed dependencies and public re-exports aren't followed, so functions
+/// used through them are never reported as not referenced
+#[test]
+fn renamed_and_reexported_crates() {
+    assert_eq!(
+        analyze(API_WORKSPACE_PATH),
+        [
+            // renamed = { package = "renamedlib" }
+            ("RUSTSEC-2021-9001".to_owned(), Some(Reachability::Unknown)),
+            // pub use globlib::*
+            ("RUSTSEC-2021-9002".to_owned(), Some(Reachability::Unknown)),
+            // pub use modlib::codec
+            ("RUSTSEC-2021-9003".to_owned(), Some(Reachability::Unknown)),
+            // use privlib::*
+            (
+                "RUSTSEC-2021-9004".to_owned(),
+                Some(Reachability::NotReferenced)
+            ),
+        ]
+    );
+}
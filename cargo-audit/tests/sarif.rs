@@ -0,0 +1,146 @@
+//! SARIF output tests
+
+use cargo_audit::presenter::sarif::SarifLog;
+use rustsec::{database::scope, lockfile::Lockfile, report, warning, Database, Report};
+use serde_json::{json, Value};
+use std::{collections::BTreeSet as Set, path::Path};
+
+/// Lockfile with two vulnerable versions of `vulnlib` and an unmaintained
+/// `oldlib` (shared with the other presenter tests)
+const LOCKFILE_PATH: &str = "./tests/support/presenter/Cargo.lock";
+
+/// Advisory database for the above lockfile
+const DB_PATH: &str = "./tests/support/presenter/advisory-db";
+
+fn generate_report() -> Report {
+    let db = Database::open(Path::new(DB_PATH)).unwrap();
+    let lockfile = Lockfile::load(LOCKFILE_PATH).unwrap();
+
+    let settings = report::Settings {
+        package_scope: Some(scope::Registry::All.into()),
+        informational_warnings: vec![rustsec::advisory::Informational::Unmaintained],
+        ..Default::default()
+    };
+
+    Report::generate(&db, &lockfile, &settings)
+}
+
+fn generate_sarif(deny_warning_kinds: &[warning::Kind]) -> Value {
+    let deny_warning_kinds: Set<_> = deny_warning_kinds.iter().cloned().collect();
+    let sarif = SarifLog::generate(
+        &generate_report(),
+        Path::new(LOCKFILE_PATH),
+        &deny_warning_kinds,
+    );
+
+    serde_json::to_value(&sarif).unwrap()
+}
+
+#[test]
+fn log_structure() {
+    let sarif = generate_sarif(&[]);
+
+    assert_eq!(sarif["version"], "2.1.0");
+    assert_eq!(sarif["runs"].as_array().unwrap().len(), 1);
+    assert_eq!(sarif["runs"][0]["tool"]["driver"]["name"], "cargo-audit");
+}
+
+#[test]
+fn rules_are_deduplicated() {
+    let sarif = generate_sarif(&[]);
+    let run = &sarif["runs"][0];
+
+    // Both versions of `vulnlib` share a single rule
+    let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+    let rule_ids: Vec<_> = rules.iter().map(|rule| rule["id"].clone()).collect();
+    assert_eq!(
+        rule_ids,
+        [json!("RUSTSEC-2021-0001"), json!("RUSTSEC-2021-0002")]
+    );
+
+    let results = run["results"].as_array().unwrap();
+    assert_eq!(results.len(), 3);
+
+    for result in results {
+        let index = result["ruleIndex"].as_u64().unwrap() as usize;
+        assert_eq!(result["ruleId"], rules[index]["id"]);
+    }
+}
+
+#[test]
+fn rule_properties() {
+    let sarif = generate_sarif(&[]);
+    let rules = &sarif["runs"][0]["tool"]["driver"]["rules"];
+
+    assert_eq!(rules[0]["name"], "vulnlib");
+    assert_eq!(rules[0]["properties"]["security-severity"], "9.8");
+    assert_eq!(
+        rules[0]["properties"]["cvss"],
+        "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"
+    );
+    assert_eq!(
+        rules[0]["helpUri"],
+        "https://rustsec.org/advisories/RUSTSEC-2021-0001"
+    );
+
+    // Advisories without a CVSS score have no `security-severity`
+    assert!(rules[1]["properties"].get("security-severity").is_none());
+    assert!(rules[1]["properties"].get("cvss").is_none());
+}
+
+#[test]
+fn levels() {
+    let sarif = generate_sarif(&[]);
+    let run = &sarif["runs"][0];
+    let levels: Vec<_> = run["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|result| result["level"].clone())
+        .collect();
+
+    assert_eq!(levels, [json!("error"), json!("error"), json!("warning")]);
+    assert_eq!(
+        run["tool"]["driver"]["rules"][1]["defaultConfiguration"]["level"],
+        "warning"
+    );
+
+    // Denied warnings are reported as errors
+    let sarif = generate_sarif(&[warning::Kind::Unmaintained]);
+    let run = &sarif["runs"][0];
+
+    assert_eq!(run["results"][2]["level"], "error");
+    assert_eq!(
+        run["tool"]["driver"]["rules"][1]["defaultConfiguration"]["level"],
+        "error"
+    );
+}
+
+#[test]
+fn lockfile_locations() {
+    let sarif = generate_sarif(&[]);
+    let results = sarif["runs"][0]["results"].as_array().unwrap();
+
+    let locations: Vec<_> = results
+        .iter()
+        .map(|result| {
+            let location = &result["locations"][0]["physicalLocation"];
+            assert_eq!(location["artifactLocation"]["uri"], LOCKFILE_PATH);
+            location["region"]["startLine"].clone()
+        })
+        .collect();
+
+    // The `[[package]]` line of each of the affected packages
+    assert_eq!(locations, [json!(18), json!(22), json!(14)]);
+}
+
+#[test]
+fn unlocatable_lockfile() {
+    let deny_warning_kinds = Set::new();
+    let sarif = SarifLog::generate(&generate_report(), Path::new("-"), &deny_warning_kinds);
+    let sarif = serde_json::to_value(&sarif).unwrap();
+    let location = &sarif["runs"][0]["results"][0]["locations"][0]["physicalLocation"];
+
+    assert_eq!(location["artifactLocation"]["uri"], "Cargo.lock");
+    assert!(location.get("region").is_none());
+}
@@ -0,0 +1,9 @@
+use otherlib::Client;
+use vulnlib::{self, parse as parse_input};
+
+fn main() {
+    let input = parse_input("example");
+    let client = Client::new();
+    client.send(&input);
+    println!("{}", thirdlib::VERSION);
+}
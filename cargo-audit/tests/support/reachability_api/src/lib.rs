@@ -0,0 +1,7 @@
+pub use globlib::*;
+pub use modlib::codec;
+use privlib::*;
+
+pub fn decode(input: &str) -> String {
+    renamed::parse(input)
+}
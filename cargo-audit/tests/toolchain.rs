@@ -0,0 +1,37 @@
+//! Rust toolchain detection tests
+
+use cargo_audit::toolchain;
+use rustsec::Version;
+use std::fs;
+
+#[test]
+fn detect_pinned_toolchain() {
+    let workspace = tempfile::tempdir().unwrap();
+
+    fs::write(
+        workspace.path().join("rust-toolchain.toml"),
+        "[toolchain]\nchannel = \"1.52.1\"\ncomponents = [\"clippy\"]\n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        toolchain::detect(workspace.path()).unwrap(),
+        Version::new(1, 52, 1)
+    );
+
+    // The legacy `rust-toolchain` file takes precedence
+    fs::write(workspace.path().join("rust-toolchain"), "1.48.0\n").unwrap();
+
+    assert_eq!(
+        toolchain::detect(workspace.path()).unwrap(),
+        Version::new(1, 48, 0)
+    );
+}
+
+#[test]
+fn detect_invalid_toolchain_file() {
+    let workspace = tempfile::tempdir().unwrap();
+    fs::write(workspace.path().join("rust-toolchain.toml"), "[toolchain\n").unwrap();
+
+    assert!(toolchain::detect(workspace.path()).is_err());
+}
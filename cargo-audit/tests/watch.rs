@@ -0,0 +1,124 @@
+//! Watch mode tests
+
+use cargo_audit::{
+    auditor::Auditor,
+    config::{AuditConfig, DatabaseFormat, DatabaseSourceConfig},
+    watch::{self, Change},
+};
+use rustsec::{database::scope, lockfile::Lockfile, report, Database, Report};
+use std::{fs, path::Path};
+
+/// Lockfile with two vulnerable versions of `vulnlib` and an unmaintained
+/// `oldlib` (shared with the presenter tests)
+const LOCKFILE_PATH: &str = "./tests/support/presenter/Cargo.lock";
+
+/// Advisory database for the above lockfile
+const DB_PATH: &str = "./tests/support/presenter/advisory-db";
+
+fn findings(ignore: &[&str]) -> watch::Findings {
+    let db = Database::open(Path::new(DB_PATH)).unwrap();
+    let lockfile = Lockfile::load(LOCKFILE_PATH).unwrap();
+
+    let settings = report::Settings {
+        package_scope: Some(scope::Registry::All.into()),
+        informational_warnings: vec![rustsec::advisory::Informational::Unmaintained],
+        ignore: ignore.iter().map(|id| id.parse().unwrap()).collect(),
+        ..Default::default()
+    };
+
+    watch::findings(&Report::generate(&db, &lockfile, &settings))
+}
+
+#[test]
+fn report_findings() {
+    let findings: Vec<_> = findings(&[])
+        .iter()
+        .map(|(finding, description)| {
+            format!(
+                "{} {} {}: {}",
+                finding.id, finding.package, finding.version, description
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        findings,
+        [
+            "RUSTSEC-2021-0001 vulnlib 0.1.0: vulnerability: Overflow in <Parser> | `parse`",
+            "RUSTSEC-2021-0001 vulnlib 0.2.0: vulnerability: Overflow in <Parser> | `parse`",
+            "RUSTSEC-2021-0002 oldlib 0.3.0: unmaintained warning: oldlib is \"unmaintained\" & <abandoned>",
+        ]
+    );
+}
+
+#[test]
+fn new_and_resolved_findings() {
+    let previous = findings(&["RUSTSEC-2021-0001"]);
+    let current = findings(&["RUSTSEC-2021-0002"]);
+
+    let changes: Vec<_> = watch::changes(&previous, &current)
+        .into_iter()
+        .map(|change| match change {
+            Change::New(finding, _) => format!("new {} {}", finding.id, finding.version),
+            Change::Resolved(finding, _) => format!("resolved {} {}", finding.id, finding.version),
+        })
+        .collect();
+
+    assert_eq!(
+        changes,
+        [
+            "new RUSTSEC-2021-0001 0.1.0",
+            "new RUSTSEC-2021-0001 0.2.0",
+            "resolved RUSTSEC-2021-0002 0.3.0",
+        ]
+    );
+
+    assert!(watch::changes(&current, &current).is_empty());
+}
+
+#[test]
+fn database_source_paths() {
+    let dir = tempfile::tempdir().unwrap();
+    let osv_dir = dir.path().join("osv");
+    fs::create_dir_all(osv_dir.join("github")).unwrap();
+    fs::write(osv_dir.join("github/GHSA-1.json"), "{}").unwrap();
+
+    let mut config = AuditConfig::default();
+    config.database.path = Some(dir.path().join("advisory-db"));
+    config.database.sources = vec![
+        DatabaseSourceConfig {
+            path: dir.path().join("internal"),
+            url: None,
+            format: DatabaseFormat::Git,
+        },
+        DatabaseSourceConfig {
+            path: osv_dir.clone(),
+            url: None,
+            format: DatabaseFormat::Osv,
+        },
+    ];
+
+    let paths = watch::database_paths(&config);
+
+    for path in &[
+        dir.path().join("advisory-db/.git/FETCH_HEAD"),
+        dir.path().join("advisory-db"),
+        dir.path().join("internal/.git/FETCH_HEAD"),
+        dir.path().join("internal"),
+        osv_dir.join("github"),
+        osv_dir.join("github/GHSA-1.json"),
+    ] {
+        assert!(paths.contains(path), "missing {}", path.display());
+    }
+}
+
+/// Reloading the auditor reports errors instead of exiting
+#[test]
+fn reload_error() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let mut config = AuditConfig::default();
+    config.database.bundle = Some(dir.path().join("missing.bundle"));
+
+    assert!(Auditor::try_new(&config).is_err());
+}
@@ -0,0 +1,120 @@
+//! CVSS vector strings of any supported version
+
+#[cfg(feature = "v3")]
+use crate::v3;
+#[cfg(feature = "v4")]
+use crate::v4;
+use crate::{
+    error::{Error, ErrorKind},
+    Severity, PREFIX,
+};
+#[cfg(feature = "serde")]
+use serde::{de, ser, Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// CVSS vector string of any of the versions supported by this crate.
+///
+/// The version is determined by the `CVSS:<version>` prefix when parsing.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Cvss {
+    /// CVSS v3.0 or v3.1 vector, including any Temporal and Environmental
+    /// metrics
+    #[cfg(feature = "v3")]
+    V3(v3::Vector),
+
+    /// CVSS v4.0 vector
+    #[cfg(feature = "v4")]
+    V4(v4::Vector),
+}
+
+impl Cvss {
+    /// Calculate the numeric score of this vector
+    pub fn score(&self) -> f64 {
+        match self {
+            #[cfg(feature = "v3")]
+            Cvss::V3(vector) => vector.score().value(),
+            #[cfg(feature = "v4")]
+            Cvss::V4(vector) => vector.score().value(),
+        }
+    }
+
+    /// Calculate `Severity` according to the
+    /// Qualitative Severity Rating Scale (i.e. Low / Medium / High / Critical)
+    pub fn severity(&self) -> Severity {
+        match self {
+            #[cfg(feature = "v3")]
+            Cvss::V3(vector) => vector.severity(),
+            #[cfg(feature = "v4")]
+            Cvss::V4(vector) => vector.severity(),
+        }
+    }
+}
+
+#[cfg(feature = "v3")]
+impl From<v3::Base> for Cvss {
+    fn from(base: v3::Base) -> Cvss {
+        Cvss::V3(base.into())
+    }
+}
+
+#[cfg(feature = "v3")]
+impl From<v3::Vector> for Cvss {
+    fn from(vector: v3::Vector) -> Cvss {
+        Cvss::V3(vector)
+    }
+}
+
+#[cfg(feature = "v4")]
+impl From<v4::Vector> for Cvss {
+    fn from(vector: v4::Vector) -> Cvss {
+        Cvss::V4(vector)
+    }
+}
+
+impl fmt::Display for Cvss {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "v3")]
+            Cvss::V3(vector) => write!(f, "{}", vector),
+            #[cfg(feature = "v4")]
+            Cvss::V4(vector) => write!(f, "{}", vector),
+        }
+    }
+}
+
+impl FromStr for Cvss {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let mut prefix = s.split('/').next().unwrap_or_default().splitn(2, ':');
+
+        if prefix.next() != Some(PREFIX) {
+            fail!(ErrorKind::Parse, "invalid CVSS prefix: {}", s);
+        }
+
+        match prefix.next().unwrap_or_default() {
+            #[cfg(feature = "v3")]
+            "3.0" | "3.1" => s.parse().map(Cvss::V3),
+            #[cfg(feature = "v4")]
+            "4.0" => s.parse().map(Cvss::V4),
+            other => fail!(ErrorKind::Version, "unsupported CVSS version: '{}'", other),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Cvss {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use de::Error;
+        let string = String::deserialize(deserializer)?;
+        string.parse().map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Cvss {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_string().serialize(serializer)
+    }
+}
@@ -1,16 +1,31 @@
 //! Common Vulnerability Scoring System.
 //!
-//! The [`cvss::v3::Base`][`v3::Base`] type provides the main functionality
-//! currently implemented by this crate, namely: support for parsing, serializing,
-//! and scoring `CVSS:3.0` and `CVSS:3.1` Base Metric Group vector strings
-//! as described in the CVSS v3.1 Specification:
+//! The [`cvss::v3::Base`][`v3::Base`] type provides support for parsing,
+//! serializing, and scoring `CVSS:3.0` and `CVSS:3.1` Base Metric Group vector
+//! strings as described in the CVSS v3.1 Specification:
 //!
 //! <https://www.first.org/cvss/specification-document>
 //!
+//! The [`cvss::v3::Vector`][`v3::Vector`] type additionally supports the
+//! optional Temporal and Environmental Metric Groups.
+//!
+//! The `cvss::v4::Vector` type provides the same for `CVSS:4.0` vector
+//! strings (including Threat, Environmental and Supplemental metrics) as
+//! described in the CVSS v4.0 Specification. It is available through the
+//! optional `v4` Cargo feature:
+//!
+//! <https://www.first.org/cvss/v4.0/specification-document>
+//!
+//! The `cvss::v2::Vector` type supports parsing and scoring legacy CVSS v2.0
+//! vector strings (e.g. `AV:N/AC:L/Au:N/C:P/I:P/A:P`) as described in the
+//! CVSS v2.0 Guide. It is available through the optional `v2` Cargo feature:
+//!
+//! <https://www.first.org/cvss/v2/guide>
+//!
+//! The [`Cvss`] type can hold a `CVSS:3.x` or `CVSS:4.0` vector string.
+//!
 //! Serde support is available through the optional `serde` Cargo feature.
 
-// TODO(tarcieri): other CVSS versions, CVSS v3.1 Temporal and Environmental Groups
-
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/RustSec/logos/main/rustsec-logo-lg.png",
     html_root_url = "https://docs.rs/cvss/1.0.2"
@@ -22,10 +37,22 @@
 pub mod error;
 pub mod severity;
 
+#[cfg(any(feature = "v3", feature = "v4"))]
+mod cvss;
+
+#[cfg(feature = "v2")]
+pub mod v2;
+
 #[cfg(feature = "v3")]
 pub mod v3;
 
+#[cfg(feature = "v4")]
+pub mod v4;
+
 pub use self::severity::Severity;
 
+#[cfg(any(feature = "v3", feature = "v4"))]
+pub use self::cvss::Cvss;
+
 /// Prefix used by all CVSS strings
 pub const PREFIX: &str = "CVSS";
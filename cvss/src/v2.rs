@@ -0,0 +1,18 @@
+//! Common Vulnerability Scoring System (v2.0)
+//!
+//! <https://www.first.org/cvss/v2/guide>
+//!
+//! CVSS v2.0 has been superseded by CVSS v3, however many older CVEs only
+//! have a CVSS v2.0 vector in the NVD.
+
+pub mod base;
+pub mod environmental;
+pub mod metric;
+pub mod score;
+pub mod temporal;
+pub mod vector;
+
+pub use self::{
+    base::Base, environmental::Environmental, metric::Metric, score::Score, temporal::Temporal,
+    vector::Vector,
+};
@@ -0,0 +1,93 @@
+//! CVSS v2.0 Base Metric Group
+
+pub mod a;
+pub mod ac;
+pub mod au;
+pub mod av;
+pub mod c;
+pub mod i;
+
+use super::{Metric, Score};
+use crate::Severity;
+
+pub use self::{
+    a::AvailabilityImpact, ac::AccessComplexity, au::Authentication, av::AccessVector,
+    c::ConfidentialityImpact, i::IntegrityImpact,
+};
+
+/// CVSS v2.0 Base Metric Group
+///
+/// Described in CVSS v2.0 Guide: Section 2.1:
+/// <https://www.first.org/cvss/v2/guide#i2.1>
+///
+/// > The base metric group captures the characteristics of a vulnerability
+/// > that are constant with time and across user environments. The Access
+/// > Vector, Access Complexity, and Authentication metrics capture how the
+/// > vulnerability is accessed and whether or not extra conditions are
+/// > required to exploit it. The three impact metrics measure how a
+/// > vulnerability, if exploited, will directly affect an IT asset.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base {
+    /// Access Vector (AV)
+    pub av: AccessVector,
+
+    /// Access Complexity (AC)
+    pub ac: AccessComplexity,
+
+    /// Authentication (Au)
+    pub au: Authentication,
+
+    /// Confidentiality Impact (C)
+    pub c: ConfidentialityImpact,
+
+    /// Integrity Impact (I)
+    pub i: IntegrityImpact,
+
+    /// Availability Impact (A)
+    pub a: AvailabilityImpact,
+}
+
+impl Base {
+    /// Calculate Base CVSS score.
+    ///
+    /// Described in CVSS v2.0 Guide: Section 3.2.1:
+    /// <https://www.first.org/cvss/v2/guide#i3.2.1>
+    ///
+    /// > BaseScore = round_to_1_decimal(((0.6*Impact)+(0.4*Exploitability)-1.5)*f(Impact))
+    pub fn score(&self) -> Score {
+        self.score_with_impact(self.impact())
+    }
+
+    /// Calculate Base Exploitability sub-score.
+    ///
+    /// > Exploitability = 20* AccessVector*AccessComplexity*Authentication
+    pub fn exploitability(&self) -> Score {
+        (20.0 * self.av.score() * self.ac.score() * self.au.score()).into()
+    }
+
+    /// Calculate Base Impact sub-score.
+    ///
+    /// > Impact = 10.41*(1-(1-ConfImpact)*(1-IntegImpact)*(1-AvailImpact))
+    pub fn impact(&self) -> Score {
+        (10.41 * (1.0 - (1.0 - self.c.score()) * (1.0 - self.i.score()) * (1.0 - self.a.score())))
+            .into()
+    }
+
+    /// Calculate `Severity` according to the NVD CVSS v2.0 ratings
+    pub fn severity(&self) -> Severity {
+        self.score().severity()
+    }
+
+    /// Calculate the Base score using the given Impact sub-score, which is
+    /// also used by the Environmental equation with the AdjustedImpact.
+    pub(super) fn score_with_impact(&self, impact: Score) -> Score {
+        let impact = impact.value();
+
+        // > f(impact) = 0 if Impact=0, 1.176 otherwise
+        let f_impact = if impact == 0.0 { 0.0 } else { 1.176 };
+
+        let score = ((0.6 * impact) + (0.4 * self.exploitability().value()) - 1.5) * f_impact;
+
+        Score::new(score).round()
+    }
+}
@@ -0,0 +1,73 @@
+//! Availability Impact (A)
+
+use crate::{
+    error::{Error, ErrorKind},
+    v2::Metric,
+};
+use std::{fmt, str::FromStr};
+
+/// Availability Impact (A) - CVSS v2.0 Base Metric Group
+///
+/// Described in CVSS v2.0 Guide: Section 2.1.6:
+/// <https://www.first.org/cvss/v2/guide#i2.1.6>
+///
+/// > This metric measures the impact to availability of a successfully
+/// > exploited vulnerability. Availability refers to the accessibility of
+/// > information resources.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum AvailabilityImpact {
+    /// None (N)
+    ///
+    /// > There is no impact to the availability of the system.
+    None,
+
+    /// Partial (P)
+    ///
+    /// > There is reduced performance or interruptions in resource
+    /// > availability.
+    Partial,
+
+    /// Complete (C)
+    ///
+    /// > There is a total shutdown of the affected resource.
+    Complete,
+}
+
+impl Metric for AvailabilityImpact {
+    const NAME: &'static str = "A";
+
+    fn score(self) -> f64 {
+        match self {
+            AvailabilityImpact::None => 0.0,
+            AvailabilityImpact::Partial => 0.275,
+            AvailabilityImpact::Complete => 0.66,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            AvailabilityImpact::None => "N",
+            AvailabilityImpact::Partial => "P",
+            AvailabilityImpact::Complete => "C",
+        }
+    }
+}
+
+impl fmt::Display for AvailabilityImpact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::NAME, self.as_str())
+    }
+}
+
+impl FromStr for AvailabilityImpact {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "N" => Ok(AvailabilityImpact::None),
+            "P" => Ok(AvailabilityImpact::Partial),
+            "C" => Ok(AvailabilityImpact::Complete),
+            other => fail!(ErrorKind::Parse, "invalid A (Base): {}", other),
+        }
+    }
+}
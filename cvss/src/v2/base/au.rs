@@ -0,0 +1,75 @@
+//! Authentication (Au)
+
+use crate::{
+    error::{Error, ErrorKind},
+    v2::Metric,
+};
+use std::{fmt, str::FromStr};
+
+/// Authentication (Au) - CVSS v2.0 Base Metric Group
+///
+/// Described in CVSS v2.0 Guide: Section 2.1.3:
+/// <https://www.first.org/cvss/v2/guide#i2.1.3>
+///
+/// > This metric measures the number of times an attacker must authenticate to
+/// > a target in order to exploit a vulnerability. [...] The fewer
+/// > authentication instances that are required, the higher the vulnerability
+/// > score.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum Authentication {
+    /// Multiple (M)
+    ///
+    /// > Exploiting the vulnerability requires that the attacker authenticate
+    /// > two or more times, even if the same credentials are used each time.
+    Multiple,
+
+    /// Single (S)
+    ///
+    /// > The vulnerability requires an attacker to be logged into the system
+    /// > (such as at a command line or via a desktop session or web interface).
+    Single,
+
+    /// None (N)
+    ///
+    /// > Authentication is not required to exploit the vulnerability.
+    None,
+}
+
+impl Metric for Authentication {
+    const NAME: &'static str = "Au";
+
+    fn score(self) -> f64 {
+        match self {
+            Authentication::Multiple => 0.45,
+            Authentication::Single => 0.56,
+            Authentication::None => 0.704,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Authentication::Multiple => "M",
+            Authentication::Single => "S",
+            Authentication::None => "N",
+        }
+    }
+}
+
+impl fmt::Display for Authentication {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::NAME, self.as_str())
+    }
+}
+
+impl FromStr for Authentication {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "M" => Ok(Authentication::Multiple),
+            "S" => Ok(Authentication::Single),
+            "N" => Ok(Authentication::None),
+            other => fail!(ErrorKind::Parse, "invalid Au (Base): {}", other),
+        }
+    }
+}
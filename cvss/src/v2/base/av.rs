@@ -0,0 +1,78 @@
+//! Access Vector (AV)
+
+use crate::{
+    error::{Error, ErrorKind},
+    v2::Metric,
+};
+use std::{fmt, str::FromStr};
+
+/// Access Vector (AV) - CVSS v2.0 Base Metric Group
+///
+/// Described in CVSS v2.0 Guide: Section 2.1.1:
+/// <https://www.first.org/cvss/v2/guide#i2.1.1>
+///
+/// > This metric reflects how the vulnerability is exploited. The more remote
+/// > an attacker can be to attack a host, the greater the vulnerability score.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum AccessVector {
+    /// Local (L)
+    ///
+    /// > A vulnerability exploitable with only local access requires the
+    /// > attacker to have either physical access to the vulnerable system or a
+    /// > local (shell) account.
+    Local,
+
+    /// Adjacent Network (A)
+    ///
+    /// > A vulnerability exploitable with adjacent network access requires the
+    /// > attacker to have access to either the broadcast or collision domain of
+    /// > the vulnerable software.
+    AdjacentNetwork,
+
+    /// Network (N)
+    ///
+    /// > A vulnerability exploitable with network access means the vulnerable
+    /// > software is bound to the network stack and the attacker does not
+    /// > require local network access or local access. Such a vulnerability is
+    /// > often termed "remotely exploitable".
+    Network,
+}
+
+impl Metric for AccessVector {
+    const NAME: &'static str = "AV";
+
+    fn score(self) -> f64 {
+        match self {
+            AccessVector::Local => 0.395,
+            AccessVector::AdjacentNetwork => 0.646,
+            AccessVector::Network => 1.0,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            AccessVector::Local => "L",
+            AccessVector::AdjacentNetwork => "A",
+            AccessVector::Network => "N",
+        }
+    }
+}
+
+impl fmt::Display for AccessVector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::NAME, self.as_str())
+    }
+}
+
+impl FromStr for AccessVector {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "L" => Ok(AccessVector::Local),
+            "A" => Ok(AccessVector::AdjacentNetwork),
+            "N" => Ok(AccessVector::Network),
+            other => fail!(ErrorKind::Parse, "invalid AV (Base): {}", other),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! Confidentiality Impact (C)
+
+use crate::{
+    error::{Error, ErrorKind},
+    v2::Metric,
+};
+use std::{fmt, str::FromStr};
+
+/// Confidentiality Impact (C) - CVSS v2.0 Base Metric Group
+///
+/// Described in CVSS v2.0 Guide: Section 2.1.4:
+/// <https://www.first.org/cvss/v2/guide#i2.1.4>
+///
+/// > This metric measures the impact on confidentiality of a successfully
+/// > exploited vulnerability. Confidentiality refers to limiting information
+/// > access and disclosure to only authorized users, as well as preventing
+/// > access by, or disclosure to, unauthorized ones.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ConfidentialityImpact {
+    /// None (N)
+    ///
+    /// > There is no impact to the confidentiality of the system.
+    None,
+
+    /// Partial (P)
+    ///
+    /// > There is considerable informational disclosure. Access to some system
+    /// > files is possible, but the attacker does not have control over what is
+    /// > obtained, or the scope of the loss is constrained.
+    Partial,
+
+    /// Complete (C)
+    ///
+    /// > There is total information disclosure, resulting in all system files
+    /// > being revealed.
+    Complete,
+}
+
+impl Metric for ConfidentialityImpact {
+    const NAME: &'static str = "C";
+
+    fn score(self) -> f64 {
+        match self {
+            ConfidentialityImpact::None => 0.0,
+            ConfidentialityImpact::Partial => 0.275,
+            ConfidentialityImpact::Complete => 0.66,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ConfidentialityImpact::None => "N",
+            ConfidentialityImpact::Partial => "P",
+            ConfidentialityImpact::Complete => "C",
+        }
+    }
+}
+
+impl fmt::Display for ConfidentialityImpact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::NAME, self.as_str())
+    }
+}
+
+impl FromStr for ConfidentialityImpact {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "N" => Ok(ConfidentialityImpact::None),
+            "P" => Ok(ConfidentialityImpact::Partial),
+            "C" => Ok(ConfidentialityImpact::Complete),
+            other => fail!(ErrorKind::Parse, "invalid C (Base): {}", other),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! Integrity Impact (I)
+
+use crate::{
+    error::{Error, ErrorKind},
+    v2::Metric,
+};
+use std::{fmt, str::FromStr};
+
+/// Integrity Impact (I) - CVSS v2.0 Base Metric Group
+///
+/// Described in CVSS v2.0 Guide: Section 2.1.5:
+/// <https://www.first.org/cvss/v2/guide#i2.1.5>
+///
+/// > This metric measures the impact to integrity of a successfully exploited
+/// > vulnerability. Integrity refers to the trustworthiness and guaranteed
+/// > veracity of information.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum IntegrityImpact {
+    /// None (N)
+    ///
+    /// > There is no impact to the integrity of the system.
+    None,
+
+    /// Partial (P)
+    ///
+    /// > Modification of some system files or information is possible, but the
+    /// > attacker does not have control over what can be modified, or the scope
+    /// > of what the attacker can affect is limited.
+    Partial,
+
+    /// Complete (C)
+    ///
+    /// > There is a total compromise of system integrity.
+    Complete,
+}
+
+impl Metric for IntegrityImpact {
+    const NAME: &'static str = "I";
+
+    fn score(self) -> f64 {
+        match self {
+            IntegrityImpact::None => 0.0,
+            IntegrityImpact::Partial => 0.275,
+            IntegrityImpact::Complete => 0.66,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            IntegrityImpact::None => "N",
+            IntegrityImpact::Partial => "P",
+            IntegrityImpact::Complete => "C",
+        }
+    }
+}
+
+impl fmt::Display for IntegrityImpact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::NAME, self.as_str())
+    }
+}
+
+impl FromStr for IntegrityImpact {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "N" => Ok(IntegrityImpact::None),
+            "P" => Ok(IntegrityImpact::Partial),
+            "C" => Ok(IntegrityImpact::Complete),
+            other => fail!(ErrorKind::Parse, "invalid I (Base): {}", other),
+        }
+    }
+}
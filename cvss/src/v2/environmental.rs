@@ -0,0 +1,85 @@
+//! CVSS v2.0 Environmental Metric Group
+
+pub mod ar;
+pub mod cdp;
+pub mod cr;
+pub mod ir;
+pub mod td;
+
+use super::{Base, Metric, Score, Temporal};
+
+pub use self::{
+    ar::AvailabilityRequirement, cdp::CollateralDamagePotential, cr::ConfidentialityRequirement,
+    ir::IntegrityRequirement, td::TargetDistribution,
+};
+
+/// CVSS v2.0 Environmental Metric Group
+///
+/// Described in CVSS v2.0 Guide: Section 2.3:
+/// <https://www.first.org/cvss/v2/guide#i2.3>
+///
+/// > Different environments can have an immense bearing on the risk that a
+/// > vulnerability poses to an organization and its stakeholders. The CVSS
+/// > environmental metric group captures the characteristics of a
+/// > vulnerability that are associated with a user's IT environment.
+///
+/// Metrics which are Not Defined (`ND`) are `None`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Environmental {
+    /// Collateral Damage Potential (CDP)
+    pub cdp: Option<CollateralDamagePotential>,
+
+    /// Target Distribution (TD)
+    pub td: Option<TargetDistribution>,
+
+    /// Confidentiality Requirement (CR)
+    pub cr: Option<ConfidentialityRequirement>,
+
+    /// Integrity Requirement (IR)
+    pub ir: Option<IntegrityRequirement>,
+
+    /// Availability Requirement (AR)
+    pub ar: Option<AvailabilityRequirement>,
+}
+
+impl Environmental {
+    /// Calculate Environmental CVSS score.
+    ///
+    /// Described in CVSS v2.0 Guide: Section 3.2.3:
+    /// <https://www.first.org/cvss/v2/guide#i3.2.3>
+    ///
+    /// > EnvironmentalScore = round_to_1_decimal((AdjustedTemporal+
+    /// > (10-AdjustedTemporal)*CollateralDamagePotential)*TargetDistribution)
+    pub fn score(&self, base: &Base, temporal: &Temporal) -> Score {
+        let adjusted_base = base.score_with_impact(self.adjusted_impact(base));
+        let adjusted_temporal = temporal.score_with_base_score(adjusted_base).value();
+
+        let cdp_score = self.cdp.map(|cdp| cdp.score()).unwrap_or(0.0);
+        let td_score = self.td.map(|td| td.score()).unwrap_or(1.0);
+
+        Score::new((adjusted_temporal + (10.0 - adjusted_temporal) * cdp_score) * td_score).round()
+    }
+
+    /// Calculate the Impact sub-score adjusted for the Security Requirements.
+    ///
+    /// > AdjustedImpact = min(10,10.41*(1-(1-ConfImpact*ConfReq)*(1-IntegImpact*IntegReq)
+    /// > *(1-AvailImpact*AvailReq)))
+    pub fn adjusted_impact(&self, base: &Base) -> Score {
+        let cr_score = self.cr.map(|cr| cr.score()).unwrap_or(1.0);
+        let ir_score = self.ir.map(|ir| ir.score()).unwrap_or(1.0);
+        let ar_score = self.ar.map(|ar| ar.score()).unwrap_or(1.0);
+
+        let impact = 10.41
+            * (1.0
+                - (1.0 - base.c.score() * cr_score)
+                    * (1.0 - base.i.score() * ir_score)
+                    * (1.0 - base.a.score() * ar_score));
+
+        impact.min(10.0).into()
+    }
+
+    /// Are all Environmental metrics Not Defined?
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
@@ -0,0 +1,78 @@
+//! Availability Requirement (AR)
+
+use crate::{
+    error::{Error, ErrorKind},
+    v2::Metric,
+};
+use std::{fmt, str::FromStr};
+
+/// Availability Requirement (AR) - CVSS v2.0 Environmental Metric Group
+///
+/// Described in CVSS v2.0 Guide: Section 2.3.3:
+/// <https://www.first.org/cvss/v2/guide#i2.3.3>
+///
+/// > These metrics enable the analyst to customize the CVSS score depending on
+/// > the importance of the affected IT asset to a user’s organization, measured
+/// > in terms of confidentiality, integrity, and availability.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum AvailabilityRequirement {
+    /// Low (L)
+    ///
+    /// > Loss of availability is likely to have only a limited adverse effect
+    /// > on the organization or individuals associated with the organization
+    /// > (e.g., employees, customers).
+    Low,
+
+    /// Medium (M)
+    ///
+    /// > Loss of availability is likely to have a serious adverse effect on the
+    /// > organization or individuals associated with the organization (e.g.,
+    /// > employees, customers).
+    Medium,
+
+    /// High (H)
+    ///
+    /// > Loss of availability is likely to have a catastrophic adverse effect
+    /// > on the organization or individuals associated with the organization
+    /// > (e.g., employees, customers).
+    High,
+}
+
+impl Metric for AvailabilityRequirement {
+    const NAME: &'static str = "AR";
+
+    fn score(self) -> f64 {
+        match self {
+            AvailabilityRequirement::Low => 0.5,
+            AvailabilityRequirement::Medium => 1.0,
+            AvailabilityRequirement::High => 1.51,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            AvailabilityRequirement::Low => "L",
+            AvailabilityRequirement::Medium => "M",
+            AvailabilityRequirement::High => "H",
+        }
+    }
+}
+
+impl fmt::Display for AvailabilityRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::NAME, self.as_str())
+    }
+}
+
+impl FromStr for AvailabilityRequirement {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "L" => Ok(AvailabilityRequirement::Low),
+            "M" => Ok(AvailabilityRequirement::Medium),
+            "H" => Ok(AvailabilityRequirement::High),
+            other => fail!(ErrorKind::Parse, "invalid AR (Environmental): {}", other),
+        }
+    }
+}
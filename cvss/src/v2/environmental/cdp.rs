@@ -0,0 +1,97 @@
+//! Collateral Damage Potential (CDP)
+
+use crate::{
+    error::{Error, ErrorKind},
+    v2::Metric,
+};
+use std::{fmt, str::FromStr};
+
+/// Collateral Damage Potential (CDP) - CVSS v2.0 Environmental Metric Group
+///
+/// Described in CVSS v2.0 Guide: Section 2.3.1:
+/// <https://www.first.org/cvss/v2/guide#i2.3.1>
+///
+/// > This metric measures the potential for loss of life or physical assets
+/// > through damage or theft of property or equipment. The metric may also
+/// > measure economic loss of productivity or revenue.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum CollateralDamagePotential {
+    /// None (N)
+    ///
+    /// > There is no potential for loss of life, physical assets, productivity
+    /// > or revenue.
+    None,
+
+    /// Low (L)
+    ///
+    /// > A successful exploit of this vulnerability may result in slight
+    /// > physical or property damage. Or, there may be a slight loss of revenue
+    /// > or productivity to the organization.
+    Low,
+
+    /// Low-Medium (LM)
+    ///
+    /// > A successful exploit of this vulnerability may result in moderate
+    /// > physical or property damage. Or, there may be a moderate loss of
+    /// > revenue or productivity to the organization.
+    LowMedium,
+
+    /// Medium-High (MH)
+    ///
+    /// > A successful exploit of this vulnerability may result in significant
+    /// > physical or property damage or loss. Or, there may be a significant
+    /// > loss of revenue or productivity.
+    MediumHigh,
+
+    /// High (H)
+    ///
+    /// > A successful exploit of this vulnerability may result in catastrophic
+    /// > physical or property damage and loss. Or, there may be a catastrophic
+    /// > loss of revenue or productivity.
+    High,
+}
+
+impl Metric for CollateralDamagePotential {
+    const NAME: &'static str = "CDP";
+
+    fn score(self) -> f64 {
+        match self {
+            CollateralDamagePotential::None => 0.0,
+            CollateralDamagePotential::Low => 0.1,
+            CollateralDamagePotential::LowMedium => 0.3,
+            CollateralDamagePotential::MediumHigh => 0.4,
+            CollateralDamagePotential::High => 0.5,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            CollateralDamagePotential::None => "N",
+            CollateralDamagePotential::Low => "L",
+            CollateralDamagePotential::LowMedium => "LM",
+            CollateralDamagePotential::MediumHigh => "MH",
+            CollateralDamagePotential::High => "H",
+        }
+    }
+}
+
+impl fmt::Display for CollateralDamagePotential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::NAME, self.as_str())
+    }
+}
+
+impl FromStr for CollateralDamagePotential {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "N" => Ok(CollateralDamagePotential::None),
+            "L" => Ok(CollateralDamagePotential::Low),
+            "LM" => Ok(CollateralDamagePotential::LowMedium),
+            "MH" => Ok(CollateralDamagePotential::MediumHigh),
+            "H" => Ok(CollateralDamagePotential::High),
+            other => fail!(ErrorKind::Parse, "invalid CDP (Environmental): {}", other),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Confidentiality Requirement (CR)
+
+use crate::{
+    error::{Error, ErrorKind},
+    v2::Metric,
+};
+use std::{fmt, str::FromStr};
+
+/// Confidentiality Requirement (CR) - CVSS v2.0 Environmental Metric Group
+///
+/// Described in CVSS v2.0 Guide: Section 2.3.3:
+/// <https://www.first.org/cvss/v2/guide#i2.3.3>
+///
+/// > These metrics enable the analyst to customize the CVSS score depending on
+/// > the importance of the affected IT asset to a user’s organization, measured
+/// > in terms of confidentiality, integrity, and availability.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ConfidentialityRequirement {
+    /// Low (L)
+    ///
+    /// > Loss of confidentiality is likely to have only a limited adverse
+    /// > effect on the organization or individuals associated with the
+    /// > organization (e.g., employees, customers).
+    Low,
+
+    /// Medium (M)
+    ///
+    /// > Loss of confidentiality is likely to have a serious adverse effect on
+    /// > the organization or individuals associated with the organization
+    /// > (e.g., employees, customers).
+    Medium,
+
+    /// High (H)
+    ///
+    /// > Loss of confidentiality is likely to have a catastrophic adverse
+    /// > effect on the organization or individuals associated with the
+    /// > organization (e.g., employees, customers).
+    High,
+}
+
+impl Metric for ConfidentialityRequirement {
+    const NAME: &'static str = "CR";
+
+    fn score(self) -> f64 {
+        match self {
+            ConfidentialityRequirement::Low => 0.5,
+            ConfidentialityRequirement::Medium => 1.0,
+            ConfidentialityRequirement::High => 1.51,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ConfidentialityRequirement::Low => "L",
+            ConfidentialityRequirement::Medium => "M",
+            ConfidentialityRequirement::High => "H",
+        }
+    }
+}
+
+impl fmt::Display for ConfidentialityRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::NAME, self.as_str())
+    }
+}
+
+impl FromStr for ConfidentialityRequirement {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "L" => Ok(ConfidentialityRequirement::Low),
+            "M" => Ok(ConfidentialityRequirement::Medium),
+            "H" => Ok(ConfidentialityRequirement::High),
+            other => fail!(ErrorKind::Parse, "invalid CR (Environmental): {}", other),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Integrity Requirement (IR)
+
+use crate::{
+    error::{Error, ErrorKind},
+    v2::Metric,
+};
+use std::{fmt, str::FromStr};
+
+/// Integrity Requirement (IR) - CVSS v2.0 Environmental Metric Group
+///
+/// Described in CVSS v2.0 Guide: Section 2.3.3:
+/// <https://www.first.org/cvss/v2/guide#i2.3.3>
+///
+/// > These metrics enable the analyst to customize the CVSS score depending on
+/// > the importance of the affected IT asset to a user’s organization, measured
+/// > in terms of confidentiality, integrity, and availability.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum IntegrityRequirement {
+    /// Low (L)
+    ///
+    /// > Loss of integrity is likely to have only a limited adverse effect on
+    /// > the organization or individuals associated with the organization
+    /// > (e.g., employees, customers).
+    Low,
+
+    /// Medium (M)
+    ///
+    /// > Loss of integrity is likely to have a serious adverse effect on the
+    /// > organization or individuals associated with the organization (e.g.,
+    /// > employees, customers).
+    Medium,
+
+    /// High (H)
+    ///
+    /// > Loss of integrity is likely to have a catastrophic adverse effect on
+    /// > the organization or individuals associated with the organization
+    /// > (e.g., employees, customers).
+    High,
+}
+
+impl Metric for IntegrityRequirement {
+    const NAME: &'static str = "IR";
+
+    fn score(self) -> f64 {
+        match self {
+            IntegrityRequirement::Low => 0.5,
+            IntegrityRequirement::Medium => 1.0,
+            IntegrityRequirement::High => 1.51,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            IntegrityRequirement::Low => "L",
+            IntegrityRequirement::Medium => "M",
+            IntegrityRequirement::High => "H",
+        }
+    }
+}
+
+impl fmt::Display for IntegrityRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::NAME, self.as_str())
+    }
+}
+
+impl FromStr for IntegrityRequirement {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "L" => Ok(IntegrityRequirement::Low),
+            "M" => Ok(IntegrityRequirement::Medium),
+            "H" => Ok(IntegrityRequirement::High),
+            other => fail!(ErrorKind::Parse, "invalid IR (Environmental): {}", other),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! CVSS v2.0 metrics
+
+use std::{
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+
+/// Trait for CVSS v2.0 metrics
+pub trait Metric: Copy + Clone + Debug + Display + Eq + FromStr + Ord {
+    /// Name of the metric (e.g. `AV`, `Au`, `CDP`)
+    const NAME: &'static str;
+
+    /// Get CVSS v2.0 score for this metric
+    fn score(self) -> f64;
+
+    /// Get `str` describing this metric's value
+    fn as_str(self) -> &'static str;
+}
@@ -0,0 +1,66 @@
+//! CVSS v2.0 scores
+
+use crate::severity::Severity;
+
+/// CVSS v2.0 scores.
+///
+/// Formulas described in CVSS v2.0 Guide: Section 3.2:
+/// <https://www.first.org/cvss/v2/guide#i3.2>
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Score(f64);
+
+impl Score {
+    /// Create a new score object
+    pub fn new(score: f64) -> Score {
+        Score(score)
+    }
+
+    /// Get the score as a floating point value
+    pub fn value(self) -> f64 {
+        self.0
+    }
+
+    /// Round the score to one decimal place (`round_to_1_decimal` in the
+    /// CVSS v2.0 equations).
+    pub fn round(self) -> Score {
+        // Compensate for floating point error, e.g. `4.349999999999999`
+        const EPSILON: f64 = 1e-6;
+        Score(((self.0 + EPSILON) * 10.0).round() / 10.0)
+    }
+
+    /// Convert the numeric score into a `Severity`
+    ///
+    /// CVSS v2.0 does not define a qualitative severity rating scale, so this
+    /// uses the ranges from the NVD: Low (0.0 - 3.9), Medium (4.0 - 6.9) and
+    /// High (7.0 - 10.0). CVSS v2.0 scores are therefore never
+    /// `Severity::None` or `Severity::Critical`.
+    ///
+    /// <https://nvd.nist.gov/vuln-metrics/cvss>
+    pub fn severity(self) -> Severity {
+        if self.0 < 4.0 {
+            Severity::Low
+        } else if self.0 < 7.0 {
+            Severity::Medium
+        } else {
+            Severity::High
+        }
+    }
+}
+
+impl From<f64> for Score {
+    fn from(score: f64) -> Score {
+        Score(score)
+    }
+}
+
+impl From<Score> for f64 {
+    fn from(score: Score) -> f64 {
+        score.value()
+    }
+}
+
+impl From<Score> for Severity {
+    fn from(score: Score) -> Severity {
+        score.severity()
+    }
+}
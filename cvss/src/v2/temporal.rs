@@ -0,0 +1,60 @@
+//! CVSS v2.0 Temporal Metric Group
+
+pub mod e;
+pub mod rc;
+pub mod rl;
+
+use super::{Base, Metric, Score};
+
+pub use self::{e::Exploitability, rc::ReportConfidence, rl::RemediationLevel};
+
+/// CVSS v2.0 Temporal Metric Group
+///
+/// Described in CVSS v2.0 Guide: Section 2.2:
+/// <https://www.first.org/cvss/v2/guide#i2.2>
+///
+/// > The threat posed by a vulnerability may change over time. Three such
+/// > factors that CVSS captures are: confirmation of the technical details of
+/// > a vulnerability, the remediation status of the vulnerability, and the
+/// > availability of exploit code or techniques.
+///
+/// Metrics which are Not Defined (`ND`) are `None`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Temporal {
+    /// Exploitability (E)
+    pub e: Option<Exploitability>,
+
+    /// Remediation Level (RL)
+    pub rl: Option<RemediationLevel>,
+
+    /// Report Confidence (RC)
+    pub rc: Option<ReportConfidence>,
+}
+
+impl Temporal {
+    /// Calculate Temporal CVSS score.
+    ///
+    /// Described in CVSS v2.0 Guide: Section 3.2.2:
+    /// <https://www.first.org/cvss/v2/guide#i3.2.2>
+    ///
+    /// > TemporalScore = round_to_1_decimal(BaseScore*Exploitability
+    /// > *RemediationLevel*ReportConfidence)
+    pub fn score(&self, base: &Base) -> Score {
+        self.score_with_base_score(base.score())
+    }
+
+    /// Are all Temporal metrics Not Defined?
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Calculate the Temporal score from the given Base score, which is also
+    /// used by the Environmental equation with the AdjustedBase score.
+    pub(super) fn score_with_base_score(&self, base_score: Score) -> Score {
+        let e_score = self.e.map(|e| e.score()).unwrap_or(1.0);
+        let rl_score = self.rl.map(|rl| rl.score()).unwrap_or(1.0);
+        let rc_score = self.rc.map(|rc| rc.score()).unwrap_or(1.0);
+
+        Score::new(base_score.value() * e_score * rl_score * rc_score).round()
+    }
+}
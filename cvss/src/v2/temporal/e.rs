@@ -0,0 +1,84 @@
+//! Exploitability (E)
+
+use crate::{
+    error::{Error, ErrorKind},
+    v2::Metric,
+};
+use std::{fmt, str::FromStr};
+
+/// Exploitability (E) - CVSS v2.0 Temporal Metric Group
+///
+/// Described in CVSS v2.0 Guide: Section 2.2.1:
+/// <https://www.first.org/cvss/v2/guide#i2.2.1>
+///
+/// > This metric measures the current state of exploit techniques or code
+/// > availability. [...] The more easily a vulnerability can be exploited, the
+/// > higher the vulnerability score.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum Exploitability {
+    /// Unproven (U)
+    ///
+    /// > No exploit code is available, or an exploit is entirely theoretical.
+    Unproven,
+
+    /// Proof-of-Concept (POC)
+    ///
+    /// > Proof-of-concept exploit code or an attack demonstration that is not
+    /// > practical for most systems is available.
+    ProofOfConcept,
+
+    /// Functional (F)
+    ///
+    /// > Functional exploit code is available. The code works in most
+    /// > situations where the vulnerability exists.
+    Functional,
+
+    /// High (H)
+    ///
+    /// > Either the vulnerability is exploitable by functional mobile
+    /// > autonomous code, or no exploit is required (manual trigger) and
+    /// > details are widely available.
+    High,
+}
+
+impl Metric for Exploitability {
+    const NAME: &'static str = "E";
+
+    fn score(self) -> f64 {
+        match self {
+            Exploitability::Unproven => 0.85,
+            Exploitability::ProofOfConcept => 0.9,
+            Exploitability::Functional => 0.95,
+            Exploitability::High => 1.0,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Exploitability::Unproven => "U",
+            Exploitability::ProofOfConcept => "POC",
+            Exploitability::Functional => "F",
+            Exploitability::High => "H",
+        }
+    }
+}
+
+impl fmt::Display for Exploitability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::NAME, self.as_str())
+    }
+}
+
+impl FromStr for Exploitability {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "U" => Ok(Exploitability::Unproven),
+            "POC" => Ok(Exploitability::ProofOfConcept),
+            "F" => Ok(Exploitability::Functional),
+            "H" => Ok(Exploitability::High),
+            other => fail!(ErrorKind::Parse, "invalid E (Temporal): {}", other),
+        }
+    }
+}
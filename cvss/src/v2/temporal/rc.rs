@@ -0,0 +1,76 @@
+//! Report Confidence (RC)
+
+use crate::{
+    error::{Error, ErrorKind},
+    v2::Metric,
+};
+use std::{fmt, str::FromStr};
+
+/// Report Confidence (RC) - CVSS v2.0 Temporal Metric Group
+///
+/// Described in CVSS v2.0 Guide: Section 2.2.3:
+/// <https://www.first.org/cvss/v2/guide#i2.2.3>
+///
+/// > This metric measures the degree of confidence in the existence of the
+/// > vulnerability and the credibility of the known technical details. [...]
+/// > The more a vulnerability is validated by the vendor or other reputable
+/// > sources, the higher the score.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ReportConfidence {
+    /// Unconfirmed (UC)
+    ///
+    /// > There is a single unconfirmed source or possibly multiple conflicting
+    /// > reports.
+    Unconfirmed,
+
+    /// Uncorroborated (UR)
+    ///
+    /// > There are multiple non-official sources, possibly including
+    /// > independent security companies or research organizations.
+    Uncorroborated,
+
+    /// Confirmed (C)
+    ///
+    /// > The vulnerability has been acknowledged by the vendor or author of the
+    /// > affected technology.
+    Confirmed,
+}
+
+impl Metric for ReportConfidence {
+    const NAME: &'static str = "RC";
+
+    fn score(self) -> f64 {
+        match self {
+            ReportConfidence::Unconfirmed => 0.9,
+            ReportConfidence::Uncorroborated => 0.95,
+            ReportConfidence::Confirmed => 1.0,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ReportConfidence::Unconfirmed => "UC",
+            ReportConfidence::Uncorroborated => "UR",
+            ReportConfidence::Confirmed => "C",
+        }
+    }
+}
+
+impl fmt::Display for ReportConfidence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::NAME, self.as_str())
+    }
+}
+
+impl FromStr for ReportConfidence {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "UC" => Ok(ReportConfidence::Unconfirmed),
+            "UR" => Ok(ReportConfidence::Uncorroborated),
+            "C" => Ok(ReportConfidence::Confirmed),
+            other => fail!(ErrorKind::Parse, "invalid RC (Temporal): {}", other),
+        }
+    }
+}
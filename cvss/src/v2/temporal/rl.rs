@@ -0,0 +1,81 @@
+//! Remediation Level (RL)
+
+use crate::{
+    error::{Error, ErrorKind},
+    v2::Metric,
+};
+use std::{fmt, str::FromStr};
+
+/// Remediation Level (RL) - CVSS v2.0 Temporal Metric Group
+///
+/// Described in CVSS v2.0 Guide: Section 2.2.2:
+/// <https://www.first.org/cvss/v2/guide#i2.2.2>
+///
+/// > The remediation level of a vulnerability is an important factor for
+/// > prioritization. [...] The less official and permanent a fix, the higher
+/// > the vulnerability score is.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum RemediationLevel {
+    /// Official Fix (OF)
+    ///
+    /// > A complete vendor solution is available. Either the vendor has issued
+    /// > an official patch, or an upgrade is available.
+    OfficialFix,
+
+    /// Temporary Fix (TF)
+    ///
+    /// > There is an official but temporary fix available.
+    TemporaryFix,
+
+    /// Workaround (W)
+    ///
+    /// > There is an unofficial, non-vendor solution available.
+    Workaround,
+
+    /// Unavailable (U)
+    ///
+    /// > There is either no solution available or it is impossible to apply.
+    Unavailable,
+}
+
+impl Metric for RemediationLevel {
+    const NAME: &'static str = "RL";
+
+    fn score(self) -> f64 {
+        match self {
+            RemediationLevel::OfficialFix => 0.87,
+            RemediationLevel::TemporaryFix => 0.9,
+            RemediationLevel::Workaround => 0.95,
+            RemediationLevel::Unavailable => 1.0,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            RemediationLevel::OfficialFix => "OF",
+            RemediationLevel::TemporaryFix => "TF",
+            RemediationLevel::Workaround => "W",
+            RemediationLevel::Unavailable => "U",
+        }
+    }
+}
+
+impl fmt::Display for RemediationLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::NAME, self.as_str())
+    }
+}
+
+impl FromStr for RemediationLevel {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "OF" => Ok(RemediationLevel::OfficialFix),
+            "TF" => Ok(RemediationLevel::TemporaryFix),
+            "W" => Ok(RemediationLevel::Workaround),
+            "U" => Ok(RemediationLevel::Unavailable),
+            other => fail!(ErrorKind::Parse, "invalid RL (Temporal): {}", other),
+        }
+    }
+}
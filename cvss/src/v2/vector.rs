@@ -0,0 +1,212 @@
+//! CVSS v2.0 vectors
+
+use super::{Base, Environmental, Score, Temporal};
+use crate::{
+    error::{Error, ErrorKind},
+    Severity,
+};
+#[cfg(feature = "serde")]
+use serde::{de, ser, Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// Value of a metric which is Not Defined
+const NOT_DEFINED: &str = "ND";
+
+/// CVSS v2.0 vector containing the Base metric group along with the
+/// (optional) Temporal and Environmental metric groups.
+///
+/// Described in CVSS v2.0 Guide: Section 2.4:
+/// <https://www.first.org/cvss/v2/guide#i2.4>
+///
+/// Unlike later versions, CVSS v2.0 vectors have no `CVSS:` prefix,
+/// e.g. `AV:N/AC:L/Au:N/C:P/I:P/A:P`. Vectors wrapped in parentheses, as
+/// found in some older NVD data, are also accepted when parsing.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Vector {
+    /// Base metric group
+    pub base: Base,
+
+    /// Temporal metric group
+    pub temporal: Temporal,
+
+    /// Environmental metric group
+    pub environmental: Environmental,
+}
+
+impl Vector {
+    /// Calculate the Base CVSS score
+    pub fn base_score(&self) -> Score {
+        self.base.score()
+    }
+
+    /// Calculate the Temporal CVSS score
+    pub fn temporal_score(&self) -> Score {
+        self.temporal.score(&self.base)
+    }
+
+    /// Calculate the Environmental CVSS score
+    pub fn environmental_score(&self) -> Score {
+        self.environmental.score(&self.base, &self.temporal)
+    }
+
+    /// Calculate the most specific CVSS score for this vector: the
+    /// Environmental score if any Environmental metrics are defined, otherwise
+    /// the Temporal score if any Temporal metrics are defined, otherwise the
+    /// Base score.
+    pub fn score(&self) -> Score {
+        if !self.environmental.is_empty() {
+            self.environmental_score()
+        } else if !self.temporal.is_empty() {
+            self.temporal_score()
+        } else {
+            self.base_score()
+        }
+    }
+
+    /// Calculate `Severity` of the most specific CVSS score for this vector
+    /// according to the NVD CVSS v2.0 ratings
+    pub fn severity(&self) -> Severity {
+        self.score().severity()
+    }
+}
+
+impl From<Base> for Vector {
+    fn from(base: Base) -> Vector {
+        Vector {
+            base,
+            temporal: Temporal::default(),
+            environmental: Environmental::default(),
+        }
+    }
+}
+
+macro_rules! write_metrics {
+    ($f:expr, $($metric:expr),+) => {
+        $(
+            if let Some(metric) = $metric {
+                write!($f, "/{}", metric)?;
+            }
+        )+
+    };
+}
+
+impl fmt::Display for Vector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let base = &self.base;
+        let temporal = &self.temporal;
+        let env = &self.environmental;
+
+        write!(
+            f,
+            "{}/{}/{}/{}/{}/{}",
+            base.av, base.ac, base.au, base.c, base.i, base.a
+        )?;
+        write_metrics!(f, temporal.e, temporal.rl, temporal.rc);
+        write_metrics!(f, env.cdp, env.td, env.cr, env.ir, env.ar);
+        Ok(())
+    }
+}
+
+/// Set an optional metric, rejecting duplicates
+macro_rules! set_metric {
+    ($field:expr, $id:expr, $value:expr) => {{
+        if $field.is_some() {
+            fail!(ErrorKind::Parse, "duplicate metric: '{}'", $id);
+        }
+        $field = Some($value.parse()?);
+    }};
+}
+
+impl FromStr for Vector {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let vector = if s.starts_with('(') && s.ends_with(')') {
+            &s[1..s.len() - 1]
+        } else {
+            s
+        };
+
+        let (mut av, mut ac, mut au, mut c, mut i, mut a) = (None, None, None, None, None, None);
+        let mut temporal = Temporal::default();
+        let mut env = Environmental::default();
+        let mut not_defined = vec![];
+
+        for component in vector.split('/') {
+            let mut parts = component.split(':');
+
+            let (id, value) = match (parts.next(), parts.next(), parts.next()) {
+                (Some(id), Some(value), None) if !id.is_empty() => (id, value),
+                _ => fail!(
+                    ErrorKind::Parse,
+                    "malformed CVSS vector component: '{}'",
+                    component
+                ),
+            };
+
+            if value == NOT_DEFINED {
+                if not_defined.contains(&id) {
+                    fail!(ErrorKind::Parse, "duplicate metric: '{}'", id);
+                }
+                not_defined.push(id);
+                continue;
+            }
+
+            match id {
+                "AV" => set_metric!(av, id, value),
+                "AC" => set_metric!(ac, id, value),
+                "Au" => set_metric!(au, id, value),
+                "C" => set_metric!(c, id, value),
+                "I" => set_metric!(i, id, value),
+                "A" => set_metric!(a, id, value),
+                "E" => set_metric!(temporal.e, id, value),
+                "RL" => set_metric!(temporal.rl, id, value),
+                "RC" => set_metric!(temporal.rc, id, value),
+                "CDP" => set_metric!(env.cdp, id, value),
+                "TD" => set_metric!(env.td, id, value),
+                "CR" => set_metric!(env.cr, id, value),
+                "IR" => set_metric!(env.ir, id, value),
+                "AR" => set_metric!(env.ar, id, value),
+                other => fail!(ErrorKind::Parse, "unknown metric type: '{}'", other),
+            }
+        }
+
+        let base = match (av, ac, au, c, i, a) {
+            (Some(av), Some(ac), Some(au), Some(c), Some(i), Some(a)) => Base {
+                av,
+                ac,
+                au,
+                c,
+                i,
+                a,
+            },
+            _ => fail!(
+                ErrorKind::Parse,
+                "missing base metric in CVSS v2.0 vector: '{}'",
+                s
+            ),
+        };
+
+        Ok(Vector {
+            base,
+            temporal,
+            environmental: env,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Vector {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use de::Error;
+        let string = String::deserialize(deserializer)?;
+        string.parse().map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Vector {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_string().serialize(serializer)
+    }
+}
@@ -2,10 +2,14 @@
 //!
 //! <https://www.first.org/cvss/specification-document>
 
-// TODO(tarcieri): Environmental and Temporal Metrics
-
 pub mod base;
+pub mod environmental;
 pub mod metric;
 pub mod score;
+pub mod temporal;
+pub mod vector;
 
-pub use self::{base::Base, metric::Metric, score::Score};
+pub use self::{
+    base::Base, environmental::Environmental, metric::Metric, score::Score, temporal::Temporal,
+    vector::Vector,
+};
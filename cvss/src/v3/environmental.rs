@@ -0,0 +1,159 @@
+//! CVSS v3.1 Environmental Metric Group
+
+pub mod ar;
+pub mod cr;
+pub mod ir;
+
+use super::{
+    base::{
+        AttackComplexity, AttackVector, Availability, Confidentiality, Integrity,
+        PrivilegesRequired, Scope, UserInteraction,
+    },
+    Base, Metric, Score, Temporal,
+};
+
+pub use self::{
+    ar::AvailabilityRequirement, cr::ConfidentialityRequirement, ir::IntegrityRequirement,
+};
+
+/// CVSS v3.1 Environmental Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 4:
+/// <https://www.first.org/cvss/specification-document#t13>
+///
+/// > These metrics enable the analyst to customize the CVSS score depending
+/// > on the importance of the affected IT asset to a user’s organization,
+/// > measured in terms of complementary/alternative security controls in
+/// > place, Confidentiality, Integrity, and Availability. The metrics are the
+/// > modified equivalent of Base metrics and are assigned values based on the
+/// > component placement within organizational infrastructure.
+///
+/// Metrics which are Not Defined (`X`) are `None`. Modified Base metrics use
+/// the same types as their Base metric counterparts, and fall back to the
+/// Base metric value when not defined.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Environmental {
+    /// Confidentiality Requirement (CR)
+    pub cr: Option<ConfidentialityRequirement>,
+
+    /// Integrity Requirement (IR)
+    pub ir: Option<IntegrityRequirement>,
+
+    /// Availability Requirement (AR)
+    pub ar: Option<AvailabilityRequirement>,
+
+    /// Modified Attack Vector (MAV)
+    pub mav: Option<AttackVector>,
+
+    /// Modified Attack Complexity (MAC)
+    pub mac: Option<AttackComplexity>,
+
+    /// Modified Privileges Required (MPR)
+    pub mpr: Option<PrivilegesRequired>,
+
+    /// Modified User Interaction (MUI)
+    pub mui: Option<UserInteraction>,
+
+    /// Modified Scope (MS)
+    pub ms: Option<Scope>,
+
+    /// Modified Confidentiality (MC)
+    pub mc: Option<Confidentiality>,
+
+    /// Modified Integrity (MI)
+    pub mi: Option<Integrity>,
+
+    /// Modified Availability (MA)
+    pub ma: Option<Availability>,
+}
+
+impl Environmental {
+    /// Calculate Environmental CVSS score: the Temporal score recalculated
+    /// using the Modified Base metrics and Security Requirements.
+    ///
+    /// Described in CVSS v3.1 Specification: Section 7.3:
+    /// <https://www.first.org/cvss/specification-document#t22>
+    pub fn score(&self, base: &Base, temporal: &Temporal) -> Score {
+        let exploitability = self.modified_exploitability(base).value();
+        let impact = self.modified_impact(base).value();
+
+        if impact <= 0.0 {
+            return Score::new(0.0);
+        }
+
+        let score = if !self.is_scope_changed(base) {
+            (impact + exploitability).min(10.0)
+        } else {
+            (1.08 * (impact + exploitability)).min(10.0)
+        };
+
+        Score::new(Score::new(score).roundup().value() * temporal.multiplier()).roundup()
+    }
+
+    /// Calculate Modified Exploitability sub-score.
+    ///
+    /// Described in CVSS v3.1 Specification: Section 7.3:
+    /// <https://www.first.org/cvss/specification-document#t22>
+    ///
+    /// > ModifiedExploitability = 8.22 × ModifiedAttackVector ×
+    /// > ModifiedAttackComplexity × ModifiedPrivilegesRequired ×
+    /// > ModifiedUserInteraction
+    pub fn modified_exploitability(&self, base: &Base) -> Score {
+        let av_score = self.mav.or(base.av).map(|av| av.score()).unwrap_or(0.0);
+        let ac_score = self.mac.or(base.ac).map(|ac| ac.score()).unwrap_or(0.0);
+        let ui_score = self.mui.or(base.ui).map(|ui| ui.score()).unwrap_or(0.0);
+        let pr_score = self
+            .mpr
+            .or(base.pr)
+            .map(|pr| pr.scoped_score(self.is_scope_changed(base)))
+            .unwrap_or(0.0);
+
+        (8.22 * av_score * ac_score * pr_score * ui_score).into()
+    }
+
+    /// Calculate Modified Impact sub-score, taking the Security Requirements
+    /// and Modified Scope into account.
+    ///
+    /// Described in CVSS v3.1 Specification: Section 7.3:
+    /// <https://www.first.org/cvss/specification-document#t22>
+    ///
+    /// > MISS = Minimum ( 1 - [ (1 - ConfidentialityRequirement ×
+    /// > ModifiedConfidentiality) × (1 - IntegrityRequirement ×
+    /// > ModifiedIntegrity) × (1 - AvailabilityRequirement ×
+    /// > ModifiedAvailability) ], 0.915)
+    pub fn modified_impact(&self, base: &Base) -> Score {
+        let c_score = self.mc.or(base.c).map(|c| c.score()).unwrap_or(0.0);
+        let i_score = self.mi.or(base.i).map(|i| i.score()).unwrap_or(0.0);
+        let a_score = self.ma.or(base.a).map(|a| a.score()).unwrap_or(0.0);
+        let cr_score = self.cr.map(|cr| cr.score()).unwrap_or(1.0);
+        let ir_score = self.ir.map(|ir| ir.score()).unwrap_or(1.0);
+        let ar_score = self.ar.map(|ar| ar.score()).unwrap_or(1.0);
+
+        let miss = (1.0
+            - ((1.0 - cr_score * c_score)
+                * (1.0 - ir_score * i_score)
+                * (1.0 - ar_score * a_score)))
+            .min(0.915);
+
+        let impact = if !self.is_scope_changed(base) {
+            6.42 * miss
+        } else if base.minor_version == 0 {
+            // CVSS v3.0 formula
+            7.52 * (miss - 0.029) - 3.25 * (miss - 0.02).powf(15.0)
+        } else {
+            7.52 * (miss - 0.029) - 3.25 * (miss * 0.9731 - 0.02).powf(13.0)
+        };
+
+        impact.into()
+    }
+
+    /// Are all Environmental metrics Not Defined?
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Has the (modified) scope changed?
+    fn is_scope_changed(&self, base: &Base) -> bool {
+        self.ms.or(base.s).map(|s| s.is_changed()).unwrap_or(false)
+    }
+}
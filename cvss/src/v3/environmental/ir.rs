@@ -0,0 +1,78 @@
+//! Integrity Requirement (IR)
+
+use crate::{
+    error::{Error, ErrorKind},
+    v3::Metric,
+};
+use std::{fmt, str::FromStr};
+
+/// Integrity Requirement (IR) - CVSS v3.1 Environmental Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 4.1:
+/// <https://www.first.org/cvss/specification-document#t13>
+///
+/// > These metrics enable the analyst to customize the CVSS score depending on
+/// > the importance of the affected IT asset to a user’s organization,
+/// > measured in terms of Confidentiality, Integrity, and Availability.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum IntegrityRequirement {
+    /// Low (L)
+    ///
+    /// > Loss of Integrity is likely to have only a limited adverse effect on
+    /// > the organization or individuals associated with the organization
+    /// > (e.g., employees, customers).
+    Low,
+
+    /// Medium (M)
+    ///
+    /// > Loss of Integrity is likely to have a serious adverse effect on the
+    /// > organization or individuals associated with the organization
+    /// > (e.g., employees, customers).
+    Medium,
+
+    /// High (H)
+    ///
+    /// > Loss of Integrity is likely to have a catastrophic adverse effect on
+    /// > the organization or individuals associated with the organization
+    /// > (e.g., employees, customers).
+    High,
+}
+
+impl Metric for IntegrityRequirement {
+    const NAME: &'static str = "IR";
+
+    fn score(self) -> f64 {
+        match self {
+            IntegrityRequirement::Low => 0.5,
+            IntegrityRequirement::Medium => 1.0,
+            IntegrityRequirement::High => 1.5,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            IntegrityRequirement::Low => "L",
+            IntegrityRequirement::Medium => "M",
+            IntegrityRequirement::High => "H",
+        }
+    }
+}
+
+impl fmt::Display for IntegrityRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::NAME, self.as_str())
+    }
+}
+
+impl FromStr for IntegrityRequirement {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "L" => Ok(IntegrityRequirement::Low),
+            "M" => Ok(IntegrityRequirement::Medium),
+            "H" => Ok(IntegrityRequirement::High),
+            other => fail!(ErrorKind::Parse, "invalid IR (Environmental): {}", other),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! CVSS v3.1 Temporal Metric Group
+
+pub mod e;
+pub mod rc;
+pub mod rl;
+
+use super::{Base, Metric, Score};
+
+pub use self::{e::ExploitCodeMaturity, rc::ReportConfidence, rl::RemediationLevel};
+
+/// CVSS v3.1 Temporal Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 3:
+/// <https://www.first.org/cvss/specification-document#t11>
+///
+/// > The Temporal metrics measure the current state of exploit techniques or
+/// > code availability, the existence of any patches or workarounds, or the
+/// > confidence in the description of a vulnerability.
+///
+/// Metrics which are Not Defined (`X`) are `None`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Temporal {
+    /// Exploit Code Maturity (E)
+    pub e: Option<ExploitCodeMaturity>,
+
+    /// Remediation Level (RL)
+    pub rl: Option<RemediationLevel>,
+
+    /// Report Confidence (RC)
+    pub rc: Option<ReportConfidence>,
+}
+
+impl Temporal {
+    /// Calculate Temporal CVSS score: the Base score adjusted for the
+    /// current state of exploitation and remediation.
+    ///
+    /// Described in CVSS v3.1 Specification: Section 7.2:
+    /// <https://www.first.org/cvss/specification-document#t21>
+    ///
+    /// > TemporalScore = Roundup(BaseScore × ExploitCodeMaturity ×
+    /// > RemediationLevel × ReportConfidence)
+    pub fn score(&self, base: &Base) -> Score {
+        Score::new(base.score().value() * self.multiplier()).roundup()
+    }
+
+    /// Product of all Temporal metric values (Not Defined counts as 1)
+    pub(super) fn multiplier(&self) -> f64 {
+        let e_score = self.e.map(|e| e.score()).unwrap_or(1.0);
+        let rl_score = self.rl.map(|rl| rl.score()).unwrap_or(1.0);
+        let rc_score = self.rc.map(|rc| rc.score()).unwrap_or(1.0);
+        e_score * rl_score * rc_score
+    }
+
+    /// Are all Temporal metrics Not Defined?
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
@@ -0,0 +1,85 @@
+//! Exploit Code Maturity (E)
+
+use crate::{
+    error::{Error, ErrorKind},
+    v3::Metric,
+};
+use std::{fmt, str::FromStr};
+
+/// Exploit Code Maturity (E) - CVSS v3.1 Temporal Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 3.1:
+/// <https://www.first.org/cvss/specification-document#t11>
+///
+/// > This metric measures the likelihood of the vulnerability being attacked,
+/// > and is typically based on the current state of exploit techniques,
+/// > exploit code availability, or active, “in-the-wild” exploitation. [...]
+/// > The more easily a vulnerability can be exploited, the higher the
+/// > vulnerability score.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ExploitCodeMaturity {
+    /// Unproven (U)
+    ///
+    /// > No exploit code is available, or an exploit is theoretical.
+    Unproven,
+
+    /// Proof-of-Concept (P)
+    ///
+    /// > Proof-of-concept exploit code is available, or an attack demonstration
+    /// > is not practical for most systems.
+    ProofOfConcept,
+
+    /// Functional (F)
+    ///
+    /// > Functional exploit code is available. The code works in most situations
+    /// > where the vulnerability exists.
+    Functional,
+
+    /// High (H)
+    ///
+    /// > Functional autonomous code exists, or no exploit is required (manual
+    /// > trigger) and details are widely available.
+    High,
+}
+
+impl Metric for ExploitCodeMaturity {
+    const NAME: &'static str = "E";
+
+    fn score(self) -> f64 {
+        match self {
+            ExploitCodeMaturity::Unproven => 0.91,
+            ExploitCodeMaturity::ProofOfConcept => 0.94,
+            ExploitCodeMaturity::Functional => 0.97,
+            ExploitCodeMaturity::High => 1.0,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ExploitCodeMaturity::Unproven => "U",
+            ExploitCodeMaturity::ProofOfConcept => "P",
+            ExploitCodeMaturity::Functional => "F",
+            ExploitCodeMaturity::High => "H",
+        }
+    }
+}
+
+impl fmt::Display for ExploitCodeMaturity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::NAME, self.as_str())
+    }
+}
+
+impl FromStr for ExploitCodeMaturity {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "U" => Ok(ExploitCodeMaturity::Unproven),
+            "P" => Ok(ExploitCodeMaturity::ProofOfConcept),
+            "F" => Ok(ExploitCodeMaturity::Functional),
+            "H" => Ok(ExploitCodeMaturity::High),
+            other => fail!(ErrorKind::Parse, "invalid E (Temporal): {}", other),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! Report Confidence (RC)
+
+use crate::{
+    error::{Error, ErrorKind},
+    v3::Metric,
+};
+use std::{fmt, str::FromStr};
+
+/// Report Confidence (RC) - CVSS v3.1 Temporal Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 3.3:
+/// <https://www.first.org/cvss/specification-document#t11>
+///
+/// > This metric measures the degree of confidence in the existence of the
+/// > vulnerability and the credibility of the known technical details. [...]
+/// > The more a vulnerability is validated by the vendor or other reputable
+/// > sources, the higher the score.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ReportConfidence {
+    /// Unknown (U)
+    ///
+    /// > There are reports of impacts that indicate a vulnerability is present.
+    /// > The reports indicate that the cause of the vulnerability is unknown.
+    Unknown,
+
+    /// Reasonable (R)
+    ///
+    /// > Significant details are published, but researchers either do not have
+    /// > full confidence in the root cause, or do not have access to source code
+    /// > to fully confirm all of the interactions that may lead to the result.
+    Reasonable,
+
+    /// Confirmed (C)
+    ///
+    /// > Detailed reports exist, or functional reproduction is possible
+    /// > (functional exploits may provide this).
+    Confirmed,
+}
+
+impl Metric for ReportConfidence {
+    const NAME: &'static str = "RC";
+
+    fn score(self) -> f64 {
+        match self {
+            ReportConfidence::Unknown => 0.92,
+            ReportConfidence::Reasonable => 0.96,
+            ReportConfidence::Confirmed => 1.0,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ReportConfidence::Unknown => "U",
+            ReportConfidence::Reasonable => "R",
+            ReportConfidence::Confirmed => "C",
+        }
+    }
+}
+
+impl fmt::Display for ReportConfidence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::NAME, self.as_str())
+    }
+}
+
+impl FromStr for ReportConfidence {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "U" => Ok(ReportConfidence::Unknown),
+            "R" => Ok(ReportConfidence::Reasonable),
+            "C" => Ok(ReportConfidence::Confirmed),
+            other => fail!(ErrorKind::Parse, "invalid RC (Temporal): {}", other),
+        }
+    }
+}
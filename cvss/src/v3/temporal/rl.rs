@@ -0,0 +1,85 @@
+//! Remediation Level (RL)
+
+use crate::{
+    error::{Error, ErrorKind},
+    v3::Metric,
+};
+use std::{fmt, str::FromStr};
+
+/// Remediation Level (RL) - CVSS v3.1 Temporal Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 3.2:
+/// <https://www.first.org/cvss/specification-document#t11>
+///
+/// > The Remediation Level of a vulnerability is an important factor for
+/// > prioritization. The typical vulnerability is unpatched when initially
+/// > published. Workarounds or hotfixes may offer interim remediation until an
+/// > official patch or upgrade is issued. [...] The less official and
+/// > permanent a fix, the higher the vulnerability score.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum RemediationLevel {
+    /// Official Fix (O)
+    ///
+    /// > A complete vendor solution is available. Either the vendor has issued
+    /// > an official patch, or an upgrade is available.
+    OfficialFix,
+
+    /// Temporary Fix (T)
+    ///
+    /// > There is an official but temporary fix available. This includes
+    /// > instances where the vendor issues a temporary hotfix, tool, or
+    /// > workaround.
+    TemporaryFix,
+
+    /// Workaround (W)
+    ///
+    /// > There is an unofficial, non-vendor solution available.
+    Workaround,
+
+    /// Unavailable (U)
+    ///
+    /// > There is either no solution available or it is impossible to apply.
+    Unavailable,
+}
+
+impl Metric for RemediationLevel {
+    const NAME: &'static str = "RL";
+
+    fn score(self) -> f64 {
+        match self {
+            RemediationLevel::OfficialFix => 0.95,
+            RemediationLevel::TemporaryFix => 0.96,
+            RemediationLevel::Workaround => 0.97,
+            RemediationLevel::Unavailable => 1.0,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            RemediationLevel::OfficialFix => "O",
+            RemediationLevel::TemporaryFix => "T",
+            RemediationLevel::Workaround => "W",
+            RemediationLevel::Unavailable => "U",
+        }
+    }
+}
+
+impl fmt::Display for RemediationLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::NAME, self.as_str())
+    }
+}
+
+impl FromStr for RemediationLevel {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "O" => Ok(RemediationLevel::OfficialFix),
+            "T" => Ok(RemediationLevel::TemporaryFix),
+            "W" => Ok(RemediationLevel::Workaround),
+            "U" => Ok(RemediationLevel::Unavailable),
+            other => fail!(ErrorKind::Parse, "invalid RL (Temporal): {}", other),
+        }
+    }
+}
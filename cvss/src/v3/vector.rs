@@ -0,0 +1,195 @@
+//! CVSS v3.1 vectors with Temporal and Environmental metrics
+
+use super::{Base, Environmental, Score, Temporal};
+use crate::{
+    error::{Error, ErrorKind},
+    Severity,
+};
+#[cfg(feature = "serde")]
+use serde::{de, ser, Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// Identifiers of the Base metrics
+const BASE_METRICS: &[&str] = &["AV", "AC", "PR", "UI", "S", "C", "I", "A"];
+
+/// Value of a metric which is Not Defined
+const NOT_DEFINED: &str = "X";
+
+/// CVSS v3.1 vector containing the Base metric group along with the
+/// (optional) Temporal and Environmental metric groups.
+///
+/// Described in CVSS v3.1 Specification: Section 6:
+/// <https://www.first.org/cvss/specification-document#t18>
+///
+/// > The Temporal and Environmental metrics are optional and their vector
+/// > components may be omitted.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Vector {
+    /// Base metric group
+    pub base: Base,
+
+    /// Temporal metric group
+    pub temporal: Temporal,
+
+    /// Environmental metric group
+    pub environmental: Environmental,
+}
+
+impl Vector {
+    /// Calculate the Base CVSS score
+    pub fn base_score(&self) -> Score {
+        self.base.score()
+    }
+
+    /// Calculate the Temporal CVSS score
+    pub fn temporal_score(&self) -> Score {
+        self.temporal.score(&self.base)
+    }
+
+    /// Calculate the Environmental CVSS score
+    pub fn environmental_score(&self) -> Score {
+        self.environmental.score(&self.base, &self.temporal)
+    }
+
+    /// Calculate the most specific CVSS score for this vector: the
+    /// Environmental score if any Environmental metrics are defined, otherwise
+    /// the Temporal score if any Temporal metrics are defined, otherwise the
+    /// Base score.
+    pub fn score(&self) -> Score {
+        if !self.environmental.is_empty() {
+            self.environmental_score()
+        } else if !self.temporal.is_empty() {
+            self.temporal_score()
+        } else {
+            self.base_score()
+        }
+    }
+
+    /// Calculate `Severity` of the most specific CVSS score for this vector
+    pub fn severity(&self) -> Severity {
+        self.score().severity()
+    }
+}
+
+impl From<Base> for Vector {
+    fn from(base: Base) -> Vector {
+        Vector {
+            base,
+            ..Default::default()
+        }
+    }
+}
+
+macro_rules! write_metrics {
+    ($f:expr, $prefix:expr, $($metric:expr),+) => {
+        $(
+            if let Some(metric) = $metric {
+                write!($f, "/{}{}", $prefix, metric)?;
+            }
+        )+
+    };
+}
+
+impl fmt::Display for Vector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let temporal = &self.temporal;
+        let env = &self.environmental;
+
+        write!(f, "{}", self.base)?;
+        write_metrics!(f, "", temporal.e, temporal.rl, temporal.rc);
+        write_metrics!(f, "", env.cr, env.ir, env.ar);
+        write_metrics!(f, "M", env.mav, env.mac, env.mpr, env.mui, env.ms, env.mc, env.mi, env.ma);
+        Ok(())
+    }
+}
+
+impl FromStr for Vector {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        // Base metrics (along with the prefix) are parsed by `Base`
+        let mut base_string = String::new();
+        let mut other_components = vec![];
+        let mut ids = vec![];
+
+        for (i, component) in s.split('/').enumerate() {
+            let id = component.split(':').next().unwrap_or_default();
+
+            if i > 0 {
+                let id = id.to_ascii_uppercase();
+
+                if ids.contains(&id) {
+                    fail!(ErrorKind::Parse, "duplicate metric: '{}'", id);
+                }
+                ids.push(id);
+            }
+
+            if i == 0 || BASE_METRICS.contains(&id.to_ascii_uppercase().as_str()) {
+                if i > 0 {
+                    base_string.push('/');
+                }
+                base_string.push_str(component);
+            } else {
+                other_components.push(component);
+            }
+        }
+
+        let mut vector = Vector::from(base_string.parse::<Base>()?);
+        let temporal = &mut vector.temporal;
+        let env = &mut vector.environmental;
+
+        for component in other_components {
+            let mut parts = component.split(':');
+            let id = parts.next().unwrap_or_default().to_ascii_uppercase();
+
+            let value = match (parts.next(), parts.next()) {
+                (Some(value), None) => value.to_ascii_uppercase(),
+                _ => fail!(
+                    ErrorKind::Parse,
+                    "malformed CVSS vector component: {}",
+                    component
+                ),
+            };
+
+            if value == NOT_DEFINED {
+                continue;
+            }
+
+            match id.as_str() {
+                "E" => temporal.e = Some(value.parse()?),
+                "RL" => temporal.rl = Some(value.parse()?),
+                "RC" => temporal.rc = Some(value.parse()?),
+                "CR" => env.cr = Some(value.parse()?),
+                "IR" => env.ir = Some(value.parse()?),
+                "AR" => env.ar = Some(value.parse()?),
+                "MAV" => env.mav = Some(value.parse()?),
+                "MAC" => env.mac = Some(value.parse()?),
+                "MPR" => env.mpr = Some(value.parse()?),
+                "MUI" => env.mui = Some(value.parse()?),
+                "MS" => env.ms = Some(value.parse()?),
+                "MC" => env.mc = Some(value.parse()?),
+                "MI" => env.mi = Some(value.parse()?),
+                "MA" => env.ma = Some(value.parse()?),
+                other => fail!(ErrorKind::Parse, "unknown metric type: '{}'", other),
+            }
+        }
+
+        Ok(vector)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Vector {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use de::Error;
+        let string = String::deserialize(deserializer)?;
+        string.parse().map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Vector {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_string().serialize(serializer)
+    }
+}
@@ -0,0 +1,12 @@
+//! Common Vulnerability Scoring System (v4.0)
+//!
+//! <https://www.first.org/cvss/v4.0/specification-document>
+
+pub mod macro_vector;
+pub mod metric;
+pub mod score;
+pub mod vector;
+
+mod scoring;
+
+pub use self::{macro_vector::MacroVector, metric::Metric, score::Score, vector::Vector};
@@ -0,0 +1,133 @@
+//! CVSS v4.0 MacroVectors
+
+use super::{scoring, Vector};
+use std::fmt;
+
+/// CVSS v4.0 MacroVector
+///
+/// Described in CVSS v4.0 Specification: Section 8.2:
+/// <https://www.first.org/cvss/v4.0/specification-document#CVSS-v4-0-Scoring-using-MacroVectors-and-Interpolation>
+///
+/// > The CVSS v4.0 scoring system is based on grouping vectors into
+/// > equivalence sets and assigning a score to each set. [...] Each
+/// > equivalence set is represented by a MacroVector: a vector of the levels
+/// > of each of the six equivalence sets (EQ1 through EQ6).
+///
+/// A lower level means a higher severity, i.e. `000000` is the most severe
+/// MacroVector.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct MacroVector {
+    /// EQ1: AV/PR/UI (levels 0-2)
+    pub eq1: u8,
+
+    /// EQ2: AC/AT (levels 0-1)
+    pub eq2: u8,
+
+    /// EQ3: VC/VI/VA (levels 0-2)
+    pub eq3: u8,
+
+    /// EQ4: SC/SI/SA (levels 0-2)
+    pub eq4: u8,
+
+    /// EQ5: E (levels 0-2)
+    pub eq5: u8,
+
+    /// EQ6: VC/VI/VA and CR/IR/AR (levels 0-1)
+    pub eq6: u8,
+}
+
+impl MacroVector {
+    /// Get the score of the highest severity vector in this MacroVector, as
+    /// assigned by the CVSS v4.0 lookup table.
+    ///
+    /// Returns `None` if this isn't a valid MacroVector.
+    pub fn score(self) -> Option<f64> {
+        scoring::lookup(self)
+    }
+}
+
+impl From<&Vector> for MacroVector {
+    fn from(vector: &Vector) -> MacroVector {
+        let m = |metric| vector.effective(metric);
+
+        // EQ1: 0-AV:N and PR:N and UI:N
+        //      1-(AV:N or PR:N or UI:N) and not (AV:N and PR:N and UI:N) and not AV:P
+        //      2-AV:P or not(AV:N or PR:N or UI:N)
+        let eq1 = if m("AV") == "N" && m("PR") == "N" && m("UI") == "N" {
+            0
+        } else if (m("AV") == "N" || m("PR") == "N" || m("UI") == "N") && m("AV") != "P" {
+            1
+        } else {
+            2
+        };
+
+        // EQ2: 0-(AC:L and AT:N)
+        //      1-not (AC:L and AT:N)
+        let eq2 = if m("AC") == "L" && m("AT") == "N" {
+            0
+        } else {
+            1
+        };
+
+        // EQ3: 0-(VC:H and VI:H)
+        //      1-not (VC:H and VI:H) and (VC:H or VI:H or VA:H)
+        //      2-not (VC:H or VI:H or VA:H)
+        let eq3 = if m("VC") == "H" && m("VI") == "H" {
+            0
+        } else if m("VC") == "H" || m("VI") == "H" || m("VA") == "H" {
+            1
+        } else {
+            2
+        };
+
+        // EQ4: 0-(MSI:S or MSA:S)
+        //      1-not (MSI:S or MSA:S) and (SC:H or SI:H or SA:H)
+        //      2-not (MSI:S or MSA:S) and not (SC:H or SI:H or SA:H)
+        let eq4 = if m("SI") == "S" || m("SA") == "S" {
+            0
+        } else if m("SC") == "H" || m("SI") == "H" || m("SA") == "H" {
+            1
+        } else {
+            2
+        };
+
+        // EQ5: 0-E:A
+        //      1-E:P
+        //      2-E:U
+        let eq5 = match m("E") {
+            "A" => 0,
+            "P" => 1,
+            _ => 2,
+        };
+
+        // EQ6: 0-(CR:H and VC:H) or (IR:H and VI:H) or (AR:H and VA:H)
+        //      1-not[(CR:H and VC:H) or (IR:H and VI:H) or (AR:H and VA:H)]
+        let eq6 = if (m("CR") == "H" && m("VC") == "H")
+            || (m("IR") == "H" && m("VI") == "H")
+            || (m("AR") == "H" && m("VA") == "H")
+        {
+            0
+        } else {
+            1
+        };
+
+        MacroVector {
+            eq1,
+            eq2,
+            eq3,
+            eq4,
+            eq5,
+            eq6,
+        }
+    }
+}
+
+impl fmt::Display for MacroVector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}{}{}{}",
+            self.eq1, self.eq2, self.eq3, self.eq4, self.eq5, self.eq6
+        )
+    }
+}
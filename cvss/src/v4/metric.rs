@@ -0,0 +1,80 @@
+//! CVSS v4.0 metrics
+
+use std::{
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+
+/// Trait for CVSS v4.0 metrics
+pub trait Metric: Copy + Clone + Debug + Display + Eq + FromStr + Ord {
+    /// Name of the metric (e.g. `AV`, `VC`, `MSI`)
+    const NAME: &'static str;
+
+    /// Get `str` describing this metric's value
+    fn as_str(self) -> &'static str;
+}
+
+/// Define a CVSS v4.0 metric enum along with its `Metric`, `Display` and
+/// `FromStr` impls.
+///
+/// Unlike CVSS v3.1, individual v4.0 metric values don't carry a numeric
+/// score: the overall score is computed from the MacroVector lookup table.
+macro_rules! metric {
+    (
+        $(#[$attr:meta])*
+        $name:ident($id:expr, $group:expr) {
+            $(
+                $(#[$variant_attr:meta])*
+                $variant:ident => $value:expr,
+            )+
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+        pub enum $name {
+            $(
+                $(#[$variant_attr])*
+                $variant,
+            )+
+        }
+
+        impl crate::v4::Metric for $name {
+            const NAME: &'static str = $id;
+
+            fn as_str(self) -> &'static str {
+                match self {
+                    $($name::$variant => $value,)+
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                use crate::v4::Metric;
+                write!(f, "{}:{}", Self::NAME, self.as_str())
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = crate::error::Error;
+
+            fn from_str(s: &str) -> Result<Self, crate::error::Error> {
+                match s {
+                    $($value => Ok($name::$variant),)+
+                    other => fail!(
+                        crate::error::ErrorKind::Parse,
+                        "invalid {} ({}): {}",
+                        $id,
+                        $group,
+                        other
+                    ),
+                }
+            }
+        }
+    };
+}
+
+pub mod base;
+pub mod environmental;
+pub mod supplemental;
+pub mod threat;
@@ -0,0 +1,336 @@
+//! CVSS v4.0 Base Metric Group
+//!
+//! Described in CVSS v4.0 Specification: Section 2:
+//! <https://www.first.org/cvss/v4.0/specification-document#Base-Metrics>
+//!
+//! > The Base metric group represents the intrinsic characteristics of a
+//! > vulnerability that are constant over time and across user environments.
+//! > It is composed of two sets of metrics: the Exploitability metrics and
+//! > the Impact metrics.
+
+metric! {
+    /// Attack Vector (AV) - CVSS v4.0 Base Metric Group
+    ///
+    /// Described in CVSS v4.0 Specification: Section 2.1.1
+    ///
+    /// > This metric reflects the context by which vulnerability exploitation
+    /// > is possible. This metric value (and consequently the resulting
+    /// > severity) will be larger the more remote (logically, and physically)
+    /// > an attacker can be in order to exploit the vulnerable system.
+    AttackVector("AV", "Base") {
+        /// Physical (P)
+        ///
+        /// > The attack requires the attacker to physically touch or
+        /// > manipulate the vulnerable system.
+        Physical => "P",
+
+        /// Local (L)
+        ///
+        /// > The vulnerable system is not bound to the network stack and the
+        /// > attacker’s path is via read/write/execute capabilities.
+        Local => "L",
+
+        /// Adjacent (A)
+        ///
+        /// > The vulnerable system is bound to a protocol stack, but the attack
+        /// > is limited at the protocol level to a logically adjacent topology.
+        Adjacent => "A",
+
+        /// Network (N)
+        ///
+        /// > The vulnerable system is bound to the network stack and the set of
+        /// > possible attackers extends beyond the other options listed,
+        /// > up to and including the entire Internet.
+        Network => "N",
+    }
+}
+
+metric! {
+    /// Attack Complexity (AC) - CVSS v4.0 Base Metric Group
+    ///
+    /// Described in CVSS v4.0 Specification: Section 2.1.2
+    ///
+    /// > This metric captures measurable actions that must be taken by the
+    /// > attacker to actively evade or circumvent existing built-in
+    /// > security-enhancing conditions in order to obtain a working exploit.
+    AttackComplexity("AC", "Base") {
+        /// High (H)
+        ///
+        /// > The successful attack depends on the evasion or circumvention of
+        /// > security-enhancing techniques in place that would otherwise
+        /// > hinder the attack.
+        High => "H",
+
+        /// Low (L)
+        ///
+        /// > The attacker must take no measurable action to exploit the
+        /// > vulnerability.
+        Low => "L",
+    }
+}
+
+metric! {
+    /// Attack Requirements (AT) - CVSS v4.0 Base Metric Group
+    ///
+    /// Described in CVSS v4.0 Specification: Section 2.1.3
+    ///
+    /// > This metric captures the prerequisite deployment and execution
+    /// > conditions or variables of the vulnerable system that enable the
+    /// > attack.
+    AttackRequirements("AT", "Base") {
+        /// Present (P)
+        ///
+        /// > The successful attack depends on the presence of specific
+        /// > deployment and execution conditions of the vulnerable system that
+        /// > enable the attack.
+        Present => "P",
+
+        /// None (N)
+        ///
+        /// > The successful attack does not depend on the deployment and
+        /// > execution conditions of the vulnerable system.
+        None => "N",
+    }
+}
+
+metric! {
+    /// Privileges Required (PR) - CVSS v4.0 Base Metric Group
+    ///
+    /// Described in CVSS v4.0 Specification: Section 2.1.4
+    ///
+    /// > This metric describes the level of privileges an attacker must
+    /// > possess prior to successfully exploiting the vulnerability.
+    PrivilegesRequired("PR", "Base") {
+        /// High (H)
+        ///
+        /// > The attacker requires privileges that provide significant
+        /// > (e.g., administrative) control over the vulnerable system.
+        High => "H",
+
+        /// Low (L)
+        ///
+        /// > The attacker requires privileges that provide basic capabilities
+        /// > that are typically limited to settings and resources owned by a
+        /// > single low-privileged user.
+        Low => "L",
+
+        /// None (N)
+        ///
+        /// > The attacker is unauthenticated prior to attack, and therefore
+        /// > does not require any access to settings or files of the
+        /// > vulnerable system to carry out an attack.
+        None => "N",
+    }
+}
+
+metric! {
+    /// User Interaction (UI) - CVSS v4.0 Base Metric Group
+    ///
+    /// Described in CVSS v4.0 Specification: Section 2.1.5
+    ///
+    /// > This metric captures the requirement for a human user, other than
+    /// > the attacker, to participate in the successful compromise of the
+    /// > vulnerable system.
+    UserInteraction("UI", "Base") {
+        /// Active (A)
+        ///
+        /// > Successful exploitation of this vulnerability requires a targeted
+        /// > user to perform specific, conscious interactions with the
+        /// > vulnerable system and the attacker’s payload.
+        Active => "A",
+
+        /// Passive (P)
+        ///
+        /// > Successful exploitation of this vulnerability requires limited
+        /// > interaction by the targeted user with the vulnerable system and
+        /// > the attacker’s payload.
+        Passive => "P",
+
+        /// None (N)
+        ///
+        /// > The vulnerable system can be exploited without interaction from
+        /// > any human user, other than the attacker.
+        None => "N",
+    }
+}
+
+metric! {
+    /// Confidentiality Impact to the Vulnerable System (VC) - CVSS v4.0 Base Metric Group
+    ///
+    /// Described in CVSS v4.0 Specification: Section 2.2.2
+    ///
+    /// > This metric measures the impact to the confidentiality of the
+    /// > information managed by the vulnerable system due to a successfully
+    /// > exploited vulnerability.
+    VulnerableConfidentiality("VC", "Base") {
+        /// None (N)
+        ///
+        /// > There is no loss of confidentiality within the vulnerable system.
+        None => "N",
+
+        /// Low (L)
+        ///
+        /// > There is some loss of confidentiality. Access to some restricted
+        /// > information is obtained, but the attacker does not have control
+        /// > over what information is obtained, or the amount or kind of loss
+        /// > is limited.
+        Low => "L",
+
+        /// High (H)
+        ///
+        /// > There is a total loss of confidentiality, resulting in all
+        /// > information within the vulnerable system being divulged to the
+        /// > attacker.
+        High => "H",
+    }
+}
+
+metric! {
+    /// Integrity Impact to the Vulnerable System (VI) - CVSS v4.0 Base Metric Group
+    ///
+    /// Described in CVSS v4.0 Specification: Section 2.2.3
+    ///
+    /// > This metric measures the impact to integrity of a successfully
+    /// > exploited vulnerability. Integrity refers to the trustworthiness and
+    /// > veracity of information.
+    VulnerableIntegrity("VI", "Base") {
+        /// None (N)
+        ///
+        /// > There is no loss of integrity within the vulnerable system.
+        None => "N",
+
+        /// Low (L)
+        ///
+        /// > Modification of data is possible, but the attacker does not have
+        /// > control over the consequence of a modification, or the amount of
+        /// > modification is limited.
+        Low => "L",
+
+        /// High (H)
+        ///
+        /// > There is a total loss of integrity, or a complete loss of
+        /// > protection.
+        High => "H",
+    }
+}
+
+metric! {
+    /// Availability Impact to the Vulnerable System (VA) - CVSS v4.0 Base Metric Group
+    ///
+    /// Described in CVSS v4.0 Specification: Section 2.2.4
+    ///
+    /// > This metric measures the impact to the availability of the
+    /// > vulnerable system resulting from a successfully exploited
+    /// > vulnerability.
+    VulnerableAvailability("VA", "Base") {
+        /// None (N)
+        ///
+        /// > There is no impact to availability within the vulnerable system.
+        None => "N",
+
+        /// Low (L)
+        ///
+        /// > Performance is reduced or there are interruptions in resource
+        /// > availability.
+        Low => "L",
+
+        /// High (H)
+        ///
+        /// > There is a total loss of availability, resulting in the attacker
+        /// > being able to fully deny access to resources in the vulnerable
+        /// > system.
+        High => "H",
+    }
+}
+
+metric! {
+    /// Confidentiality Impact to the Subsequent System (SC) - CVSS v4.0 Base Metric Group
+    ///
+    /// Described in CVSS v4.0 Specification: Section 2.2.5
+    ///
+    /// > This metric measures the impact to the confidentiality of the
+    /// > information managed by the system due to a successfully exploited
+    /// > vulnerability, outside of the vulnerable system.
+    SubsequentConfidentiality("SC", "Base") {
+        /// None (N)
+        ///
+        /// > There is no loss of confidentiality within the subsequent system
+        /// > or all confidentiality impact is constrained to the vulnerable
+        /// > system.
+        None => "N",
+
+        /// Low (L)
+        ///
+        /// > There is some loss of confidentiality. Access to some restricted
+        /// > information is obtained, but the attacker does not have control
+        /// > over what information is obtained.
+        Low => "L",
+
+        /// High (H)
+        ///
+        /// > There is a total loss of confidentiality, resulting in all
+        /// > resources within the subsequent system being divulged to the
+        /// > attacker.
+        High => "H",
+    }
+}
+
+metric! {
+    /// Integrity Impact to the Subsequent System (SI) - CVSS v4.0 Base Metric Group
+    ///
+    /// Described in CVSS v4.0 Specification: Section 2.2.6
+    ///
+    /// > This metric measures the impact to integrity of a successfully
+    /// > exploited vulnerability outside of the vulnerable system.
+    SubsequentIntegrity("SI", "Base") {
+        /// None (N)
+        ///
+        /// > There is no loss of integrity within the subsequent system or all
+        /// > integrity impact is constrained to the vulnerable system.
+        None => "N",
+
+        /// Low (L)
+        ///
+        /// > Modification of data is possible, but the attacker does not have
+        /// > control over the consequence of a modification, or the amount of
+        /// > modification is limited.
+        Low => "L",
+
+        /// High (H)
+        ///
+        /// > There is a total loss of integrity, or a complete loss of
+        /// > protection within the subsequent system.
+        High => "H",
+    }
+}
+
+metric! {
+    /// Availability Impact to the Subsequent System (SA) - CVSS v4.0 Base Metric Group
+    ///
+    /// Described in CVSS v4.0 Specification: Section 2.2.7
+    ///
+    /// > This metric measures the impact to the availability of the
+    /// > subsequent system resulting from a successfully exploited
+    /// > vulnerability.
+    SubsequentAvailability("SA", "Base") {
+        /// None (N)
+        ///
+        /// > There is no impact to availability within the subsequent system
+        /// > or all availability impact is constrained to the vulnerable
+        /// > system.
+        None => "N",
+
+        /// Low (L)
+        ///
+        /// > Performance is reduced or there are interruptions in resource
+        /// > availability.
+        Low => "L",
+
+        /// High (H)
+        ///
+        /// > There is a total loss of availability, resulting in the attacker
+        /// > being able to fully deny access to resources in the subsequent
+        /// > system.
+        High => "H",
+    }
+}
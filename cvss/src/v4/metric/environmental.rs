@@ -0,0 +1,322 @@
+//! CVSS v4.0 Environmental Metric Group
+//!
+//! Described in CVSS v4.0 Specification: Section 4:
+//! <https://www.first.org/cvss/v4.0/specification-document#Environmental-Metrics>
+//!
+//! > These metrics enable the consumer analyst to customize the resulting
+//! > score depending on the importance of the affected IT asset to a user’s
+//! > organization, measured in terms of complementary/alternative security
+//! > controls in place, Confidentiality, Integrity, and Availability.
+//!
+//! The Modified Base metrics override the corresponding Base metric values
+//! when set to anything other than Not Defined (X).
+
+metric! {
+    /// Confidentiality Requirement (CR) - CVSS v4.0 Environmental Metric Group
+    ///
+    /// Described in CVSS v4.0 Specification: Section 4.1
+    ///
+    /// > These metrics enable the consumer to customize the assessment
+    /// > depending on the importance of the affected IT asset to the analyst’s
+    /// > organization, measured in terms of Confidentiality.
+    ConfidentialityRequirement("CR", "Environmental") {
+        /// Not Defined (X)
+        ///
+        /// > This is the default value. Assigning this value indicates there
+        /// > is insufficient information to choose one of the other values.
+        /// > This has the same effect as assigning High as the worst case.
+        NotDefined => "X",
+
+        /// Low (L)
+        ///
+        /// > Loss of Confidentiality is likely to have only a limited adverse
+        /// > effect on the organization or individuals associated with the
+        /// > organization.
+        Low => "L",
+
+        /// Medium (M)
+        ///
+        /// > Loss of Confidentiality is likely to have a serious adverse effect
+        /// > on the organization or individuals associated with the
+        /// > organization.
+        Medium => "M",
+
+        /// High (H)
+        ///
+        /// > Loss of Confidentiality is likely to have a catastrophic adverse
+        /// > effect on the organization or individuals associated with the
+        /// > organization.
+        High => "H",
+    }
+}
+
+metric! {
+    /// Integrity Requirement (IR) - CVSS v4.0 Environmental Metric Group
+    ///
+    /// Described in CVSS v4.0 Specification: Section 4.1
+    ///
+    /// > These metrics enable the consumer to customize the assessment
+    /// > depending on the importance of the affected IT asset to the analyst’s
+    /// > organization, measured in terms of Integrity.
+    IntegrityRequirement("IR", "Environmental") {
+        /// Not Defined (X)
+        NotDefined => "X",
+
+        /// Low (L)
+        Low => "L",
+
+        /// Medium (M)
+        Medium => "M",
+
+        /// High (H)
+        High => "H",
+    }
+}
+
+metric! {
+    /// Availability Requirement (AR) - CVSS v4.0 Environmental Metric Group
+    ///
+    /// Described in CVSS v4.0 Specification: Section 4.1
+    ///
+    /// > These metrics enable the consumer to customize the assessment
+    /// > depending on the importance of the affected IT asset to the analyst’s
+    /// > organization, measured in terms of Availability.
+    AvailabilityRequirement("AR", "Environmental") {
+        /// Not Defined (X)
+        NotDefined => "X",
+
+        /// Low (L)
+        Low => "L",
+
+        /// Medium (M)
+        Medium => "M",
+
+        /// High (H)
+        High => "H",
+    }
+}
+
+metric! {
+    /// Modified Attack Vector (MAV) - CVSS v4.0 Environmental Metric Group
+    ///
+    /// Overrides [`AttackVector`][`super::base::AttackVector`] unless Not Defined.
+    ModifiedAttackVector("MAV", "Environmental") {
+        /// Not Defined (X)
+        NotDefined => "X",
+
+        /// Physical (P)
+        Physical => "P",
+
+        /// Local (L)
+        Local => "L",
+
+        /// Adjacent (A)
+        Adjacent => "A",
+
+        /// Network (N)
+        Network => "N",
+    }
+}
+
+metric! {
+    /// Modified Attack Complexity (MAC) - CVSS v4.0 Environmental Metric Group
+    ///
+    /// Overrides [`AttackComplexity`][`super::base::AttackComplexity`] unless Not Defined.
+    ModifiedAttackComplexity("MAC", "Environmental") {
+        /// Not Defined (X)
+        NotDefined => "X",
+
+        /// High (H)
+        High => "H",
+
+        /// Low (L)
+        Low => "L",
+    }
+}
+
+metric! {
+    /// Modified Attack Requirements (MAT) - CVSS v4.0 Environmental Metric Group
+    ///
+    /// Overrides [`AttackRequirements`][`super::base::AttackRequirements`] unless Not Defined.
+    ModifiedAttackRequirements("MAT", "Environmental") {
+        /// Not Defined (X)
+        NotDefined => "X",
+
+        /// Present (P)
+        Present => "P",
+
+        /// None (N)
+        None => "N",
+    }
+}
+
+metric! {
+    /// Modified Privileges Required (MPR) - CVSS v4.0 Environmental Metric Group
+    ///
+    /// Overrides [`PrivilegesRequired`][`super::base::PrivilegesRequired`] unless Not Defined.
+    ModifiedPrivilegesRequired("MPR", "Environmental") {
+        /// Not Defined (X)
+        NotDefined => "X",
+
+        /// High (H)
+        High => "H",
+
+        /// Low (L)
+        Low => "L",
+
+        /// None (N)
+        None => "N",
+    }
+}
+
+metric! {
+    /// Modified User Interaction (MUI) - CVSS v4.0 Environmental Metric Group
+    ///
+    /// Overrides [`UserInteraction`][`super::base::UserInteraction`] unless Not Defined.
+    ModifiedUserInteraction("MUI", "Environmental") {
+        /// Not Defined (X)
+        NotDefined => "X",
+
+        /// Active (A)
+        Active => "A",
+
+        /// Passive (P)
+        Passive => "P",
+
+        /// None (N)
+        None => "N",
+    }
+}
+
+metric! {
+    /// Modified Vulnerable System Confidentiality (MVC) - CVSS v4.0 Environmental Metric Group
+    ///
+    /// Overrides [`VulnerableConfidentiality`][`super::base::VulnerableConfidentiality`]
+    /// unless Not Defined.
+    ModifiedVulnerableConfidentiality("MVC", "Environmental") {
+        /// Not Defined (X)
+        NotDefined => "X",
+
+        /// None (N)
+        None => "N",
+
+        /// Low (L)
+        Low => "L",
+
+        /// High (H)
+        High => "H",
+    }
+}
+
+metric! {
+    /// Modified Vulnerable System Integrity (MVI) - CVSS v4.0 Environmental Metric Group
+    ///
+    /// Overrides [`VulnerableIntegrity`][`super::base::VulnerableIntegrity`] unless Not Defined.
+    ModifiedVulnerableIntegrity("MVI", "Environmental") {
+        /// Not Defined (X)
+        NotDefined => "X",
+
+        /// None (N)
+        None => "N",
+
+        /// Low (L)
+        Low => "L",
+
+        /// High (H)
+        High => "H",
+    }
+}
+
+metric! {
+    /// Modified Vulnerable System Availability (MVA) - CVSS v4.0 Environmental Metric Group
+    ///
+    /// Overrides [`VulnerableAvailability`][`super::base::VulnerableAvailability`]
+    /// unless Not Defined.
+    ModifiedVulnerableAvailability("MVA", "Environmental") {
+        /// Not Defined (X)
+        NotDefined => "X",
+
+        /// None (N)
+        None => "N",
+
+        /// Low (L)
+        Low => "L",
+
+        /// High (H)
+        High => "H",
+    }
+}
+
+metric! {
+    /// Modified Subsequent System Confidentiality (MSC) - CVSS v4.0 Environmental Metric Group
+    ///
+    /// Overrides [`SubsequentConfidentiality`][`super::base::SubsequentConfidentiality`]
+    /// unless Not Defined.
+    ModifiedSubsequentConfidentiality("MSC", "Environmental") {
+        /// Not Defined (X)
+        NotDefined => "X",
+
+        /// Negligible (N)
+        None => "N",
+
+        /// Low (L)
+        Low => "L",
+
+        /// High (H)
+        High => "H",
+    }
+}
+
+metric! {
+    /// Modified Subsequent System Integrity (MSI) - CVSS v4.0 Environmental Metric Group
+    ///
+    /// Overrides [`SubsequentIntegrity`][`super::base::SubsequentIntegrity`] unless Not Defined.
+    ModifiedSubsequentIntegrity("MSI", "Environmental") {
+        /// Not Defined (X)
+        NotDefined => "X",
+
+        /// Negligible (N)
+        None => "N",
+
+        /// Low (L)
+        Low => "L",
+
+        /// High (H)
+        High => "H",
+
+        /// Safety (S)
+        ///
+        /// > The exploited vulnerability will result in integrity impacts that
+        /// > could cause serious injury or worse (categories of “Marginal” or
+        /// > worse as described in IEC 61508) to a human actor or participant.
+        Safety => "S",
+    }
+}
+
+metric! {
+    /// Modified Subsequent System Availability (MSA) - CVSS v4.0 Environmental Metric Group
+    ///
+    /// Overrides [`SubsequentAvailability`][`super::base::SubsequentAvailability`]
+    /// unless Not Defined.
+    ModifiedSubsequentAvailability("MSA", "Environmental") {
+        /// Not Defined (X)
+        NotDefined => "X",
+
+        /// Negligible (N)
+        None => "N",
+
+        /// Low (L)
+        Low => "L",
+
+        /// High (H)
+        High => "H",
+
+        /// Safety (S)
+        ///
+        /// > The exploited vulnerability will result in availability impacts
+        /// > that could cause serious injury or worse (categories of
+        /// > “Marginal” or worse as described in IEC 61508) to a human actor
+        /// > or participant.
+        Safety => "S",
+    }
+}
@@ -0,0 +1,193 @@
+//! CVSS v4.0 Supplemental Metric Group
+//!
+//! Described in CVSS v4.0 Specification: Section 5:
+//! <https://www.first.org/cvss/v4.0/specification-document#Supplemental-Metrics>
+//!
+//! > The Supplemental metric group is a new optional metric group that
+//! > provides new metrics that describe and measure additional extrinsic
+//! > attributes of a vulnerability. [...] None of the Supplemental metrics
+//! > will have any impact on the final calculated CVSS score.
+
+metric! {
+    /// Safety (S) - CVSS v4.0 Supplemental Metric Group
+    ///
+    /// Described in CVSS v4.0 Specification: Section 5.1
+    ///
+    /// > When a system does have an intended use or fitness of purpose aligned
+    /// > to safety, it is possible that exploiting a vulnerability within that
+    /// > system may have Safety impact which can be represented in the
+    /// > Supplemental Metrics group.
+    Safety("S", "Supplemental") {
+        /// Not Defined (X)
+        NotDefined => "X",
+
+        /// Negligible (N)
+        ///
+        /// > Consequences of the vulnerability meet definition of IEC 61508
+        /// > consequence category "negligible."
+        Negligible => "N",
+
+        /// Present (P)
+        ///
+        /// > Consequences of the vulnerability meet definition of IEC 61508
+        /// > consequence categories of "marginal," "critical," or
+        /// > "catastrophic."
+        Present => "P",
+    }
+}
+
+metric! {
+    /// Automatable (AU) - CVSS v4.0 Supplemental Metric Group
+    ///
+    /// Described in CVSS v4.0 Specification: Section 5.2
+    ///
+    /// > The “Automatable” metric captures the answer to the question ”Can an
+    /// > attacker automate exploitation events for this vulnerability across
+    /// > multiple targets?”
+    Automatable("AU", "Supplemental") {
+        /// Not Defined (X)
+        NotDefined => "X",
+
+        /// No (N)
+        ///
+        /// > Attackers cannot reliably automate all 4 steps of the kill chain
+        /// > for this vulnerability for some reason.
+        No => "N",
+
+        /// Yes (Y)
+        ///
+        /// > Attackers can reliably automate all 4 steps of the kill chain.
+        Yes => "Y",
+    }
+}
+
+metric! {
+    /// Recovery (R) - CVSS v4.0 Supplemental Metric Group
+    ///
+    /// Described in CVSS v4.0 Specification: Section 5.4
+    ///
+    /// > Recovery describes the resilience of a system to recover services,
+    /// > in terms of performance and availability, after an attack has been
+    /// > performed.
+    Recovery("R", "Supplemental") {
+        /// Not Defined (X)
+        NotDefined => "X",
+
+        /// Automatic (A)
+        ///
+        /// > The system recovers services automatically after an attack has
+        /// > been performed.
+        Automatic => "A",
+
+        /// User (U)
+        ///
+        /// > The system requires manual intervention by the user to recover
+        /// > services, after an attack has been performed.
+        User => "U",
+
+        /// Irrecoverable (I)
+        ///
+        /// > The system services are irrecoverable by the user, after an attack
+        /// > has been performed.
+        Irrecoverable => "I",
+    }
+}
+
+metric! {
+    /// Value Density (V) - CVSS v4.0 Supplemental Metric Group
+    ///
+    /// Described in CVSS v4.0 Specification: Section 5.5
+    ///
+    /// > Value Density describes the resources that the attacker will gain
+    /// > control over with a single exploitation event.
+    ValueDensity("V", "Supplemental") {
+        /// Not Defined (X)
+        NotDefined => "X",
+
+        /// Diffuse (D)
+        ///
+        /// > The vulnerable system has limited resources. That is, the
+        /// > resources that the attacker will gain control over with a single
+        /// > exploitation event are relatively small.
+        Diffuse => "D",
+
+        /// Concentrated (C)
+        ///
+        /// > The vulnerable system is rich in resources. Heuristically, such
+        /// > systems are often the direct responsibility of “system operators”
+        /// > rather than users.
+        Concentrated => "C",
+    }
+}
+
+metric! {
+    /// Vulnerability Response Effort (RE) - CVSS v4.0 Supplemental Metric Group
+    ///
+    /// Described in CVSS v4.0 Specification: Section 5.6
+    ///
+    /// > The intention of the Vulnerability Response Effort metric is to
+    /// > provide supplemental information on how difficult it is for consumers
+    /// > to provide an initial response to the impact of vulnerabilities for
+    /// > deployed products and services in their infrastructure.
+    VulnerabilityResponseEffort("RE", "Supplemental") {
+        /// Not Defined (X)
+        NotDefined => "X",
+
+        /// Low (L)
+        ///
+        /// > The effort required to respond to a vulnerability is low/trivial.
+        Low => "L",
+
+        /// Moderate (M)
+        ///
+        /// > The actions required to respond to a vulnerability require some
+        /// > effort on behalf of the consumer and could cause minimal service
+        /// > impact to implement.
+        Moderate => "M",
+
+        /// High (H)
+        ///
+        /// > The actions required to respond to a vulnerability are significant
+        /// > and/or difficult, and may possibly lead to an extended, scheduled
+        /// > service impact.
+        High => "H",
+    }
+}
+
+metric! {
+    /// Provider Urgency (U) - CVSS v4.0 Supplemental Metric Group
+    ///
+    /// Described in CVSS v4.0 Specification: Section 5.3
+    ///
+    /// > To facilitate a standardized method to incorporate additional
+    /// > provider-supplied assessment, an optional “pass-through” Supplemental
+    /// > Metric called Provider Urgency is available.
+    ProviderUrgency("U", "Supplemental") {
+        /// Not Defined (X)
+        NotDefined => "X",
+
+        /// Clear
+        ///
+        /// > Provider has assessed the impact of this vulnerability as having
+        /// > no urgency (Informational).
+        Clear => "Clear",
+
+        /// Green
+        ///
+        /// > Provider has assessed the impact of this vulnerability as having a
+        /// > reduced urgency.
+        Green => "Green",
+
+        /// Amber
+        ///
+        /// > Provider has assessed the impact of this vulnerability as having a
+        /// > moderate urgency.
+        Amber => "Amber",
+
+        /// Red
+        ///
+        /// > Provider has assessed the impact of this vulnerability as having
+        /// > the highest urgency.
+        Red => "Red",
+    }
+}
@@ -0,0 +1,48 @@
+//! CVSS v4.0 Threat Metric Group
+//!
+//! Described in CVSS v4.0 Specification: Section 3:
+//! <https://www.first.org/cvss/v4.0/specification-document#Threat-Metrics>
+//!
+//! > The Threat metrics measure the current state of exploit techniques or
+//! > code availability for a vulnerability.
+
+metric! {
+    /// Exploit Maturity (E) - CVSS v4.0 Threat Metric Group
+    ///
+    /// Described in CVSS v4.0 Specification: Section 3.1
+    ///
+    /// > This metric measures the likelihood of the vulnerability being
+    /// > attacked, and is based on the current state of exploit techniques,
+    /// > exploit code availability, or active, “in-the-wild” exploitation.
+    ExploitMaturity("E", "Threat") {
+        /// Not Defined (X)
+        ///
+        /// > Reliable threat intelligence is not available to determine
+        /// > Exploit Maturity characteristics. This is the default value and
+        /// > is equivalent to Attacked (A) for the purposes of the calculation
+        /// > of the score by assuming the worst case.
+        NotDefined => "X",
+
+        /// Unreported (U)
+        ///
+        /// > Based on available threat intelligence each of the following must
+        /// > apply: no knowledge of publicly available proof-of-concept, no
+        /// > knowledge of reported attempts to exploit this vulnerability.
+        Unreported => "U",
+
+        /// POC (P)
+        ///
+        /// > Based on available threat intelligence proof-of-concept exploit
+        /// > code is publicly available, but there are no known reported
+        /// > attempts to exploit this vulnerability.
+        ProofOfConcept => "P",
+
+        /// Attacked (A)
+        ///
+        /// > Based on available threat intelligence attacks targeting this
+        /// > vulnerability have been reported, or solutions to simplify
+        /// > attempts to exploit the vulnerability are publicly or privately
+        /// > available.
+        Attacked => "A",
+    }
+}
@@ -0,0 +1,68 @@
+//! CVSS v4.0 scores
+
+use crate::severity::Severity;
+
+/// CVSS v4.0 scores.
+///
+/// Unlike CVSS v3.1, CVSS v4.0 scores are not computed from a formula but
+/// interpolated from a lookup table of MacroVector scores, as described in
+/// CVSS v4.0 Specification: Section 8:
+/// <https://www.first.org/cvss/v4.0/specification-document#CVSS-v4-0-Scoring>
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Score(f64);
+
+impl Score {
+    /// Create a new score object
+    pub fn new(score: f64) -> Score {
+        Score(score)
+    }
+
+    /// Get the score as a floating point value
+    pub fn value(self) -> f64 {
+        self.0
+    }
+
+    /// Round the score to one decimal place, as done by the CVSS v4.0
+    /// reference implementation.
+    pub fn round(self) -> Score {
+        // Compensate for floating point error, e.g. `8.649999999999999`
+        const EPSILON: f64 = 1e-6;
+        Score(((self.0 + EPSILON) * 10.0).round() / 10.0)
+    }
+
+    /// Convert the numeric score into a `Severity`
+    ///
+    /// Described in CVSS v4.0 Specification: Section 6:
+    /// <https://www.first.org/cvss/v4.0/specification-document#Qualitative-Severity-Rating-Scale>
+    pub fn severity(self) -> Severity {
+        if self.0 < 0.1 {
+            Severity::None
+        } else if self.0 < 4.0 {
+            Severity::Low
+        } else if self.0 < 7.0 {
+            Severity::Medium
+        } else if self.0 < 9.0 {
+            Severity::High
+        } else {
+            Severity::Critical
+        }
+    }
+}
+
+impl From<f64> for Score {
+    fn from(score: f64) -> Score {
+        Score(score)
+    }
+}
+
+impl From<Score> for f64 {
+    fn from(score: Score) -> f64 {
+        score.value()
+    }
+}
+
+impl From<Score> for Severity {
+    fn from(score: Score) -> Severity {
+        score.severity()
+    }
+}
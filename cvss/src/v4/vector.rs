@@ -0,0 +1,414 @@
+//! CVSS v4.0 vector strings
+
+use super::{
+    metric::{
+        base::{
+            AttackComplexity, AttackRequirements, AttackVector, PrivilegesRequired,
+            SubsequentAvailability, SubsequentConfidentiality, SubsequentIntegrity,
+            UserInteraction, VulnerableAvailability, VulnerableConfidentiality,
+            VulnerableIntegrity,
+        },
+        environmental::{
+            AvailabilityRequirement, ConfidentialityRequirement, IntegrityRequirement,
+            ModifiedAttackComplexity, ModifiedAttackRequirements, ModifiedAttackVector,
+            ModifiedPrivilegesRequired, ModifiedSubsequentAvailability,
+            ModifiedSubsequentConfidentiality, ModifiedSubsequentIntegrity,
+            ModifiedUserInteraction, ModifiedVulnerableAvailability,
+            ModifiedVulnerableConfidentiality, ModifiedVulnerableIntegrity,
+        },
+        supplemental::{
+            Automatable, ProviderUrgency, Recovery, Safety, ValueDensity,
+            VulnerabilityResponseEffort,
+        },
+        threat::ExploitMaturity,
+    },
+    scoring, MacroVector, Metric, Score,
+};
+use crate::{
+    error::{Error, ErrorKind},
+    Severity, PREFIX,
+};
+#[cfg(feature = "serde")]
+use serde::{de, ser, Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// CVSS v4.0 vector
+///
+/// Described in CVSS v4.0 Specification: Section 7:
+/// <https://www.first.org/cvss/v4.0/specification-document#Vector-String>
+///
+/// > The CVSS v4.0 vector string is a text representation of a set of CVSS
+/// > metrics. [...] All Base metrics are mandatory and must be included in
+/// > the vector string. Threat, Environmental, and Supplemental metrics are
+/// > optional, and omitted metrics are considered to have the value of
+/// > Not Defined (X).
+///
+/// Base metrics are therefore plain fields, whereas all other metrics are
+/// `None` when omitted from the vector string. An explicit `X` value is
+/// preserved as the metric's `NotDefined` variant.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Vector {
+    /// Attack Vector (AV)
+    pub av: AttackVector,
+
+    /// Attack Complexity (AC)
+    pub ac: AttackComplexity,
+
+    /// Attack Requirements (AT)
+    pub at: AttackRequirements,
+
+    /// Privileges Required (PR)
+    pub pr: PrivilegesRequired,
+
+    /// User Interaction (UI)
+    pub ui: UserInteraction,
+
+    /// Vulnerable System Confidentiality Impact (VC)
+    pub vc: VulnerableConfidentiality,
+
+    /// Vulnerable System Integrity Impact (VI)
+    pub vi: VulnerableIntegrity,
+
+    /// Vulnerable System Availability Impact (VA)
+    pub va: VulnerableAvailability,
+
+    /// Subsequent System Confidentiality Impact (SC)
+    pub sc: SubsequentConfidentiality,
+
+    /// Subsequent System Integrity Impact (SI)
+    pub si: SubsequentIntegrity,
+
+    /// Subsequent System Availability Impact (SA)
+    pub sa: SubsequentAvailability,
+
+    /// Exploit Maturity (E)
+    pub e: Option<ExploitMaturity>,
+
+    /// Confidentiality Requirement (CR)
+    pub cr: Option<ConfidentialityRequirement>,
+
+    /// Integrity Requirement (IR)
+    pub ir: Option<IntegrityRequirement>,
+
+    /// Availability Requirement (AR)
+    pub ar: Option<AvailabilityRequirement>,
+
+    /// Modified Attack Vector (MAV)
+    pub mav: Option<ModifiedAttackVector>,
+
+    /// Modified Attack Complexity (MAC)
+    pub mac: Option<ModifiedAttackComplexity>,
+
+    /// Modified Attack Requirements (MAT)
+    pub mat: Option<ModifiedAttackRequirements>,
+
+    /// Modified Privileges Required (MPR)
+    pub mpr: Option<ModifiedPrivilegesRequired>,
+
+    /// Modified User Interaction (MUI)
+    pub mui: Option<ModifiedUserInteraction>,
+
+    /// Modified Vulnerable System Confidentiality (MVC)
+    pub mvc: Option<ModifiedVulnerableConfidentiality>,
+
+    /// Modified Vulnerable System Integrity (MVI)
+    pub mvi: Option<ModifiedVulnerableIntegrity>,
+
+    /// Modified Vulnerable System Availability (MVA)
+    pub mva: Option<ModifiedVulnerableAvailability>,
+
+    /// Modified Subsequent System Confidentiality (MSC)
+    pub msc: Option<ModifiedSubsequentConfidentiality>,
+
+    /// Modified Subsequent System Integrity (MSI)
+    pub msi: Option<ModifiedSubsequentIntegrity>,
+
+    /// Modified Subsequent System Availability (MSA)
+    pub msa: Option<ModifiedSubsequentAvailability>,
+
+    /// Safety (S)
+    pub s: Option<Safety>,
+
+    /// Automatable (AU)
+    pub au: Option<Automatable>,
+
+    /// Recovery (R)
+    pub r: Option<Recovery>,
+
+    /// Value Density (V)
+    pub v: Option<ValueDensity>,
+
+    /// Vulnerability Response Effort (RE)
+    pub re: Option<VulnerabilityResponseEffort>,
+
+    /// Provider Urgency (U)
+    pub u: Option<ProviderUrgency>,
+}
+
+impl Vector {
+    /// Calculate the CVSS v4.0 score of this vector.
+    ///
+    /// Which nomenclature applies (i.e. CVSS-B, CVSS-BT, CVSS-BE or CVSS-BTE)
+    /// depends on which Threat and Environmental metrics are present.
+    ///
+    /// Described in CVSS v4.0 Specification: Section 8:
+    /// <https://www.first.org/cvss/v4.0/specification-document#CVSS-v4-0-Scoring>
+    pub fn score(&self) -> Score {
+        scoring::score(self)
+    }
+
+    /// Calculate CVSS v4.0 `Severity` according to the
+    /// Qualitative Severity Rating Scale (i.e. Low / Medium / High / Critical)
+    ///
+    /// Described in CVSS v4.0 Specification: Section 6:
+    /// <https://www.first.org/cvss/v4.0/specification-document#Qualitative-Severity-Rating-Scale>
+    pub fn severity(&self) -> Severity {
+        self.score().severity()
+    }
+
+    /// Get the MacroVector this vector belongs to
+    pub fn macro_vector(&self) -> MacroVector {
+        MacroVector::from(self)
+    }
+
+    /// Get the value of a metric used for scoring, i.e. the Modified Base
+    /// metric value if defined, otherwise the Base metric value, with
+    /// Not Defined (X) Threat and Security Requirement metrics treated as
+    /// their worst case.
+    pub(crate) fn effective(&self, metric: &str) -> &'static str {
+        match metric {
+            "AV" => modified(self.mav, self.av),
+            "AC" => modified(self.mac, self.ac),
+            "AT" => modified(self.mat, self.at),
+            "PR" => modified(self.mpr, self.pr),
+            "UI" => modified(self.mui, self.ui),
+            "VC" => modified(self.mvc, self.vc),
+            "VI" => modified(self.mvi, self.vi),
+            "VA" => modified(self.mva, self.va),
+            "SC" => modified(self.msc, self.sc),
+            "SI" => modified(self.msi, self.si),
+            "SA" => modified(self.msa, self.sa),
+            "E" => defined(self.e).unwrap_or("A"),
+            "CR" => defined(self.cr).unwrap_or("H"),
+            "IR" => defined(self.ir).unwrap_or("H"),
+            "AR" => defined(self.ar).unwrap_or("H"),
+            other => unreachable!("not a scored CVSS v4.0 metric: {}", other),
+        }
+    }
+}
+
+/// Get the value of an optional metric unless it is Not Defined (X)
+fn defined<M: Metric>(metric: Option<M>) -> Option<&'static str> {
+    metric.map(M::as_str).filter(|&value| value != "X")
+}
+
+/// Get the value of a Modified Base metric if defined, else the Base metric
+fn modified<M: Metric, B: Metric>(modified: Option<M>, base: B) -> &'static str {
+    defined(modified).unwrap_or_else(|| base.as_str())
+}
+
+macro_rules! write_metrics {
+    ($f:expr, $($metric:expr),+) => {
+        $(
+            if let Some(metric) = $metric {
+                write!($f, "/{}", metric)?;
+            }
+        )+
+    };
+}
+
+impl fmt::Display for Vector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:4.0/{}/{}/{}/{}/{}/{}/{}/{}/{}/{}/{}",
+            PREFIX,
+            self.av,
+            self.ac,
+            self.at,
+            self.pr,
+            self.ui,
+            self.vc,
+            self.vi,
+            self.va,
+            self.sc,
+            self.si,
+            self.sa
+        )?;
+        write_metrics!(
+            f, self.e, self.cr, self.ir, self.ar, self.mav, self.mac, self.mat, self.mpr, self.mui,
+            self.mvc, self.mvi, self.mva, self.msc, self.msi, self.msa, self.s, self.au, self.r,
+            self.v, self.re, self.u
+        );
+        Ok(())
+    }
+}
+
+/// Set a metric parsed from a vector string, rejecting duplicates
+macro_rules! set_metric {
+    ($field:ident, $id:expr, $value:expr) => {{
+        if $field.is_some() {
+            fail!(ErrorKind::Parse, "duplicate metric in CVSS vector: {}", $id);
+        }
+
+        $field = Some($value.parse()?);
+    }};
+}
+
+/// Get a mandatory Base metric parsed from a vector string
+macro_rules! base_metric {
+    ($field:ident, $id:expr) => {
+        $field.ok_or_else(|| format_err!(ErrorKind::Parse, "missing base metric: {}", $id))?
+    };
+}
+
+impl FromStr for Vector {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let mut components = s.split('/').map(|component| {
+            let mut parts = component.splitn(2, ':');
+
+            let id = parts.next().filter(|id| !id.is_empty()).ok_or_else(|| {
+                format_err!(ErrorKind::Parse, "empty component in CVSS vector: {}", s)
+            })?;
+
+            let value = parts.next().ok_or_else(|| {
+                format_err!(
+                    ErrorKind::Parse,
+                    "empty value for CVSS vector component: {}",
+                    id
+                )
+            })?;
+
+            if value.contains(':') {
+                fail!(
+                    ErrorKind::Parse,
+                    "malformed CVSS vector component: {}",
+                    component
+                );
+            }
+
+            Ok((id, value))
+        });
+
+        let prefix = components
+            .next()
+            .ok_or_else(|| format_err!(ErrorKind::Parse, "empty CVSS string"))?;
+
+        let (id, version_string) = prefix?;
+
+        if id != PREFIX {
+            fail!(ErrorKind::Parse, "invalid CVSS prefix: {}", id);
+        }
+
+        if version_string != "4.0" {
+            fail!(
+                ErrorKind::Version,
+                "wrong CVSS version (expected '4.0'): '{}'",
+                version_string
+            );
+        }
+
+        let (mut av, mut ac, mut at, mut pr, mut ui) = (None, None, None, None, None);
+        let (mut vc, mut vi, mut va, mut sc, mut si, mut sa) = (None, None, None, None, None, None);
+        let mut e = None;
+        let (mut cr, mut ir, mut ar) = (None, None, None);
+        let (mut mav, mut mac, mut mat, mut mpr, mut mui) = (None, None, None, None, None);
+        let (mut mvc, mut mvi, mut mva) = (None, None, None);
+        let (mut msc, mut msi, mut msa) = (None, None, None);
+        let (mut safety, mut au, mut r, mut v, mut re, mut u) =
+            (None, None, None, None, None, None);
+
+        for component in components {
+            let (id, value) = component?;
+
+            match id {
+                "AV" => set_metric!(av, id, value),
+                "AC" => set_metric!(ac, id, value),
+                "AT" => set_metric!(at, id, value),
+                "PR" => set_metric!(pr, id, value),
+                "UI" => set_metric!(ui, id, value),
+                "VC" => set_metric!(vc, id, value),
+                "VI" => set_metric!(vi, id, value),
+                "VA" => set_metric!(va, id, value),
+                "SC" => set_metric!(sc, id, value),
+                "SI" => set_metric!(si, id, value),
+                "SA" => set_metric!(sa, id, value),
+                "E" => set_metric!(e, id, value),
+                "CR" => set_metric!(cr, id, value),
+                "IR" => set_metric!(ir, id, value),
+                "AR" => set_metric!(ar, id, value),
+                "MAV" => set_metric!(mav, id, value),
+                "MAC" => set_metric!(mac, id, value),
+                "MAT" => set_metric!(mat, id, value),
+                "MPR" => set_metric!(mpr, id, value),
+                "MUI" => set_metric!(mui, id, value),
+                "MVC" => set_metric!(mvc, id, value),
+                "MVI" => set_metric!(mvi, id, value),
+                "MVA" => set_metric!(mva, id, value),
+                "MSC" => set_metric!(msc, id, value),
+                "MSI" => set_metric!(msi, id, value),
+                "MSA" => set_metric!(msa, id, value),
+                "S" => set_metric!(safety, id, value),
+                "AU" => set_metric!(au, id, value),
+                "R" => set_metric!(r, id, value),
+                "V" => set_metric!(v, id, value),
+                "RE" => set_metric!(re, id, value),
+                "U" => set_metric!(u, id, value),
+                other => fail!(ErrorKind::Parse, "unknown metric type: '{}'", other),
+            }
+        }
+
+        Ok(Self {
+            av: base_metric!(av, "AV"),
+            ac: base_metric!(ac, "AC"),
+            at: base_metric!(at, "AT"),
+            pr: base_metric!(pr, "PR"),
+            ui: base_metric!(ui, "UI"),
+            vc: base_metric!(vc, "VC"),
+            vi: base_metric!(vi, "VI"),
+            va: base_metric!(va, "VA"),
+            sc: base_metric!(sc, "SC"),
+            si: base_metric!(si, "SI"),
+            sa: base_metric!(sa, "SA"),
+            e,
+            cr,
+            ir,
+            ar,
+            mav,
+            mac,
+            mat,
+            mpr,
+            mui,
+            mvc,
+            mvi,
+            mva,
+            msc,
+            msi,
+            msa,
+            s: safety,
+            au,
+            r,
+            v,
+            re,
+            u,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Vector {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use de::Error;
+        let string = String::deserialize(deserializer)?;
+        string.parse().map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Vector {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_string().serialize(serializer)
+    }
+}
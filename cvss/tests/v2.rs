@@ -0,0 +1,69 @@
+//! CVSS v2.0 tests
+
+#![cfg(feature = "v2")]
+
+use cvss::{
+    v2::{base::AccessComplexity, Vector},
+    Severity,
+};
+use std::str::FromStr;
+
+/// Base metrics only
+#[test]
+fn base_only() {
+    let vector_string = "AV:N/AC:L/Au:N/C:P/I:P/A:P";
+    let vector = Vector::from_str(vector_string).unwrap();
+    assert_eq!(&vector.to_string(), vector_string);
+    assert_eq!(vector.score().value(), 7.5);
+    assert_eq!(vector.severity(), Severity::High);
+
+    let vector = Vector::from_str("AV:N/AC:M/Au:N/C:N/I:P/A:N").unwrap();
+    assert_eq!(vector.base.ac, AccessComplexity::Medium);
+    assert_eq!(vector.score().value(), 4.3);
+    assert_eq!(vector.severity(), Severity::Medium);
+}
+
+/// CVE-2002-0392 example from the CVSS v2.0 Guide: Section 3.3.1
+#[test]
+fn cve_2002_0392() {
+    let vector_string = "AV:N/AC:L/Au:N/C:N/I:N/A:C/E:F/RL:OF/RC:C/CDP:H/TD:H/CR:M/IR:M/AR:H";
+    let vector = Vector::from_str(vector_string).unwrap();
+    assert_eq!(&vector.to_string(), vector_string);
+    assert_eq!(vector.base_score().value(), 7.8);
+    assert_eq!(vector.temporal_score().value(), 6.4);
+    assert_eq!(vector.environmental_score().value(), 9.2);
+    assert_eq!(vector.score().value(), 9.2);
+}
+
+/// CVE-2003-0818 example from the CVSS v2.0 Guide: Section 3.3.2
+#[test]
+fn cve_2003_0818() {
+    let vector =
+        Vector::from_str("AV:N/AC:L/Au:N/C:C/I:C/A:C/E:F/RL:OF/RC:C/CDP:H/TD:H/CR:M/IR:M/AR:L")
+            .unwrap();
+    assert_eq!(vector.base_score().value(), 10.0);
+    assert_eq!(vector.temporal_score().value(), 8.3);
+    assert_eq!(vector.environmental_score().value(), 9.0);
+}
+
+/// Not Defined metrics are omitted
+#[test]
+fn not_defined() {
+    let vector = Vector::from_str("(AV:N/AC:L/Au:N/C:P/I:P/A:P/E:ND/RL:ND/CDP:ND)").unwrap();
+    assert!(vector.temporal.is_empty());
+    assert!(vector.environmental.is_empty());
+    assert_eq!(vector.to_string(), "AV:N/AC:L/Au:N/C:P/I:P/A:P");
+}
+
+/// Invalid vectors
+#[test]
+fn invalid() {
+    // Missing base metric
+    assert!(Vector::from_str("AV:N/AC:L/Au:N/C:P/I:P").is_err());
+    // Duplicate metric
+    assert!(Vector::from_str("AV:N/AC:L/Au:N/C:P/I:P/A:P/A:N").is_err());
+    // Invalid value
+    assert!(Vector::from_str("AV:N/AC:L/Au:N/C:P/I:P/A:H").is_err());
+    // CVSS v3 vector
+    assert!(Vector::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N").is_err());
+}
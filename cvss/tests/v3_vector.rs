@@ -0,0 +1,86 @@
+//! CVSS v3.1 Temporal and Environmental Metrics tests
+
+#![cfg(feature = "v3")]
+
+use cvss::v3::{temporal::ExploitCodeMaturity, Vector};
+use std::str::FromStr;
+
+/// Base metrics only
+#[test]
+fn base_only() {
+    let vector_string = "CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N";
+    let vector = Vector::from_str(vector_string).unwrap();
+    assert_eq!(&vector.to_string(), vector_string);
+    assert!(vector.temporal.is_empty());
+    assert!(vector.environmental.is_empty());
+    assert_eq!(vector.score().value(), 6.1);
+}
+
+/// Temporal metrics
+#[test]
+fn temporal() {
+    let vector_string = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P/RL:O/RC:C";
+    let vector = Vector::from_str(vector_string).unwrap();
+    assert_eq!(&vector.to_string(), vector_string);
+    assert_eq!(vector.temporal.e, Some(ExploitCodeMaturity::ProofOfConcept));
+    assert_eq!(vector.base_score().value(), 9.8);
+    assert_eq!(vector.temporal_score().value(), 8.8);
+    assert_eq!(vector.score().value(), 8.8);
+}
+
+/// Environmental metrics
+#[test]
+fn environmental() {
+    let vector_string =
+        "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P/RL:O/RC:C/CR:H/IR:H/AR:L/MAV:L";
+    let vector = Vector::from_str(vector_string).unwrap();
+    assert_eq!(&vector.to_string(), vector_string);
+    assert_eq!(vector.environmental_score().value(), 7.6);
+    assert_eq!(vector.score().value(), 7.6);
+}
+
+/// Modified Scope
+#[test]
+fn modified_scope() {
+    let vector =
+        Vector::from_str("CVSS:3.1/AV:N/AC:L/PR:L/UI:N/S:U/C:L/I:L/A:N/CR:H/IR:H/MS:C").unwrap();
+    assert_eq!(vector.base_score().value(), 5.4);
+    assert_eq!(vector.environmental_score().value(), 7.6);
+}
+
+/// No modified impact
+#[test]
+fn no_modified_impact() {
+    let vector =
+        Vector::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/MC:N/MI:N/MA:N").unwrap();
+    assert_eq!(vector.environmental_score().value(), 0.0);
+}
+
+/// Not Defined metrics are omitted
+#[test]
+fn not_defined() {
+    let vector =
+        Vector::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N/E:X/RL:X/MAV:X").unwrap();
+    assert!(vector.temporal.is_empty());
+    assert!(vector.environmental.is_empty());
+    assert_eq!(
+        vector.to_string(),
+        "CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N"
+    );
+}
+
+/// Invalid metrics
+#[test]
+fn invalid_metrics() {
+    assert!(Vector::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N/E:Z").is_err());
+    assert!(Vector::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N/XX:H").is_err());
+    assert!(Vector::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N/CR").is_err());
+}
+
+/// Repeated metrics
+#[test]
+fn duplicate_metrics() {
+    assert!(Vector::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N/E:P/E:H").is_err());
+    assert!(Vector::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N/MAV:X/MAV:L").is_err());
+    assert!(Vector::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N/AV:L").is_err());
+}
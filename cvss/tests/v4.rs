@@ -0,0 +1,145 @@
+//! CVSS v4.0 tests
+
+#![cfg(feature = "v4")]
+
+use cvss::{v4::Vector, Cvss, Severity};
+use std::str::FromStr;
+
+/// Assert the given vector string round-trips and has the expected score
+fn assert_score(vector_string: &str, expected_score: f64) {
+    let vector = Vector::from_str(vector_string).unwrap();
+    assert_eq!(&vector.to_string(), vector_string);
+    assert_eq!(vector.score().value(), expected_score, "{}", vector_string);
+}
+
+/// Highest possible score
+#[test]
+fn all_high() {
+    assert_score(
+        "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:H/SI:H/SA:H",
+        10.0,
+    );
+}
+
+/// No impact on the vulnerable or subsequent systems
+#[test]
+fn no_impact() {
+    assert_score(
+        "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:N/VI:N/VA:N/SC:N/SI:N/SA:N",
+        0.0,
+    );
+}
+
+/// Critical remote code execution
+#[test]
+fn critical() {
+    let vector_string = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N";
+    let vector = Vector::from_str(vector_string).unwrap();
+    assert_eq!(vector.macro_vector().to_string(), "000200");
+    assert_eq!(vector.score().value(), 9.3);
+    assert_eq!(vector.severity(), Severity::Critical);
+}
+
+/// Scores interpolated within a MacroVector
+#[test]
+fn interpolated() {
+    assert_score(
+        "CVSS:4.0/AV:N/AC:L/AT:P/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+        9.2,
+    );
+    assert_score(
+        "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:N/VI:N/VA:H/SC:N/SI:N/SA:N",
+        8.7,
+    );
+    assert_score(
+        "CVSS:4.0/AV:L/AC:L/AT:N/PR:L/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+        8.5,
+    );
+    assert_score(
+        "CVSS:4.0/AV:N/AC:L/AT:N/PR:L/UI:N/VC:H/VI:N/VA:N/SC:N/SI:N/SA:N",
+        7.1,
+    );
+    assert_score(
+        "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:L/VI:N/VA:N/SC:N/SI:N/SA:N",
+        6.9,
+    );
+    assert_score(
+        "CVSS:4.0/AV:L/AC:L/AT:N/PR:L/UI:N/VC:N/VI:N/VA:H/SC:N/SI:N/SA:N",
+        6.8,
+    );
+    assert_score(
+        "CVSS:4.0/AV:N/AC:L/AT:N/PR:L/UI:N/VC:L/VI:N/VA:N/SC:N/SI:N/SA:N",
+        5.3,
+    );
+    assert_score(
+        "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:A/VC:N/VI:N/VA:N/SC:L/SI:L/SA:N",
+        5.1,
+    );
+}
+
+/// Threat, Environmental and Supplemental metrics
+#[test]
+fn optional_metrics() {
+    assert_score(
+        "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:H/SI:H/SA:H/MSI:S/MSA:S",
+        10.0,
+    );
+
+    let vector_string = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/E:X/CR:X/MAV:X/S:N/AU:Y/U:Amber";
+    let vector = Vector::from_str(vector_string).unwrap();
+    assert_eq!(&vector.to_string(), vector_string);
+    assert_eq!(vector.score().value(), 9.3);
+}
+
+/// Metrics are serialized in the order required by the specification
+#[test]
+fn canonical_order() {
+    let vector = Vector::from_str(
+        "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/U:Red/E:A",
+    )
+    .unwrap();
+
+    assert_eq!(
+        vector.to_string(),
+        "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/E:A/U:Red"
+    );
+}
+
+/// Missing mandatory base metric
+#[test]
+fn missing_base_metric() {
+    assert!(
+        Vector::from_str("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N").is_err()
+    );
+}
+
+/// Duplicate metric
+#[test]
+fn duplicate_metric() {
+    assert!(Vector::from_str(
+        "CVSS:4.0/AV:N/AV:L/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N"
+    )
+    .is_err());
+}
+
+/// Wrong CVSS version prefix
+#[test]
+fn bad_version() {
+    assert!(Vector::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N").is_err());
+}
+
+/// Version-agnostic parsing
+#[test]
+fn any_version() {
+    let v3 = Cvss::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N").unwrap();
+    assert!(matches!(v3, Cvss::V3(_)));
+    assert_eq!(v3.score(), 6.1);
+
+    let v4_string = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N";
+    let v4 = Cvss::from_str(v4_string).unwrap();
+    assert!(matches!(v4, Cvss::V4(_)));
+    assert_eq!(v4.to_string(), v4_string);
+    assert_eq!(v4.severity(), Severity::Critical);
+
+    assert!(Cvss::from_str("CVSS:2.0/AV:N/AC:L/Au:N/C:P/I:P/A:P").is_err());
+}
@@ -71,7 +71,7 @@ impl Advisory {
         &self.metadata.date
     }
 
-    /// Get the severity of this advisory if it has a CVSS v3 associated
+    /// Get the severity of this advisory if it has a CVSS vector associated
     pub fn severity(&self) -> Option<Severity> {
         self.metadata.cvss.as_ref().map(|cvss| cvss.severity())
     }
@@ -5,7 +5,10 @@ use crate::{
     error::{Error, ErrorKind},
     Map,
 };
-use platforms::target::{Arch, OS};
+use platforms::{
+    target::{Arch, OS},
+    Platform,
+};
 use semver::VersionReq;
 use serde::{de::Error as DeError, Deserialize, Deserializer, Serialize, Serializer};
 use std::{
@@ -36,6 +39,14 @@ pub struct Affected {
     pub functions: Map<FunctionPath, Vec<VersionReq>>,
 }
 
+impl Affected {
+    /// Does this vulnerability affect the given platform?
+    pub fn affects_platform(&self, platform: &Platform) -> bool {
+        (self.arch.is_empty() || self.arch.contains(&platform.target_arch))
+            && (self.os.is_empty() || self.os.contains(&platform.target_os))
+    }
+}
+
 /// Canonical Rust Paths (sans parameters) to vulnerable types and/or functions
 /// affected by a particular advisory.
 /// <https://doc.rust-lang.org/reference/paths.html#canonical-paths>
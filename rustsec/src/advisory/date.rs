@@ -5,6 +5,7 @@ use serde::{de, Deserialize, Serialize};
 use std::{
     fmt::{self, Display},
     str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
 };
 
 /// Minimum allowed year on advisory dates
@@ -33,6 +34,33 @@ impl Date {
         self.component(2).expect("has day")
     }
 
+    /// Get today's date (in UTC)
+    pub fn today() -> Self {
+        let days = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs() / 86_400)
+            .unwrap_or_default();
+
+        Self::from_days_since_epoch(days)
+    }
+
+    /// Get the date the given number of days after the UNIX epoch
+    fn from_days_since_epoch(days: u64) -> Self {
+        // Civil from days algorithm: <http://howardhinnant.github.io/date_algorithms.html>
+        let z = days + 719_468;
+        let era = z / 146_097;
+        let day_of_era = z - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        Date(format!("{:04}-{:02}-{:02}", year, month, day))
+    }
+
     /// Borrow this date as a string reference
     pub fn as_str(&self) -> &str {
         self.0.as_ref()
@@ -135,6 +163,13 @@ mod tests {
         assert!(Date::from_str("2017-01-01-01").is_err());
     }
 
+    #[test]
+    fn from_days_since_epoch_test() {
+        assert_eq!(Date::from_days_since_epoch(0).as_str(), "1970-01-01");
+        assert_eq!(Date::from_days_since_epoch(11_016).as_str(), "2000-02-29");
+        assert_eq!(Date::from_days_since_epoch(19_000).as_str(), "2022-01-08");
+    }
+
     #[test]
     fn date_components_test() {
         let date = Date::from_str("2000-01-02").unwrap();
@@ -171,6 +171,23 @@ impl Linter {
                             }
                         }
                     }
+                    "source" => {
+                        if let Some(source) = &self.advisory.metadata.source {
+                            if !source.is_remote_registry() {
+                                self.errors.push(Error {
+                                    kind: ErrorKind::value("source", value.to_string()),
+                                    section: Some("advisory"),
+                                    message: Some("source must be a registry"),
+                                });
+                            } else if source.is_default_registry() {
+                                self.errors.push(Error {
+                                    kind: ErrorKind::value("source", value.to_string()),
+                                    section: Some("advisory"),
+                                    message: Some("source shouldn't be explicit for crates.io"),
+                                });
+                            }
+                        }
+                    }
                     "date" => {
                         let y1 = self.advisory.metadata.date.year();
 
@@ -16,6 +16,13 @@ pub struct Metadata {
     /// Name of affected crate
     pub package: package::Name,
 
+    /// Registry the affected crate is published to (e.g.
+    /// `registry+https://my-registry.example.com/index`).
+    ///
+    /// When absent, the advisory applies to crates published to crates.io.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<package::SourceId>,
+
     /// One-liner description of a vulnerability
     #[serde(default)]
     pub title: String,
@@ -50,14 +57,16 @@ pub struct Metadata {
     #[serde(default)]
     pub keywords: Vec<Keyword>,
 
-    /// CVSS v3.1 Base Metrics vector string containing severity information.
+    /// CVSS v3.1 Base Metrics or CVSS v4.0 vector string containing
+    /// severity information.
     ///
-    /// Example:
+    /// Examples:
     ///
     /// ```text
     /// CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N
+    /// CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N
     /// ```
-    pub cvss: Option<cvss::v3::Base>,
+    pub cvss: Option<cvss::Cvss>,
 
     /// Informational advisories can be used to warn users about issues
     /// affecting a particular crate without failing the build.
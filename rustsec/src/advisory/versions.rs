@@ -50,6 +50,46 @@ impl Versions {
     pub fn unaffected(&self) -> &[VersionReq] {
         self.unaffected.as_slice()
     }
+
+    /// Select the minimal non-vulnerable upgrade for the `current` version
+    /// from the `available` versions (e.g. the releases in the registry).
+    ///
+    /// Versions which are semver-compatible with `current` are preferred.
+    /// If there are none, the minimal non-vulnerable version in a newer
+    /// semver-incompatible release line is returned instead. Pre-releases are
+    /// never selected.
+    pub fn minimal_upgrade<'a, I>(&self, current: &Version, available: I) -> Option<&'a Version>
+    where
+        I: IntoIterator<Item = &'a Version>,
+    {
+        let compatible_req = VersionReq::parse(&format!("^{}", current)).ok();
+
+        let candidates = available
+            .into_iter()
+            .filter(|version| *version > current && version.pre.is_empty())
+            .filter(|version| !self.is_vulnerable(version));
+
+        let mut best_compatible: Option<&'a Version> = None;
+        let mut best_incompatible: Option<&'a Version> = None;
+
+        for version in candidates {
+            let best = if compatible_req
+                .as_ref()
+                .map(|req| req.matches(version))
+                .unwrap_or(false)
+            {
+                &mut best_compatible
+            } else {
+                &mut best_incompatible
+            };
+
+            if best.map(|best| version < best).unwrap_or(true) {
+                *best = Some(version);
+            }
+        }
+
+        best_compatible.or(best_incompatible)
+    }
 }
 
 impl TryFrom<RawVersions> for Versions {
@@ -77,3 +117,62 @@ fn validate_ranges(versions: &RawVersions) -> Result<(), Error> {
     let _ = osv::ranges_for_unvalidated_advisory(versions)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Versions;
+    use semver::{Version, VersionReq};
+
+    fn versions(patched: &[&str]) -> Versions {
+        Versions::new(
+            patched
+                .iter()
+                .map(|req| VersionReq::parse(req).unwrap())
+                .collect(),
+            vec![],
+        )
+        .unwrap()
+    }
+
+    fn releases(versions: &[&str]) -> Vec<Version> {
+        versions
+            .iter()
+            .map(|v| Version::parse(v).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn minimal_upgrade_prefers_semver_compatible() {
+        let versions = versions(&[">= 2.0.1", "^1.4.2"]);
+        let available = releases(&[
+            "1.4.0", "1.4.1", "1.4.3", "1.4.2", "2.0.0", "2.0.1", "2.1.0",
+        ]);
+        let current = Version::parse("1.4.0").unwrap();
+
+        assert_eq!(
+            versions.minimal_upgrade(&current, &available),
+            Some(&Version::parse("1.4.2").unwrap())
+        );
+    }
+
+    #[test]
+    fn minimal_upgrade_crosses_major_version() {
+        let versions = versions(&[">= 2.0.1"]);
+        let available = releases(&["1.4.0", "1.5.0", "3.0.0", "2.0.1", "2.0.2-alpha"]);
+        let current = Version::parse("1.4.0").unwrap();
+
+        assert_eq!(
+            versions.minimal_upgrade(&current, &available),
+            Some(&Version::parse("2.0.1").unwrap())
+        );
+    }
+
+    #[test]
+    fn minimal_upgrade_none_available() {
+        let versions = versions(&[">= 2.0.1"]);
+        let available = releases(&["1.4.0", "2.0.0", "2.0.2-alpha"]);
+        let current = Version::parse("1.4.0").unwrap();
+
+        assert_eq!(versions.minimal_upgrade(&current, &available), None);
+    }
+}
@@ -0,0 +1,241 @@
+//! Dependency kinds and features from `cargo metadata` output.
+//!
+//! `Cargo.lock` doesn't record how packages are depended upon. The JSON
+//! output of `cargo metadata --format-version 1` does, which makes it possible
+//! to tell packages which are only used as dev-dependencies (e.g. by tests) or
+//! build-dependencies (e.g. by build scripts) apart from those which end up in
+//! the final build artifacts.
+//!
+//! This module only parses that output: running `cargo` is left to the caller.
+
+use crate::{
+    error::{Error, ErrorKind},
+    fs,
+    package::{self, Package},
+    Map,
+};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeSet as Set, path::Path, str::FromStr};
+
+/// Information about how the packages in a `Cargo.lock` are depended upon,
+/// parsed from the output of `cargo metadata --format-version 1`
+#[derive(Clone, Debug, Default)]
+pub struct CargoMetadata {
+    /// Packages, keyed by name and version
+    packages: Map<(package::Name, Version), PackageInfo>,
+}
+
+impl CargoMetadata {
+    /// Load `cargo metadata` output from the file at the given path
+    pub fn load_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+
+        let json = fs::read_to_string(path)
+            .map_err(|e| format_err!(ErrorKind::Io, "couldn't open {}: {}", path.display(), e))?;
+
+        json.parse().map_err(|e: Error| {
+            Error::new(
+                e.kind(),
+                &format!("error parsing {}: {}", path.display(), e),
+            )
+        })
+    }
+
+    /// Get information about the given package (if it's in the metadata)
+    pub fn package(&self, package: &Package) -> Option<&PackageInfo> {
+        self.packages
+            .get(&(package.name.clone(), package.version.clone()))
+    }
+
+    /// Iterate over the packages in the metadata
+    pub fn iter(&self) -> impl Iterator<Item = (&package::Name, &Version, &PackageInfo)> {
+        self.packages
+            .iter()
+            .map(|((name, version), info)| (name, version, info))
+    }
+}
+
+impl FromStr for CargoMetadata {
+    type Err = Error;
+
+    fn from_str(json: &str) -> Result<Self, Error> {
+        let metadata: MetadataJson = serde_json::from_str(json)
+            .map_err(|e| format_err!(ErrorKind::Parse, "invalid cargo metadata: {}", e))?;
+
+        let resolve = metadata.resolve.ok_or_else(|| {
+            format_err!(
+                ErrorKind::Parse,
+                "cargo metadata is missing the dependency graph (was it run with `--no-deps`?)"
+            )
+        })?;
+
+        let nodes: Map<&str, &NodeJson> = resolve
+            .nodes
+            .iter()
+            .map(|node| (node.id.as_str(), node))
+            .collect();
+
+        // Walk the dependency graph from the workspace members, tracking the
+        // kind of dependency each package is reached through
+        let mut kinds: Map<&str, Set<DependencyKind>> = Map::new();
+        let mut pending: Vec<(&str, DependencyKind)> = metadata
+            .workspace_members
+            .iter()
+            .map(|id| (id.as_str(), DependencyKind::Normal))
+            .collect();
+
+        while let Some((id, kind)) = pending.pop() {
+            if !kinds.entry(id).or_default().insert(kind) {
+                continue;
+            }
+
+            let node = match nodes.get(id) {
+                Some(node) => node,
+                None => continue,
+            };
+
+            for dep in &node.deps {
+                if dep.dep_kinds.is_empty() {
+                    // Older versions of Cargo don't report dependency kinds
+                    pending.push((&dep.pkg, kind));
+                }
+
+                for dep_kind in &dep.dep_kinds {
+                    pending.push((&dep.pkg, kind.then(dep_kind.kind)));
+                }
+            }
+        }
+
+        let mut packages: Map<(package::Name, Version), PackageInfo> = Map::new();
+
+        for package in &metadata.packages {
+            let info = packages
+                .entry((package.name.clone(), package.version.clone()))
+                .or_default();
+
+            if let Some(package_kinds) = kinds.get(package.id.as_str()) {
+                info.kinds.extend(package_kinds);
+            }
+
+            if let Some(node) = nodes.get(package.id.as_str()) {
+                info.features.extend(node.features.iter().cloned());
+            }
+        }
+
+        Ok(Self { packages })
+    }
+}
+
+/// How a package is depended upon, and which of its features are enabled
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PackageInfo {
+    /// Kinds of dependencies through which this package is used.
+    ///
+    /// Empty if the package isn't reachable from any workspace member.
+    pub kinds: Set<DependencyKind>,
+
+    /// Enabled features
+    pub features: Set<String>,
+}
+
+impl PackageInfo {
+    /// Is this package only used as a dev-dependency?
+    pub fn is_dev_only(&self) -> bool {
+        self.is_only(&[DependencyKind::Dev])
+    }
+
+    /// Is this package only used as a build-dependency?
+    pub fn is_build_only(&self) -> bool {
+        self.is_only(&[DependencyKind::Build])
+    }
+
+    /// Is this package used exclusively through the given kinds of dependencies?
+    pub fn is_only(&self, kinds: &[DependencyKind]) -> bool {
+        !self.kinds.is_empty() && self.kinds.iter().all(|kind| kinds.contains(kind))
+    }
+}
+
+/// Kinds of dependencies
+#[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum DependencyKind {
+    /// Normal dependency (i.e. part of the final build artifacts)
+    #[serde(rename = "normal")]
+    Normal,
+
+    /// Dev-dependency (i.e. only used by tests, examples and benchmarks),
+    /// including transitive dependencies of dev-dependencies
+    #[serde(rename = "dev")]
+    Dev,
+
+    /// Build-dependency (i.e. only used by build scripts and procedural
+    /// macros at compile time), including their transitive dependencies
+    #[serde(rename = "build")]
+    Build,
+}
+
+impl DependencyKind {
+    /// Get the kind of a dependency of a package used as this kind of
+    /// dependency, through a dependency edge of the given kind
+    fn then(self, edge: DependencyKind) -> DependencyKind {
+        match (self, edge) {
+            (DependencyKind::Dev, _) | (_, DependencyKind::Dev) => DependencyKind::Dev,
+            (DependencyKind::Build, _) | (_, DependencyKind::Build) => DependencyKind::Build,
+            _ => DependencyKind::Normal,
+        }
+    }
+}
+
+/// `cargo metadata` output
+#[derive(Debug, Deserialize)]
+struct MetadataJson {
+    packages: Vec<PackageJson>,
+    workspace_members: Vec<String>,
+    resolve: Option<ResolveJson>,
+}
+
+/// Package in `cargo metadata` output
+#[derive(Debug, Deserialize)]
+struct PackageJson {
+    id: String,
+    name: package::Name,
+    version: Version,
+}
+
+/// Resolved dependency graph in `cargo metadata` output
+#[derive(Debug, Deserialize)]
+struct ResolveJson {
+    nodes: Vec<NodeJson>,
+}
+
+/// Node in the resolved dependency graph
+#[derive(Debug, Deserialize)]
+struct NodeJson {
+    id: String,
+    #[serde(default)]
+    deps: Vec<NodeDepJson>,
+    #[serde(default)]
+    features: Vec<String>,
+}
+
+/// Dependency of a node in the resolved dependency graph
+#[derive(Debug, Deserialize)]
+struct NodeDepJson {
+    pkg: String,
+    #[serde(default)]
+    dep_kinds: Vec<DepKindJson>,
+}
+
+/// Kind of a dependency edge
+#[derive(Debug, Deserialize)]
+struct DepKindJson {
+    #[serde(deserialize_with = "deserialize_dep_kind")]
+    kind: DependencyKind,
+}
+
+/// Deserialize a dependency kind, where `null` means a normal dependency
+fn deserialize_dep_kind<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<DependencyKind, D::Error> {
+    Ok(Option::<DependencyKind>::deserialize(deserializer)?.unwrap_or(DependencyKind::Normal))
+}
@@ -0,0 +1,490 @@
+//! Support for generating [CycloneDX] v1.4 Software Bill of Materials (SBOM)
+//! documents for a `Cargo.lock` file, annotated with the vulnerabilities
+//! found in an audit [`Report`] (a.k.a. Vulnerability Exploitability
+//! eXchange, or VEX).
+//!
+//! The [`Bom`] type implements [`Serialize`] and maps directly to the
+//! CycloneDX JSON format.
+//!
+//! [CycloneDX]: https://cyclonedx.org/docs/1.4/json/
+
+use crate::{
+    advisory::{self, id},
+    collection::Collection,
+    lockfile::Lockfile,
+    package::{Checksum, Package, SourceId},
+    report::Report,
+    vulnerability::Vulnerability,
+    Map,
+};
+use serde::Serialize;
+use std::fmt::Write as _;
+
+/// CycloneDX specification version generated
+pub const SPEC_VERSION: &str = "1.4";
+
+/// CycloneDX Bill of Materials (BOM)
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bom {
+    bom_format: &'static str,
+    spec_version: &'static str,
+    version: u32,
+    metadata: Metadata,
+    components: Vec<Component>,
+    dependencies: Vec<DependencyGraphEntry>,
+    vulnerabilities: Vec<BomVulnerability>,
+}
+
+impl Bom {
+    /// Generate a BOM describing the packages in the given lockfile, along
+    /// with the vulnerabilities found in the given report.
+    pub fn new(lockfile: &Lockfile, report: &Report) -> Self {
+        let mut components: Vec<Component> =
+            lockfile.packages.iter().map(Component::from).collect();
+
+        let dependencies = lockfile
+            .packages
+            .iter()
+            .map(|package| DependencyGraphEntry {
+                bom_ref: purl(package),
+                depends_on: package
+                    .dependencies
+                    .iter()
+                    .filter_map(|dep| {
+                        let candidates: Vec<_> = lockfile
+                            .packages
+                            .iter()
+                            .filter(|pkg| dep.matches(pkg))
+                            .collect();
+
+                        // Dependencies on registry packages omit the source
+                        // unless they're ambiguous with e.g. a path
+                        // dependency of the same name and version
+                        candidates
+                            .iter()
+                            .find(|pkg| pkg.source == dep.source)
+                            .or_else(|| candidates.first())
+                            .copied()
+                    })
+                    .map(purl)
+                    .collect(),
+            })
+            .collect();
+
+        let mut vulnerabilities: Vec<BomVulnerability> = vec![];
+        let mut indexes: Map<advisory::Id, usize> = Map::new();
+
+        for vulnerability in &report.vulnerabilities.list {
+            let affects = Affects::from(vulnerability);
+
+            // Toolchain packages (e.g. `std`) aren't in the lockfile
+            if !components
+                .iter()
+                .any(|component| component.bom_ref == affects.bom_ref)
+            {
+                components.push(Component::toolchain(&vulnerability.package));
+            }
+
+            match indexes.get(&vulnerability.advisory.id) {
+                Some(&index) => vulnerabilities[index].affects.push(affects),
+                None => {
+                    indexes.insert(vulnerability.advisory.id.clone(), vulnerabilities.len());
+
+                    let mut bom_vulnerability = BomVulnerability::from(vulnerability);
+                    bom_vulnerability.affects.push(affects);
+                    vulnerabilities.push(bom_vulnerability);
+                }
+            }
+        }
+
+        Bom {
+            bom_format: "CycloneDX",
+            spec_version: SPEC_VERSION,
+            version: 1,
+            metadata: Metadata {
+                tools: vec![Tool {
+                    vendor: "RustSec",
+                    name: "rustsec",
+                    version: crate::VERSION,
+                }],
+            },
+            components,
+            dependencies,
+            vulnerabilities,
+        }
+    }
+}
+
+/// BOM metadata
+#[derive(Clone, Debug, Serialize)]
+struct Metadata {
+    tools: Vec<Tool>,
+}
+
+/// Tool used to generate the BOM
+#[derive(Clone, Debug, Serialize)]
+struct Tool {
+    vendor: &'static str,
+    name: &'static str,
+    version: &'static str,
+}
+
+/// Component (i.e. package) described by the BOM
+#[derive(Clone, Debug, Serialize)]
+struct Component {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    #[serde(rename = "bom-ref")]
+    bom_ref: String,
+    name: String,
+    version: String,
+    purl: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    hashes: Vec<Hash>,
+}
+
+impl From<&Package> for Component {
+    fn from(package: &Package) -> Self {
+        let purl = purl(package);
+
+        Component {
+            kind: "library",
+            bom_ref: purl.clone(),
+            name: package.name.to_string(),
+            version: package.version.to_string(),
+            purl,
+            hashes: package.checksum.iter().map(Hash::from).collect(),
+        }
+    }
+}
+
+impl Component {
+    /// Component for a package which is part of the Rust toolchain
+    fn toolchain(package: &Package) -> Self {
+        let purl = toolchain_purl(package);
+
+        Component {
+            kind: "framework",
+            bom_ref: purl.clone(),
+            name: package.name.to_string(),
+            version: package.version.to_string(),
+            purl,
+            hashes: vec![],
+        }
+    }
+}
+
+/// Hash of a component
+#[derive(Clone, Debug, Serialize)]
+struct Hash {
+    alg: &'static str,
+    content: String,
+}
+
+impl From<&Checksum> for Hash {
+    fn from(checksum: &Checksum) -> Self {
+        match checksum {
+            Checksum::Sha256(_) => Hash {
+                alg: "SHA-256",
+                content: checksum.to_string(),
+            },
+        }
+    }
+}
+
+/// Direct dependencies of a component
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DependencyGraphEntry {
+    #[serde(rename = "ref")]
+    bom_ref: String,
+    depends_on: Vec<String>,
+}
+
+/// Vulnerability affecting components in the BOM
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BomVulnerability {
+    #[serde(rename = "bom-ref")]
+    bom_ref: String,
+    id: String,
+    source: Source,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    references: Vec<Reference>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    ratings: Vec<Rating>,
+    description: String,
+    detail: String,
+    recommendation: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    advisories: Vec<AdvisoryLink>,
+    published: String,
+    affects: Vec<Affects>,
+}
+
+impl From<&Vulnerability> for BomVulnerability {
+    fn from(vulnerability: &Vulnerability) -> Self {
+        let metadata = &vulnerability.advisory;
+        let source = Source::from(&metadata.id);
+
+        let ratings = metadata
+            .cvss
+            .iter()
+            .map(|cvss| Rating {
+                source: source.clone(),
+                score: cvss.score(),
+                severity: cvss.severity().as_str(),
+                method: rating_method(cvss),
+                vector: cvss.to_string(),
+            })
+            .collect();
+
+        let patched = vulnerability.versions.patched();
+
+        let recommendation = if patched.is_empty() {
+            "No safe upgrade is available!".to_owned()
+        } else {
+            format!(
+                "Upgrade to {}",
+                patched
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" OR ")
+            )
+        };
+
+        BomVulnerability {
+            bom_ref: metadata.id.to_string(),
+            id: metadata.id.to_string(),
+            source,
+            references: metadata
+                .aliases
+                .iter()
+                .map(|alias| Reference {
+                    id: alias.to_string(),
+                    source: Source::from(alias),
+                })
+                .collect(),
+            ratings,
+            description: metadata.title.clone(),
+            detail: metadata.description.clone(),
+            recommendation,
+            advisories: metadata
+                .url
+                .iter()
+                .chain(metadata.references.iter())
+                .map(|url| AdvisoryLink {
+                    url: url.to_string(),
+                })
+                .collect(),
+            published: format!("{}T00:00:00Z", metadata.date.as_str()),
+            affects: vec![],
+        }
+    }
+}
+
+/// Source of a vulnerability
+#[derive(Clone, Debug, Serialize)]
+struct Source {
+    name: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+}
+
+impl From<&advisory::Id> for Source {
+    fn from(id: &advisory::Id) -> Self {
+        let name = match id.kind() {
+            id::Kind::RustSec => "RustSec",
+            id::Kind::Cve => "NVD",
+            id::Kind::Ghsa => "GitHub",
+            id::Kind::Talos => "Talos",
+            _ => "Other",
+        };
+
+        Source {
+            name,
+            url: id.url(),
+        }
+    }
+}
+
+/// Reference to the same vulnerability in another source
+#[derive(Clone, Debug, Serialize)]
+struct Reference {
+    id: String,
+    source: Source,
+}
+
+/// Severity rating of a vulnerability
+#[derive(Clone, Debug, Serialize)]
+struct Rating {
+    source: Source,
+    score: f64,
+    severity: &'static str,
+    method: &'static str,
+    vector: String,
+}
+
+/// Link to an advisory about a vulnerability
+#[derive(Clone, Debug, Serialize)]
+struct AdvisoryLink {
+    url: String,
+}
+
+/// Component affected by a vulnerability
+#[derive(Clone, Debug, Serialize)]
+struct Affects {
+    #[serde(rename = "ref")]
+    bom_ref: String,
+    versions: Vec<AffectedVersion>,
+}
+
+impl From<&Vulnerability> for Affects {
+    fn from(vulnerability: &Vulnerability) -> Self {
+        let package = &vulnerability.package;
+
+        let bom_ref = if vulnerability.advisory.collection == Some(Collection::Rust) {
+            toolchain_purl(package)
+        } else {
+            purl(package)
+        };
+
+        Affects {
+            bom_ref,
+            versions: vec![AffectedVersion {
+                version: package.version.to_string(),
+                status: "affected",
+            }],
+        }
+    }
+}
+
+/// Version of a component affected by a vulnerability
+#[derive(Clone, Debug, Serialize)]
+struct AffectedVersion {
+    version: String,
+    status: &'static str,
+}
+
+/// Get the CycloneDX rating method for the given CVSS vector
+fn rating_method(cvss: &cvss::Cvss) -> &'static str {
+    match cvss {
+        cvss::Cvss::V3(vector) if vector.base.minor_version == 0 => "CVSSv3",
+        cvss::Cvss::V3(_) => "CVSSv31",
+        // CycloneDX v1.4 predates CVSS v4.0
+        _ => "other",
+    }
+}
+
+/// Get the [package URL] for the given package.
+///
+/// Packages which don't come from crates.io have their source recorded in
+/// the `vcs_url` (git) or `repository_url` (other registries) qualifiers,
+/// and local (e.g. path) packages are marked with a `local=true` qualifier so
+/// they can't be confused with crates.io packages of the same name.
+///
+/// [package URL]: https://github.com/package-url/purl-spec
+pub fn purl(package: &Package) -> String {
+    let mut purl = format!(
+        "pkg:cargo/{}@{}",
+        percent_encode(package.name.as_str()),
+        percent_encode(&package.version.to_string())
+    );
+
+    if let Some((qualifier, value)) = source_qualifier(package.source.as_ref()) {
+        write!(purl, "?{}={}", qualifier, percent_encode(&value)).unwrap();
+    }
+
+    purl
+}
+
+/// Get the package URL for a package which is part of the Rust toolchain
+fn toolchain_purl(package: &Package) -> String {
+    format!(
+        "pkg:generic/rust-lang/{}@{}",
+        percent_encode(package.name.as_str()),
+        percent_encode(&package.version.to_string())
+    )
+}
+
+/// Get the package URL qualifier describing the given source (if any)
+fn source_qualifier(source: Option<&SourceId>) -> Option<(&'static str, String)> {
+    let source = match source {
+        Some(source) if !source.is_path() => source,
+        _ => return Some(("local", "true".to_owned())),
+    };
+
+    if source.is_default_registry() {
+        None
+    } else if source.is_git() {
+        let mut vcs_url = format!("git+{}", source.url());
+
+        if let Some(precise) = source.precise() {
+            write!(vcs_url, "@{}", precise).unwrap();
+        }
+
+        Some(("vcs_url", vcs_url))
+    } else {
+        Some(("repository_url", source.url().to_string()))
+    }
+}
+
+/// Percent-encode a package URL component
+fn percent_encode(s: &str) -> String {
+    let mut encoded = String::with_capacity(s.len());
+
+    for byte in s.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => write!(encoded, "%{:02X}", byte).unwrap(),
+        }
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::purl;
+    use crate::lockfile::Lockfile;
+
+    const LOCKFILE: &str = r#"
+[[package]]
+name = "base64"
+version = "0.13.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "904dfeac50f3cdaba28fc6f57fdcddb75f49ed61346676a78c4ffe55877802fd"
+
+[[package]]
+name = "example"
+version = "0.1.0"
+dependencies = [
+ "base64",
+ "forked",
+]
+
+[[package]]
+name = "forked"
+version = "1.0.0+patched"
+source = "git+https://github.com/example/forked.git#c8a7e7a0ffb1e8e0f3b5b3e0c9e8d0a2b1c3d4e5"
+"#;
+
+    #[test]
+    fn package_urls() {
+        let lockfile = LOCKFILE.parse::<Lockfile>().unwrap();
+        let purls: Vec<_> = lockfile.packages.iter().map(purl).collect();
+
+        assert_eq!(
+            purls,
+            [
+                "pkg:cargo/base64@0.13.0",
+                "pkg:cargo/example@0.1.0?local=true",
+                "pkg:cargo/forked@1.0.0%2Bpatched?vcs_url=git%2Bhttps%3A%2F%2Fgithub.com%2Fexample%2Fforked.git%40c8a7e7a0ffb1e8e0f3b5b3e0c9e8d0a2b1c3d4e5"
+            ]
+        );
+    }
+}
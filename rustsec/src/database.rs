@@ -1,5 +1,7 @@
 //! Database containing `RustSec` security advisories
 
+#[cfg(feature = "git")]
+mod bundle;
 mod entries;
 mod index;
 mod query;
@@ -8,20 +10,31 @@ pub mod scope;
 
 pub use self::query::Query;
 
-use self::{entries::Entries, index::Index};
+use self::{
+    entries::{Entries, Slot},
+    index::Index,
+};
 use crate::{
     advisory::{self, Advisory},
     collection::Collection,
     error::Error,
     fs,
     lockfile::Lockfile,
+    package::Package,
     vulnerability::Vulnerability,
 };
+use semver::Version;
 use std::path::Path;
 
+#[cfg(any(feature = "git", feature = "osv-import"))]
+use crate::error::ErrorKind;
+
 #[cfg(feature = "git")]
 use crate::repository::git;
 
+#[cfg(feature = "osv-import")]
+use crate::osv::OsvAdvisory;
+
 /// Iterator over entries in the database
 pub type Iter<'a> = std::slice::Iter<'a, Advisory>;
 
@@ -40,6 +53,24 @@ pub struct Database {
     /// Information about the last git commit to the database
     #[cfg(feature = "git")]
     latest_commit: Option<git::Commit>,
+
+    /// Databases which have been merged into this one
+    sources: Vec<Source>,
+}
+
+/// Information about an advisory database which has been merged into
+/// another one with [`Database::merge`]
+#[derive(Debug)]
+pub struct Source {
+    /// Name of the database (e.g. the URL of its git repository)
+    pub name: String,
+
+    /// Number of advisories merged from the database
+    pub advisory_count: usize,
+
+    /// Information about the last git commit to the database
+    #[cfg(feature = "git")]
+    pub latest_commit: Option<git::Commit>,
 }
 
 impl Database {
@@ -59,31 +90,129 @@ impl Database {
             }
         }
 
-        let mut advisories = Entries::new();
-        let mut rust_index = Index::new();
-        let mut crate_index = Index::new();
+        let mut db = Self::new();
 
         for path in &advisory_paths {
-            if let Some(slot) = advisories.load_file(path)? {
-                let advisory = advisories.get(slot).unwrap();
-                match advisory.metadata.collection.unwrap() {
-                    Collection::Crates => {
-                        crate_index.insert(&advisory.metadata.package, slot);
-                    }
-                    Collection::Rust => {
-                        rust_index.insert(&advisory.metadata.package, slot);
-                    }
+            if let Some(slot) = db.advisories.load_file(path)? {
+                db.index(slot);
+            }
+        }
+
+        Ok(db)
+    }
+
+    /// Open a [`Database`] of advisories in the [OSV] JSON format (e.g. a
+    /// dump of the GitHub Advisory Database) located at the given path.
+    ///
+    /// All `*.json` files in the directory and its subdirectories are loaded.
+    /// Advisories which don't affect any crates.io packages are skipped.
+    ///
+    /// Advisories which affect more than one crates.io package, or whose
+    /// crates.io version ranges aren't valid semver, can't be represented as
+    /// RustSec advisories, so they're skipped too, and their IDs are returned
+    /// along with the database. Versions of packages in other ecosystems are
+    /// never parsed.
+    ///
+    /// [OSV]: https://github.com/google/osv
+    #[cfg(feature = "osv-import")]
+    pub fn open_osv(path: &Path) -> Result<(Self, Vec<advisory::Id>), Error> {
+        let mut advisory_paths = vec![];
+        let mut dirs = vec![path.to_owned()];
+
+        while let Some(dir) = dirs.pop() {
+            for dir_entry in fs::read_dir(&dir)? {
+                let entry_path = dir_entry?.path();
+
+                if entry_path.is_dir() {
+                    dirs.push(entry_path);
+                } else if entry_path.extension().and_then(|ext| ext.to_str()) == Some("json") {
+                    advisory_paths.push(entry_path);
                 }
             }
         }
 
-        Ok(Self {
-            advisories,
-            crate_index,
-            rust_index,
-            #[cfg(feature = "git")]
-            latest_commit: None,
-        })
+        // Load advisories in a consistent order regardless of the filesystem
+        advisory_paths.sort();
+
+        let mut db = Self::new();
+        let mut skipped = vec![];
+
+        for path in &advisory_paths {
+            let osv_advisory = OsvAdvisory::load_file(path)?;
+
+            if !osv_advisory.affects_crates_io() {
+                continue;
+            }
+
+            if osv_advisory.affects_multiple_crates() {
+                skipped.push(osv_advisory.id().clone());
+                continue;
+            }
+
+            let id = osv_advisory.id().clone();
+
+            let advisory = match osv_advisory.into_rustsec() {
+                Ok(advisory) => advisory,
+                Err(e) if e.kind() == ErrorKind::Version => {
+                    skipped.push(id);
+                    continue;
+                }
+                Err(e) => {
+                    return Err(Error::new(
+                        e.kind(),
+                        &format!("error converting {}: {}", path.display(), e),
+                    ))
+                }
+            };
+
+            if let Some(slot) = db.advisories.insert(advisory)? {
+                db.index(slot);
+            }
+        }
+
+        Ok((db, skipped))
+    }
+
+    /// Open an offline [`Database`] bundle previously written with
+    /// [`Database::export_bundle`].
+    ///
+    /// The bundle's checksum is verified before any advisories are loaded.
+    /// Bundles are unsigned, so this only detects corruption: it doesn't
+    /// authenticate the advisories.
+    ///
+    /// Information about the commit it was exported from is available via
+    /// [`Database::latest_commit`], e.g. to check whether it is stale.
+    #[cfg(feature = "git")]
+    pub fn open_bundle(path: &Path) -> Result<Self, Error> {
+        let bundle = bundle::Bundle::load_file(path)?;
+        let mut db = Self::new();
+
+        for advisory in bundle.advisories {
+            if let Some(slot) = db.advisories.insert(advisory)? {
+                db.index(slot);
+            }
+        }
+
+        db.latest_commit = Some(bundle.commit.into_commit());
+        Ok(db)
+    }
+
+    /// Export this [`Database`] into a single versioned, checksummed bundle
+    /// file which can be loaded with [`Database::open_bundle`] (e.g. on
+    /// machines without network access).
+    ///
+    /// Only databases loaded from a git repository can be exported, as the
+    /// bundle records the commit they were loaded from.
+    #[cfg(feature = "git")]
+    pub fn export_bundle(&self, path: &Path) -> Result<(), Error> {
+        let commit = self.latest_commit.as_ref().ok_or_else(|| {
+            format_err!(
+                ErrorKind::NotFound,
+                "no commit information for advisory database"
+            )
+        })?;
+
+        bundle::Bundle::new(commit, self.iter().cloned().collect()).write_file(path)
     }
 
     /// Load [`Database`] from the given [`git::Repository`]
@@ -100,6 +229,86 @@ impl Database {
         git::Repository::fetch_default_repo().and_then(|repo| Self::load_from_repo(&repo))
     }
 
+    /// Merge the advisories from another [`Database`] into this one, and
+    /// record it as a [`Source`] with the given name (e.g. the URL of its git
+    /// repository).
+    ///
+    /// Advisories with the same ID as an advisory which is already in this
+    /// database are skipped, and their IDs are returned. Databases should
+    /// therefore be merged in order of precedence.
+    ///
+    /// Information about the latest commit to the first database merged into
+    /// an empty database (see [`Database::default`]) is used as the latest
+    /// commit of the merged database.
+    pub fn merge(&mut self, name: impl Into<String>, other: Database) -> Vec<advisory::Id> {
+        #[cfg(feature = "git")]
+        let latest_commit = other.latest_commit.clone();
+
+        let mut advisory_count = 0;
+        let mut skipped = vec![];
+
+        for advisory in other {
+            if self.advisories.find_by_id(advisory.id()).is_some() {
+                skipped.push(advisory.id().clone());
+                continue;
+            }
+
+            // Advisories in a database always have a collection and unique ID
+            if let Some(slot) = self.advisories.insert(advisory).unwrap() {
+                self.index(slot);
+                advisory_count += 1;
+            }
+        }
+
+        #[cfg(feature = "git")]
+        {
+            if self.sources.is_empty() && self.latest_commit.is_none() {
+                self.latest_commit = latest_commit.clone();
+            }
+        }
+
+        self.sources.push(Source {
+            name: name.into(),
+            advisory_count,
+            #[cfg(feature = "git")]
+            latest_commit,
+        });
+
+        skipped
+    }
+
+    /// Get information about the databases which have been merged into
+    /// this one with [`Database::merge`]
+    pub fn sources(&self) -> &[Source] {
+        &self.sources
+    }
+
+    /// Create an empty database
+    fn new() -> Self {
+        Self {
+            advisories: Entries::new(),
+            crate_index: Index::new(),
+            rust_index: Index::new(),
+            #[cfg(feature = "git")]
+            latest_commit: None,
+            sources: vec![],
+        }
+    }
+
+    /// Add the advisory in the given slot to the package index for its
+    /// collection
+    fn index(&mut self, slot: Slot) {
+        let advisory = self.advisories.get(slot).unwrap();
+        match advisory.metadata.collection.unwrap() {
+            Collection::Crates => {
+                self.crate_index.insert(&advisory.metadata.package, slot);
+            }
+            Collection::Rust => {
+                self.rust_index.insert(&advisory.metadata.package, slot);
+            }
+        }
+    }
+
     /// Look up an advisory by an advisory ID (e.g. "RUSTSEC-YYYY-XXXX")
     pub fn get(&self, id: &advisory::Id) -> Option<&Advisory> {
         self.advisories.find_by_id(id)
@@ -145,15 +354,23 @@ impl Database {
                 continue;
             }
 
-            let advisories = self.query(
-                &query
-                    .clone()
-                    .package_version(package.name.clone(), package.version.clone()),
-            );
+            let mut package_query = query
+                .clone()
+                .package_version(package.name.clone(), package.version.clone());
+
+            if let Some(source) = &package.source {
+                package_query = package_query.package_source(source.clone());
+            }
+
+            let advisories = self.query(&package_query);
 
+            // Local packages can't come from the private registry an advisory is for
             vulns.extend(
                 advisories
                     .iter()
+                    .filter(|advisory| {
+                        package.source.is_some() || advisory.metadata.source.is_none()
+                    })
                     .map(|advisory| Vulnerability::new(advisory, package)),
             );
         }
@@ -161,6 +378,34 @@ impl Database {
         vulns
     }
 
+    /// Find vulnerabilities in the Rust toolchain with the given version (i.e.
+    /// advisories in the `rust` collection) which match a given query.
+    ///
+    /// The vulnerable package of each vulnerability is the toolchain
+    /// component the advisory is for (e.g. `std`) with the toolchain version.
+    pub fn query_toolchain_vulnerabilities(
+        &self,
+        rust_version: &Version,
+        query: &Query,
+    ) -> Vec<Vulnerability> {
+        self.query(&query.clone().collection(Collection::Rust))
+            .into_iter()
+            .filter(|advisory| advisory.versions.is_vulnerable(rust_version))
+            .map(|advisory| {
+                let package = Package {
+                    name: advisory.metadata.package.clone(),
+                    version: rust_version.clone(),
+                    source: None,
+                    checksum: None,
+                    dependencies: vec![],
+                    replace: None,
+                };
+
+                Vulnerability::new(advisory, &package)
+            })
+            .collect()
+    }
+
     /// Scan for vulnerabilities in the provided `Lockfile`.
     pub fn vulnerabilities(&self, lockfile: &Lockfile) -> Vec<Vulnerability> {
         self.query_vulnerabilities(lockfile, &Query::crate_scope(), scope::Package::default())
@@ -178,6 +423,13 @@ impl Database {
     }
 }
 
+impl Default for Database {
+    /// Create an empty database, e.g. to [`Database::merge`] others into
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl IntoIterator for Database {
     type Item = Advisory;
 
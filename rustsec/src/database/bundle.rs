@@ -0,0 +1,258 @@
+//! Offline advisory database bundles
+//!
+//! Bundles contain every advisory in the database along with information
+//! about the commit they were exported from, serialized as TOML and
+//! prefixed with a header line identifying the bundle format version and
+//! the SHA-256 checksum of the payload:
+//!
+//! ```text
+//! rustsec-db-bundle v1 sha256:<hex digest of payload>
+//! [commit]
+//! commit_id = "..."
+//! ...
+//!
+//! [[advisories]]
+//! ...
+//! ```
+//!
+//! Bundles are not authenticated: the checksum only detects corruption, and
+//! anyone who can modify a bundle can update its checksum too. The signature
+//! on the commit a bundle was exported from doesn't cover its advisories, so
+//! it isn't included.
+
+use crate::{
+    advisory::Advisory,
+    error::{Error, ErrorKind},
+    fs,
+    repository::git::Commit,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    path::Path,
+    time::{Duration, UNIX_EPOCH},
+};
+
+/// Magic string at the start of every bundle
+const MAGIC: &str = "rustsec-db-bundle";
+
+/// Current version of the bundle format
+const VERSION: u32 = 1;
+
+/// Offline advisory database bundle
+#[derive(Debug, Deserialize, Serialize)]
+pub(super) struct Bundle {
+    /// Commit the advisories were exported from
+    pub(super) commit: BundleCommit,
+
+    /// All advisories in the database
+    pub(super) advisories: Vec<Advisory>,
+}
+
+impl Bundle {
+    /// Create a new bundle
+    pub(super) fn new(commit: &Commit, advisories: Vec<Advisory>) -> Self {
+        Self {
+            commit: BundleCommit::from(commit),
+            advisories,
+        }
+    }
+
+    /// Load a bundle from the file at the given path, verifying its checksum
+    pub(super) fn load_file(path: &Path) -> Result<Self, Error> {
+        let data = fs::read_to_string(path)?;
+
+        Self::parse(&data).map_err(|e| {
+            Error::new(
+                e.kind(),
+                &format!(
+                    "error loading advisory database bundle {}: {}",
+                    path.display(),
+                    e
+                ),
+            )
+        })
+    }
+
+    /// Write this bundle to a file at the given path
+    pub(super) fn write_file(&self, path: &Path) -> Result<(), Error> {
+        let payload = toml::to_string(self)
+            .map_err(|e| format_err!(ErrorKind::Parse, "error serializing bundle: {}", e))?;
+
+        let header = format!("{} v{} sha256:{}", MAGIC, VERSION, checksum(&payload));
+        fs::write(path, format!("{}\n{}", header, payload))?;
+        Ok(())
+    }
+
+    /// Parse a serialized bundle
+    fn parse(data: &str) -> Result<Self, Error> {
+        let (header, payload) = match data.find('\n') {
+            Some(pos) => (&data[..pos], &data[pos + 1..]),
+            None => fail!(ErrorKind::Parse, "missing bundle header"),
+        };
+
+        let mut fields = header.trim_end().split(' ');
+
+        if fields.next() != Some(MAGIC) {
+            fail!(ErrorKind::Parse, "not an advisory database bundle");
+        }
+
+        let version = fields
+            .next()
+            .and_then(|version| version.strip_prefix('v'))
+            .and_then(|version| version.parse::<u32>().ok())
+            .ok_or_else(|| format_err!(ErrorKind::Parse, "malformed bundle version"))?;
+
+        if version != VERSION {
+            fail!(
+                ErrorKind::Version,
+                "unsupported bundle version: v{} (expected v{})",
+                version,
+                VERSION
+            );
+        }
+
+        let expected = fields
+            .next()
+            .and_then(|digest| digest.strip_prefix("sha256:"))
+            .ok_or_else(|| format_err!(ErrorKind::Parse, "missing bundle checksum"))?;
+
+        if fields.next().is_some() {
+            fail!(ErrorKind::Parse, "malformed bundle header");
+        }
+
+        if checksum(payload) != expected.to_ascii_lowercase() {
+            fail!(ErrorKind::Parse, "bundle checksum mismatch");
+        }
+
+        Ok(toml::from_str(payload)?)
+    }
+}
+
+/// Information about the commit a bundle was exported from
+#[derive(Debug, Deserialize, Serialize)]
+pub(super) struct BundleCommit {
+    /// ID (i.e. SHA-1 hash) of the commit
+    commit_id: String,
+
+    /// Author of the commit
+    author: String,
+
+    /// Summary message for the commit
+    summary: String,
+
+    /// Commit time in number of seconds since the UNIX epoch
+    timestamp: u64,
+}
+
+impl BundleCommit {
+    /// Convert into a [`Commit`] (which is never signed)
+    pub(super) fn into_commit(self) -> Commit {
+        Commit {
+            commit_id: self.commit_id,
+            author: self.author,
+            summary: self.summary,
+            timestamp: UNIX_EPOCH + Duration::from_secs(self.timestamp),
+            signature: None,
+            signed_data: None,
+        }
+    }
+}
+
+impl From<&Commit> for BundleCommit {
+    fn from(commit: &Commit) -> Self {
+        Self {
+            commit_id: commit.commit_id.clone(),
+            author: commit.author.clone(),
+            summary: commit.summary.clone(),
+            timestamp: commit
+                .timestamp
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_secs())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Compute the hex-encoded SHA-256 checksum of a bundle's payload
+fn checksum(payload: &str) -> String {
+    Sha256::digest(payload.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bundle, Commit};
+    use crate::{advisory::Advisory, collection::Collection, error::ErrorKind, Database};
+    use std::{
+        fs,
+        time::{Duration, UNIX_EPOCH},
+    };
+
+    fn example_bundle() -> Bundle {
+        let commit = Commit {
+            commit_id: "6fb1e9c16bcc2f58bd3c1193b7cc1ab0bbd86e16".to_owned(),
+            author: "Example Author <author@example.com>".to_owned(),
+            summary: "Add example advisory".to_owned(),
+            timestamp: UNIX_EPOCH + Duration::from_secs(1_600_000_000),
+            signature: None,
+            signed_data: Some(b"tree 0123\n\nAdd example advisory\n".to_vec()),
+        };
+
+        let mut advisory: Advisory = include_str!("../../tests/support/example_advisory_v4.md")
+            .parse()
+            .unwrap();
+
+        advisory.metadata.collection = Some(Collection::Crates);
+        Bundle::new(&commit, vec![advisory])
+    }
+
+    #[test]
+    fn round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("advisory-db.bundle");
+        let bundle = example_bundle();
+        bundle.write_file(&path).unwrap();
+
+        let db = Database::open_bundle(&path).unwrap();
+        assert_eq!(
+            db.iter().collect::<Vec<_>>(),
+            bundle.advisories.iter().collect::<Vec<_>>()
+        );
+        assert!(db.get(&bundle.advisories[0].metadata.id).is_some());
+
+        let commit = db.latest_commit().unwrap();
+        assert_eq!(commit.commit_id, "6fb1e9c16bcc2f58bd3c1193b7cc1ab0bbd86e16");
+        assert_eq!(
+            commit.timestamp,
+            UNIX_EPOCH + Duration::from_secs(1_600_000_000)
+        );
+        assert!(commit.signature.is_none());
+        assert_eq!(commit.raw_signed_bytes(), None);
+        assert!(!commit.is_fresh());
+    }
+
+    #[test]
+    fn checksum_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("advisory-db.bundle");
+        example_bundle().write_file(&path).unwrap();
+
+        let tampered = fs::read_to_string(&path)
+            .unwrap()
+            .replace("Add example advisory", "Remove example advisory");
+
+        fs::write(&path, tampered).unwrap();
+
+        let err = Database::open_bundle(&path).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Parse);
+    }
+
+    #[test]
+    fn unsupported_version() {
+        let err = Bundle::parse("rustsec-db-bundle v2 sha256:00\n").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Version);
+    }
+}
@@ -115,6 +115,21 @@ impl Entries {
             None => advisory.metadata.collection = Some(collection),
         }
 
+        self.insert(advisory)
+    }
+
+    /// Insert an advisory into the database entry table.
+    ///
+    /// The advisory's collection must already be set.
+    pub fn insert(&mut self, advisory: Advisory) -> Result<Option<Slot>, Error> {
+        if advisory.metadata.collection.is_none() {
+            fail!(
+                ErrorKind::Parse,
+                "no collection for {}",
+                &advisory.metadata.id
+            );
+        }
+
         // Ensure placeholder advisories load and parse correctly, but
         // don't actually insert them into the advisory database
         if advisory.metadata.id.is_placeholder() {
@@ -6,7 +6,10 @@ use crate::{
     database::scope,
     package,
 };
-use platforms::target::{Arch, OS};
+use platforms::{
+    target::{Arch, OS},
+    Platform,
+};
 use semver::Version;
 
 /// Queries against the RustSec database
@@ -21,6 +24,9 @@ pub struct Query {
     /// Version of a package to search for
     version: Option<Version>,
 
+    /// Source of a package to search for
+    package_source: Option<package::SourceId>,
+
     /// Severity threshold (i.e. minimum severity)
     severity: Option<Severity>,
 
@@ -30,6 +36,9 @@ pub struct Query {
     /// Target operating system
     target_os: Option<OS>,
 
+    /// Target platforms, any of which must be affected
+    target_platforms: Vec<&'static Platform>,
+
     /// Year associated with the advisory ID
     year: Option<u32>,
 
@@ -86,6 +95,17 @@ impl Query {
         self
     }
 
+    /// Set the source (i.e. registry, git repository or path) of the package
+    /// to search for.
+    ///
+    /// Advisories for crates published to a private registry only match
+    /// packages from that registry, and advisories for crates.io crates
+    /// never match packages from other registries.
+    pub fn package_source(mut self, source: package::SourceId) -> Self {
+        self.package_source = Some(source);
+        self
+    }
+
     /// Set minimum severity threshold according to the CVSS
     /// Qualitative Severity Rating Scale.
     ///
@@ -108,6 +128,15 @@ impl Query {
         self
     }
 
+    /// Add a target platform.
+    ///
+    /// When any target platforms are set, only advisories which affect at
+    /// least one of them will match.
+    pub fn target_platform(mut self, platform: &'static Platform) -> Self {
+        self.target_platforms.push(platform);
+        self
+    }
+
     /// Query for vulnerabilities occurring in a specific year.
     pub fn year(mut self, year: u32) -> Self {
         self.year = Some(year);
@@ -149,6 +178,21 @@ impl Query {
             }
         }
 
+        if let Some(package_source) = &self.package_source {
+            let matches_source = match &advisory.metadata.source {
+                Some(advisory_source) => {
+                    package_source.is_registry() && package_source.url() == advisory_source.url()
+                }
+                None => {
+                    !package_source.is_remote_registry() || package_source.is_default_registry()
+                }
+            };
+
+            if !matches_source {
+                return false;
+            }
+        }
+
         if let Some(severity_threshold) = self.severity {
             if let Some(advisory_severity) = advisory.severity() {
                 if advisory_severity < severity_threshold {
@@ -169,6 +213,15 @@ impl Query {
                     return false;
                 }
             }
+
+            if !self.target_platforms.is_empty()
+                && !self
+                    .target_platforms
+                    .iter()
+                    .any(|platform| affected.affects_platform(platform))
+            {
+                return false;
+            }
         }
 
         if let Some(query_year) = self.year {
@@ -99,6 +99,10 @@ pub enum ErrorKind {
     #[error("git operation failed")]
     Repo,
 
+    /// Commit signature verification failed
+    #[error("signature verification failed")]
+    Signature,
+
     /// Errors related to versions
     #[error("bad version")]
     Version,
@@ -2,41 +2,185 @@
 
 use crate::{
     error::{Error, ErrorKind},
+    fs, package, registry,
     vulnerability::Vulnerability,
 };
-use semver::VersionReq;
-use std::path::Path;
+use semver::Version;
+use std::path::{Path, PathBuf};
+
+/// Dependency tables which can declare a dependency
+const DEPENDENCY_TABLES: &[&str] = &["dependencies", "dev-dependencies", "build-dependencies"];
 
 /// Auto-fixer for vulnerable dependencies
 pub struct Fixer {
-    manifest: cargo_edit::LocalManifest,
+    /// Root manifest followed by the manifests of all workspace members
+    manifests: Vec<cargo_edit::LocalManifest>,
+    registry_index: registry::Index,
 }
 
 impl Fixer {
-    /// Create a new [`Fixer`] for the given `Cargo.toml` file
-    pub fn new(cargo_toml: impl AsRef<Path>) -> Result<Self, Error> {
-        let manifest = cargo_edit::LocalManifest::try_new(cargo_toml.as_ref())?;
-        Ok(Self { manifest })
+    /// Create a new [`Fixer`] for the given `Cargo.toml` file, using the
+    /// given crates.io index to look up available releases.
+    ///
+    /// If the manifest is the root of a workspace, the manifests of all of
+    /// its members are fixed as well.
+    ///
+    /// Requires the `git` feature (enabled by the `fix` feature), which
+    /// provides [`registry::Index`].
+    pub fn new(
+        cargo_toml: impl AsRef<Path>,
+        registry_index: registry::Index,
+    ) -> Result<Self, Error> {
+        let cargo_toml = cargo_toml.as_ref();
+        let mut manifests = vec![cargo_edit::LocalManifest::try_new(cargo_toml)?];
+
+        for member in cargo_edit::workspace_members(Some(cargo_toml))? {
+            let manifest = cargo_edit::LocalManifest::try_new(member.manifest_path.as_ref())?;
+
+            if !manifests.iter().any(|m| same_file(&m.path, &manifest.path)) {
+                manifests.push(manifest);
+            }
+        }
+
+        Ok(Self {
+            manifests,
+            registry_index,
+        })
     }
 
-    /// Attempt to fix the given vulnerability
-    pub fn fix(
-        &mut self,
-        vulnerability: &Vulnerability,
-        dry_run: bool,
-    ) -> Result<VersionReq, Error> {
-        // TODO(tarcieri): find semver-compatible fix?
-        let version_req = match vulnerability.versions.patched().get(0) {
-            Some(req) => req,
-            None => fail!(ErrorKind::Version, "no fixed version available"),
+    /// Attempt to fix the given vulnerability in every manifest which
+    /// declares the vulnerable package (including `[workspace.dependencies]`).
+    ///
+    /// The minimal non-yanked patched release which is semver-compatible with
+    /// the current version is preferred, falling back to the minimal patched
+    /// release in a newer (semver-incompatible) release line.
+    pub fn fix(&mut self, vulnerability: &Vulnerability, dry_run: bool) -> Result<Fix, Error> {
+        let package = &vulnerability.package;
+
+        let releases: Vec<Version> = self
+            .registry_index
+            .find_all(&package.name)?
+            .into_iter()
+            .filter(|release| !release.is_yanked)
+            .map(|release| release.version)
+            .collect();
+
+        let version = match vulnerability
+            .versions
+            .minimal_upgrade(&package.version, &releases)
+        {
+            Some(version) => version.clone(),
+            None => fail!(
+                ErrorKind::Version,
+                "no fixed version available for {} {}",
+                package.name,
+                package.version
+            ),
         };
 
-        let dependency = cargo_edit::Dependency::new(vulnerability.package.name.as_str())
-            .set_version(&version_req.to_string());
+        let dependency =
+            cargo_edit::Dependency::new(package.name.as_str()).set_version(&version.to_string());
+
+        let workspace_table = ["workspace".to_owned(), "dependencies".to_owned()];
+        let mut manifests = vec![];
+
+        for manifest in &mut self.manifests {
+            if declares_workspace_dependency(manifest, package.name.as_str()) {
+                manifest.update_table_entry(&workspace_table, &dependency, dry_run)?;
+
+                // Unlike `upgrade`, updating a table entry doesn't save it.
+                // `LocalManifest::write` refuses to write virtual manifests,
+                // which is where `[workspace.dependencies]` usually are.
+                if !dry_run {
+                    fs::write(&manifest.path, manifest.data.to_string())?;
+                }
+
+                manifests.push(manifest.path.clone());
+            }
+
+            // Dependencies inherited from the workspace (`workspace = true`)
+            // are upgraded through `[workspace.dependencies]` above
+            if declares_dependency(manifest, package.name.as_str()) {
+                manifest.upgrade(&dependency, dry_run, false)?;
+
+                if !manifests.contains(&manifest.path) {
+                    manifests.push(manifest.path.clone());
+                }
+            }
+        }
+
+        if manifests.is_empty() {
+            fail!(
+                ErrorKind::NotFound,
+                "{} is not a direct dependency in any workspace manifest",
+                package.name
+            );
+        }
 
-        self.manifest.upgrade(&dependency, dry_run, false)?;
+        Ok(Fix {
+            package: package.name.clone(),
+            version,
+            manifests,
+        })
+    }
+}
+
+/// Upgrade of a vulnerable dependency performed by the [`Fixer`]
+#[derive(Clone, Debug)]
+pub struct Fix {
+    /// Name of the upgraded package
+    pub package: package::Name,
+
+    /// Version the package was upgraded to
+    pub version: Version,
+
+    /// Paths to the manifests which were changed
+    pub manifests: Vec<PathBuf>,
+}
+
+/// Does the given manifest declare the given package in
+/// `[workspace.dependencies]`?
+fn declares_workspace_dependency(manifest: &cargo_edit::LocalManifest, name: &str) -> bool {
+    manifest
+        .data
+        .as_table()
+        .get("workspace")
+        .and_then(|workspace| workspace.as_table_like())
+        .and_then(|workspace| workspace.get("dependencies"))
+        .and_then(|deps| deps.as_table_like())
+        .and_then(|deps| deps.get(name))
+        .is_some()
+}
+
+/// Does the given manifest declare the given package in any of its
+/// (possibly target-specific) dependency tables, other than by inheriting it
+/// from the workspace?
+fn declares_dependency(manifest: &cargo_edit::LocalManifest, name: &str) -> bool {
+    let root = manifest.data.as_table();
+
+    let targets = root
+        .get("target")
+        .and_then(|target| target.as_table_like())
+        .map(|target| target.iter().map(|(_, item)| item).collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    std::iter::once(root as &dyn toml_edit::TableLike)
+        .chain(targets.into_iter().filter_map(|item| item.as_table_like()))
+        .flat_map(|table| DEPENDENCY_TABLES.iter().filter_map(move |t| table.get(t)))
+        .filter_map(|deps| deps.as_table_like())
+        .filter_map(|deps| deps.get(name))
+        .any(|dep| {
+            dep.as_table_like()
+                .and_then(|dep| dep.get("workspace"))
+                .and_then(|workspace| workspace.as_bool())
+                != Some(true)
+        })
+}
 
-        // TODO(tarcieri): return new version rather than req?
-        Ok(version_req.clone())
+/// Do the given paths refer to the same file?
+fn same_file(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
     }
 }
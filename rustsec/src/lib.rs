@@ -16,20 +16,29 @@ mod error;
 
 pub mod advisory;
 mod collection;
+pub mod cyclonedx;
 pub mod database;
 pub mod osv;
 pub mod report;
 pub mod repository;
+pub mod toolchain;
 mod vulnerability;
 pub mod warning;
 
+#[cfg(feature = "cargo-metadata")]
+pub mod cargo_metadata;
+
 #[cfg(feature = "fix")]
 mod fixer;
 
+#[cfg(all(feature = "git", feature = "dependency-tree"))]
+pub mod planner;
+
 #[cfg(feature = "git")]
 pub mod registry;
 
 pub use cargo_lock::{self, lockfile, package};
+pub use cvss;
 pub use fs_err as fs;
 pub use platforms;
 pub use semver::{self, Version, VersionReq};
@@ -40,12 +49,12 @@ pub use crate::{
     database::Database,
     error::{Error, ErrorKind, Result},
     report::Report,
-    vulnerability::Vulnerability,
+    vulnerability::{Reachability, Vulnerability},
     warning::Warning,
 };
 
 #[cfg(feature = "fix")]
-pub use crate::fixer::Fixer;
+pub use crate::fixer::{Fix, Fixer};
 
 #[cfg(feature = "git")]
 pub use crate::repository::git::Repository;
@@ -1,14 +1,14 @@
-//! Provides support for exporting to the interchange format defined by
-//! https://github.com/google/osv
+//! Provides support for exporting to and importing from the interchange
+//! format defined by https://github.com/google/osv
 //!
 //! We also use OSV-style ranges for version matching in RustSec crate
 //! because it allows handling pre-releases correctly,
 //! which `semver` crate does not allow doing directly.
 //! See https://github.com/dtolnay/semver/issues/172
 
-#[cfg(feature = "osv-export")]
+#[cfg(any(feature = "osv-export", feature = "osv-import"))]
 mod osv_advisory;
-#[cfg(feature = "osv-export")]
+#[cfg(any(feature = "osv-export", feature = "osv-import"))]
 pub use osv_advisory::OsvAdvisory;
 
 // The rest are enabled unconditionally because the OSV range format
@@ -17,7 +17,9 @@ pub use osv_advisory::OsvAdvisory;
 mod osv_range;
 mod ranges_for_advisory;
 mod unaffected_range;
+mod versions_for_ranges;
 
 pub use osv_range::OsvRange;
 pub use ranges_for_advisory::ranges_for_advisory;
 pub(crate) use ranges_for_advisory::ranges_for_unvalidated_advisory;
+pub use versions_for_ranges::versions_for_ranges;
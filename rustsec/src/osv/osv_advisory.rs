@@ -1,81 +1,136 @@
+#[cfg(feature = "osv-export")]
 use std::{convert::TryInto, ops::Add};
+#[cfg(feature = "osv-import")]
+use std::{path::Path, str::FromStr};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use url::Url;
 
+#[cfg(feature = "osv-export")]
 use super::ranges_for_advisory;
+#[cfg(feature = "osv-import")]
+use super::{ranges_for_advisory::increment, versions_for_ranges, OsvRange};
 
+#[cfg(feature = "osv-export")]
+use crate::repository::git::{GitModificationTimes, GitPath};
+#[cfg(feature = "osv-import")]
 use crate::{
-    advisory::{affected::FunctionPath, Affected, Category, Id, Informational},
-    repository::git::{GitModificationTimes, GitPath},
+    advisory::Metadata,
+    collection::Collection,
+    error::{Error, ErrorKind},
+    fs, package,
+};
+use crate::{
+    advisory::{affected::FunctionPath, Affected, Category, Date, Id, Informational},
+    package::SourceId,
     Advisory,
 };
 
 const ECOSYSTEM: &str = "crates.io";
 
 /// Security advisory in the format defined by https://github.com/google/osv
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct OsvAdvisory {
     id: Id,
-    modified: String,  // maybe add an rfc3339 newtype?
+    modified: String, // maybe add an rfc3339 newtype?
+    #[serde(default)]
     published: String, // maybe add an rfc3339 newtype?
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     withdrawn: Option<String>, // maybe add an rfc3339 newtype?
+    #[serde(default)]
     aliases: Vec<Id>,
+    #[serde(default)]
     related: Vec<Id>,
+    #[serde(default)]
     summary: String,
+    #[serde(default)]
     details: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    severity: Vec<OsvSeverity>,
+    #[serde(default)]
     affected: Vec<OsvAffected>,
+    #[serde(default)]
     references: Vec<OsvReference>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct OsvPackage {
     /// Set to a constant identifying crates.io
-    ecosystem: &'static str,
+    ecosystem: String,
     /// Crate name
     name: String,
     /// https://github.com/package-url/purl-spec derived from the other two
-    purl: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    purl: Option<String>,
 }
 
 impl From<&cargo_lock::Name> for OsvPackage {
     fn from(package: &cargo_lock::Name) -> Self {
         OsvPackage {
-            ecosystem: ECOSYSTEM,
+            ecosystem: ECOSYSTEM.to_owned(),
             name: package.to_string(),
-            purl: "pkg:cargo/".to_string() + package.as_str(),
+            purl: Some("pkg:cargo/".to_string() + package.as_str()),
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct OsvAffected {
     package: OsvPackage,
+    #[serde(default)]
     ecosystem_specific: OsvEcosystemSpecific,
+    #[serde(default)]
     database_specific: OsvDatabaseSpecific,
+    #[serde(default)]
     ranges: Vec<OsvJsonRange>,
     // 'versions' field is not needed because we use semver ranges
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct OsvJsonRange {
     // 'type' is a reserved keyword in Rust
     #[serde(rename = "type")]
-    kind: &'static str,
+    kind: String,
     events: Vec<OsvTimelineEvent>,
     // 'repo' field is not used because we don't track or export git commit data
 }
 
-#[derive(Debug, Clone, Serialize)]
+/// Event in the timeline of a range. Versions are kept as strings, as only
+/// those of crates.io packages are semver versions (other ecosystems use
+/// their own version schemes, and `GIT` ranges use commit hashes).
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum OsvTimelineEvent {
     #[serde(rename = "introduced")]
-    Introduced(semver::Version),
+    Introduced(String),
     #[serde(rename = "fixed")]
-    Fixed(semver::Version),
+    Fixed(String),
+    /// Only used by advisories imported from other databases
+    #[serde(rename = "last_affected")]
+    LastAffected(String),
+}
+
+impl OsvTimelineEvent {
+    /// Semver version at which this event happened
+    #[cfg(feature = "osv-import")]
+    fn version(&self) -> Result<semver::Version, Error> {
+        match self {
+            OsvTimelineEvent::Introduced(v)
+            | OsvTimelineEvent::Fixed(v)
+            | OsvTimelineEvent::LastAffected(v) => parse_version(v),
+        }
+    }
+}
+
+/// Severity score, e.g. a CVSS vector
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OsvSeverity {
+    // 'type' is a reserved keyword in Rust
+    #[serde(rename = "type")]
+    kind: String,
+    score: String,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct OsvReference {
     // 'type' is a reserved keyword in Rust
     #[serde(rename = "type")]
@@ -83,6 +138,7 @@ pub struct OsvReference {
     url: Url,
 }
 
+#[cfg(feature = "osv-export")]
 impl From<Url> for OsvReference {
     fn from(url: Url) -> Self {
         OsvReference {
@@ -93,7 +149,7 @@ impl From<Url> for OsvReference {
 }
 
 #[allow(clippy::upper_case_acronyms)]
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum OsvReferenceKind {
     ADVISORY,
     #[allow(dead_code)]
@@ -103,22 +159,30 @@ pub enum OsvReferenceKind {
     FIX,
     PACKAGE,
     WEB,
+    /// Other kinds of references used by other databases
+    #[serde(other)]
+    OTHER,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct OsvEcosystemSpecific {
+    #[serde(default)]
     affects: OsvEcosystemSpecificAffected,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct OsvEcosystemSpecificAffected {
+    #[serde(default)]
     arch: Vec<platforms::target::Arch>,
+    #[serde(default)]
     os: Vec<platforms::target::OS>,
     /// We include function names only in order to allow changing
     /// the way versions are specified without an API break
+    #[serde(default)]
     functions: Vec<FunctionPath>,
 }
 
+#[cfg(feature = "osv-export")]
 impl From<Affected> for OsvEcosystemSpecificAffected {
     fn from(a: Affected) -> Self {
         OsvEcosystemSpecificAffected {
@@ -129,16 +193,23 @@ impl From<Affected> for OsvEcosystemSpecificAffected {
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct OsvDatabaseSpecific {
+    #[serde(default)]
     categories: Vec<Category>,
-    cvss: Option<cvss::v3::Base>,
+    #[serde(default)]
+    cvss: Option<cvss::Cvss>,
+    #[serde(default)]
     informational: Option<Informational>,
+    /// Registry the crate is published to, if other than crates.io
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    source: Option<SourceId>,
 }
 
 impl OsvAdvisory {
     /// Converts a single RustSec advisory to OSV format.
     /// `path` is the path to the advisory file. It must be relative to the git repository root.
+    #[cfg(feature = "osv-export")]
     pub fn from_rustsec(
         advisory: Advisory,
         mod_times: &GitModificationTimes,
@@ -149,8 +220,10 @@ impl OsvAdvisory {
         // Assemble the URLs to put into 'references' field
         let mut reference_urls: Vec<Url> = Vec::new();
         // link to the package on crates.io
-        let package_url = "https://crates.io/crates/".to_owned() + metadata.package.as_str();
-        reference_urls.push(Url::parse(&package_url).unwrap());
+        if metadata.source.is_none() {
+            let package_url = "https://crates.io/crates/".to_owned() + metadata.package.as_str();
+            reference_urls.push(Url::parse(&package_url).unwrap());
+        }
         // link to human-readable RustSec advisory
         let advisory_url = format!(
             "https://rustsec.org/advisories/{}.html",
@@ -178,6 +251,7 @@ impl OsvAdvisory {
                     categories: metadata.categories,
                     cvss: metadata.cvss,
                     informational: metadata.informational,
+                    source: metadata.source,
                 },
             }],
             withdrawn: metadata.withdrawn.map(|d| rustsec_date_to_rfc3339(&d)),
@@ -185,15 +259,276 @@ impl OsvAdvisory {
             related: metadata.related,
             summary: metadata.title,
             details: metadata.description,
+            severity: Vec::new(),
             references: osv_references(reference_urls),
         }
     }
 }
 
+#[cfg(feature = "osv-import")]
+impl OsvAdvisory {
+    /// Load an OSV advisory from a JSON file
+    pub fn load_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+
+        let json = fs::read_to_string(path)
+            .map_err(|e| format_err!(ErrorKind::Io, "couldn't open {}: {}", path.display(), e))?;
+
+        serde_json::from_str(&json)
+            .map_err(|e| format_err!(ErrorKind::Parse, "error parsing {}: {}", path.display(), e))
+    }
+
+    /// Get the OSV ID of this advisory
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    /// Does this advisory affect any crates published on crates.io?
+    pub fn affects_crates_io(&self) -> bool {
+        self.affected
+            .iter()
+            .any(|affected| affected.package.ecosystem == ECOSYSTEM)
+    }
+
+    /// Does this advisory affect more than one crate published on crates.io?
+    pub fn affects_multiple_crates(&self) -> bool {
+        let mut names = self
+            .affected
+            .iter()
+            .filter(|affected| affected.package.ecosystem == ECOSYSTEM)
+            .map(|affected| &affected.package.name);
+
+        match names.next() {
+            Some(first) => names.any(|name| name != first),
+            None => false,
+        }
+    }
+
+    /// Converts this OSV advisory to a RustSec advisory.
+    ///
+    /// The advisory must affect exactly one crate on crates.io: entries for
+    /// packages in other ecosystems are ignored. All of the affected
+    /// `SEMVER` and `ECOSYSTEM` ranges for the crate are merged into the
+    /// `patched` and `unaffected` version requirements of the advisory.
+    pub fn into_rustsec(self) -> Result<Advisory, Error> {
+        let affected: Vec<OsvAffected> = self
+            .affected
+            .into_iter()
+            .filter(|affected| affected.package.ecosystem == ECOSYSTEM)
+            .collect();
+
+        let package_name = match affected.first() {
+            Some(first) => first.package.name.clone(),
+            None => fail!(
+                ErrorKind::NotFound,
+                "{} doesn't affect any crates.io packages",
+                self.id
+            ),
+        };
+
+        if affected
+            .iter()
+            .any(|affected| affected.package.name != package_name)
+        {
+            fail!(
+                ErrorKind::BadParam,
+                "{} affects more than one crates.io package",
+                self.id
+            );
+        }
+
+        let id = &self.id;
+        let mut ranges = vec![];
+        for range in affected.iter().flat_map(|affected| &affected.ranges) {
+            if range.kind == "SEMVER" || range.kind == "ECOSYSTEM" {
+                ranges.extend(range.osv_ranges().map_err(|e| {
+                    Error::new(e.kind(), &format!("invalid ranges in {}: {}", id, e))
+                })?);
+            }
+        }
+
+        let versions = versions_for_ranges(&ranges)
+            .map_err(|e| Error::new(e.kind(), &format!("invalid ranges in {}: {}", id, e)))?;
+
+        let package = package::Name::from_str(&package_name)?;
+        let specific = &affected[0];
+        let affects = &specific.ecosystem_specific.affects;
+
+        let affected_reqs: Vec<_> = ranges.iter().filter_map(affected_version_req).collect();
+
+        let affected_section =
+            if affects.arch.is_empty() && affects.os.is_empty() && affects.functions.is_empty() {
+                None
+            } else {
+                Some(Affected {
+                    arch: affects.arch.clone(),
+                    os: affects.os.clone(),
+                    functions: affects
+                        .functions
+                        .iter()
+                        .map(|function| (function.clone(), affected_reqs.clone()))
+                        .collect(),
+                })
+            };
+
+        let database_specific = &specific.database_specific;
+
+        let cvss = match &database_specific.cvss {
+            Some(cvss) => Some(cvss.clone()),
+            None => self
+                .severity
+                .iter()
+                .filter(|severity| severity.kind.starts_with("CVSS_"))
+                .find_map(|severity| severity.score.parse().ok()),
+        };
+
+        // Drop the links added when exporting to OSV, which are implied
+        // by the package name and advisory ID
+        let package_url = format!("https://crates.io/crates/{}", package_name);
+        let advisory_url = format!("https://rustsec.org/advisories/{}.html", self.id);
+
+        let mut references = self
+            .references
+            .into_iter()
+            .map(|reference| reference.url)
+            .filter(|url| url.as_str() != package_url && url.as_str() != advisory_url);
+
+        let url = references.next();
+        let references = references.collect();
+
+        let published = if self.published.is_empty() {
+            &self.modified
+        } else {
+            &self.published
+        };
+
+        let metadata = Metadata {
+            id: self.id,
+            package,
+            source: database_specific.source.clone(),
+            title: self.summary,
+            description: self.details,
+            date: rfc3339_to_rustsec_date(published)?,
+            aliases: self.aliases,
+            related: self.related,
+            collection: Some(Collection::Crates),
+            categories: database_specific.categories.clone(),
+            keywords: vec![],
+            cvss,
+            informational: database_specific.informational.clone(),
+            url,
+            references,
+            withdrawn: self
+                .withdrawn
+                .as_deref()
+                .map(rfc3339_to_rustsec_date)
+                .transpose()?,
+        };
+
+        Ok(Advisory {
+            metadata,
+            affected: affected_section,
+            versions,
+        })
+    }
+}
+
+#[cfg(feature = "osv-import")]
+impl OsvJsonRange {
+    /// Converts the timeline of events into `[introduced, fixed)` ranges,
+    /// parsing the versions of the events as semver versions
+    fn osv_ranges(&self) -> Result<Vec<OsvRange>, Error> {
+        let mut events = self
+            .events
+            .iter()
+            .map(|event| Ok((event.version()?, event)))
+            .collect::<Result<Vec<_>, Error>>()?;
+        events.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut ranges = vec![];
+        let mut introduced = None;
+
+        for (v, event) in events {
+            match event {
+                OsvTimelineEvent::Introduced(_) => {
+                    if introduced.is_none() {
+                        introduced = Some(v);
+                    }
+                }
+                OsvTimelineEvent::Fixed(_) => {
+                    if let Some(start) = introduced.take() {
+                        ranges.push(OsvRange {
+                            introduced: Some(start),
+                            fixed: Some(v),
+                        });
+                    }
+                }
+                OsvTimelineEvent::LastAffected(_) => {
+                    if let Some(start) = introduced.take() {
+                        ranges.push(OsvRange {
+                            introduced: Some(start),
+                            fixed: Some(increment(&v)),
+                        });
+                    }
+                }
+            }
+        }
+
+        if let Some(start) = introduced {
+            ranges.push(OsvRange {
+                introduced: Some(start),
+                fixed: None,
+            });
+        }
+
+        Ok(ranges)
+    }
+}
+
+/// Parses the version of a crates.io package in an OSV timeline event.
+/// Also accepts "0", which OSV uses to denote the lowest possible version.
+#[cfg(feature = "osv-import")]
+fn parse_version(version: &str) -> Result<semver::Version, Error> {
+    if version == "0" {
+        return Ok(semver::Version::parse("0.0.0-0").unwrap());
+    }
+
+    semver::Version::parse(version)
+        .map_err(|e| format_err!(ErrorKind::Version, "invalid version {}: {}", version, e))
+}
+
+/// Version requirement matching the versions in the given range
+#[cfg(feature = "osv-import")]
+fn affected_version_req(range: &OsvRange) -> Option<semver::VersionReq> {
+    let zero = semver::Version::parse("0.0.0-0").unwrap();
+
+    let req = match (
+        range.introduced.as_ref().filter(|v| **v != zero),
+        &range.fixed,
+    ) {
+        (Some(start), Some(end)) => format!(">= {}, < {}", start, end),
+        (Some(start), None) => format!(">= {}", start),
+        (None, Some(end)) => format!("< {}", end),
+        (None, None) => "*".to_owned(),
+    };
+
+    req.parse().ok()
+}
+
+#[cfg(feature = "osv-import")]
+fn rfc3339_to_rustsec_date(timestamp: &str) -> Result<Date, Error> {
+    timestamp
+        .get(..10)
+        .ok_or_else(|| format_err!(ErrorKind::Parse, "invalid timestamp: {}", timestamp))?
+        .parse()
+}
+
+#[cfg(feature = "osv-export")]
 fn osv_references(references: Vec<Url>) -> Vec<OsvReference> {
     references.into_iter().map(|u| u.into()).collect()
 }
 
+#[cfg(feature = "osv-export")]
 fn guess_url_kind(url: &Url) -> OsvReferenceKind {
     let str = url.as_str();
     if (str.contains("://github.com/") || str.contains("://gitlab.")) && str.contains("/issues/") {
@@ -208,6 +543,7 @@ fn guess_url_kind(url: &Url) -> OsvReferenceKind {
     }
 }
 
+#[cfg(feature = "osv-export")]
 /// Generates the timeline of the bug being introduced and fixed for the
 /// [`affected[].ranges[].events`](https://github.com/ossf/osv-schema/blob/main/schema.md#affectedrangesevents-fields) field.
 fn timeline_for_advisory(versions: &crate::advisory::Versions) -> OsvJsonRange {
@@ -216,29 +552,29 @@ fn timeline_for_advisory(versions: &crate::advisory::Versions) -> OsvJsonRange {
     let mut timeline = Vec::new();
     for range in ranges {
         match range.introduced {
-            Some(ver) => timeline.push(OsvTimelineEvent::Introduced(ver)),
-            None => timeline.push(OsvTimelineEvent::Introduced(
-                semver::Version::parse("0.0.0-0").unwrap(),
-            )),
+            Some(ver) => timeline.push(OsvTimelineEvent::Introduced(ver.to_string())),
+            None => timeline.push(OsvTimelineEvent::Introduced("0.0.0-0".to_owned())),
         }
         #[allow(clippy::single_match)]
         match range.fixed {
-            Some(ver) => timeline.push(OsvTimelineEvent::Fixed(ver)),
+            Some(ver) => timeline.push(OsvTimelineEvent::Fixed(ver.to_string())),
             None => (), // "everything after 'introduced' is affected" is implicit in OSV
         }
     }
     OsvJsonRange {
-        kind: "SEMVER",
+        kind: "SEMVER".to_owned(),
         events: timeline,
     }
 }
 
+#[cfg(feature = "osv-export")]
 fn git2_time_to_rfc3339(git_timestamp: &git2::Time) -> String {
     let unix_timestamp: u64 = git_timestamp.seconds().try_into().unwrap();
     let duration_from_epoch = std::time::Duration::from_secs(unix_timestamp);
     humantime::format_rfc3339(std::time::UNIX_EPOCH.add(duration_from_epoch)).to_string()
 }
 
-fn rustsec_date_to_rfc3339(d: &crate::advisory::Date) -> String {
+#[cfg(feature = "osv-export")]
+fn rustsec_date_to_rfc3339(d: &Date) -> String {
     format!("{}-{:02}-{:02}T12:00:00Z", d.year(), d.month(), d.day())
 }
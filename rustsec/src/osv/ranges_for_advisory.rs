@@ -134,7 +134,7 @@ fn unaffected_to_osv_ranges(
 /// [the SemVer 2.0 precedence rules](https://semver.org/#spec-item-11).
 /// This is not the intutive "increment": this function returns a pre-release version!
 /// E.g. "1.2.3" is transformed to "1.2.4-0".
-fn increment(v: &Version) -> Version {
+pub(super) fn increment(v: &Version) -> Version {
     let mut v = v.clone();
     v.build = Default::default(); // Clear any build metadata, it's not used to determine precedence
     if v.pre.is_empty() {
@@ -0,0 +1,152 @@
+//! Conversion from OSV ranges of affected versions back into the
+//! `patched` and `unaffected` version requirements used by RustSec advisories.
+//! This is the inverse of [`ranges_for_advisory`](super::ranges_for_advisory).
+
+use semver::{Version, VersionReq};
+
+use crate::{advisory::Versions, Error, ErrorKind};
+
+use super::osv_range::OsvRange;
+
+/// Returns the `[versions]` section of an advisory for which exactly
+/// the versions included in the given OSV ranges are affected.
+/// Ranges may be given in any order and may overlap.
+/// Errors if no versions are affected.
+pub fn versions_for_ranges(ranges: &[OsvRange]) -> Result<Versions, Error> {
+    let zero = Version::parse("0.0.0-0").unwrap();
+
+    let mut ranges: Vec<OsvRange> = ranges
+        .iter()
+        .filter(|range| match (&range.introduced, &range.fixed) {
+            (Some(start), Some(end)) => start < end,
+            _ => true,
+        })
+        .map(|range| OsvRange {
+            // "0.0.0-0" is the lowest possible version, i.e. the range is unbounded
+            introduced: range.introduced.clone().filter(|v| *v != zero),
+            fixed: range.fixed.clone(),
+        })
+        .collect();
+
+    if ranges.is_empty() {
+        fail!(ErrorKind::BadParam, "no affected version ranges");
+    }
+
+    // Unbounded ranges go first, then order by the lower bound
+    ranges.sort_by(|a, b| a.introduced.cmp(&b.introduced));
+
+    // Merge overlapping and adjacent ranges
+    let mut merged: Vec<OsvRange> = Vec::new();
+    for range in ranges {
+        if let Some(last) = merged.last_mut() {
+            let overlaps = match (&last.fixed, &range.introduced) {
+                (Some(end), Some(start)) => start <= end,
+                _ => true,
+            };
+
+            if overlaps {
+                last.fixed = match (last.fixed.take(), range.fixed) {
+                    (Some(a), Some(b)) => Some(a.max(b)),
+                    _ => None,
+                };
+                continue;
+            }
+        }
+        merged.push(range);
+    }
+
+    let mut unaffected = Vec::new();
+    let mut patched = Vec::new();
+
+    // Everything before the first range was never affected
+    if let Some(start) = &merged.first().unwrap().introduced {
+        unaffected.push(version_req(&format!("< {}", start))?);
+    }
+
+    // The space between a pair of ranges was patched, then affected again
+    for pair in merged.windows(2) {
+        // Merged ranges are disjoint, so these bounds are always specified
+        let end = pair[0].fixed.as_ref().unwrap();
+        let start = pair[1].introduced.as_ref().unwrap();
+        patched.push(version_req(&format!(">= {}, < {}", end, start))?);
+    }
+
+    // Everything after the last range is patched
+    if let Some(end) = &merged.last().unwrap().fixed {
+        patched.push(version_req(&format!(">= {}", end))?);
+    }
+
+    Versions::new(patched, unaffected)
+}
+
+fn version_req(req: &str) -> Result<VersionReq, Error> {
+    VersionReq::parse(req)
+        .map_err(|e| format_err!(ErrorKind::Version, "invalid version requirement: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::versions_for_ranges;
+    use crate::{
+        advisory::Versions,
+        osv::{ranges_for_advisory, OsvRange},
+    };
+    use semver::{Version, VersionReq};
+
+    fn range(introduced: Option<&str>, fixed: Option<&str>) -> OsvRange {
+        OsvRange {
+            introduced: introduced.map(|v| Version::parse(v).unwrap()),
+            fixed: fixed.map(|v| Version::parse(v).unwrap()),
+        }
+    }
+
+    fn reqs(reqs: &[&str]) -> Vec<VersionReq> {
+        reqs.iter()
+            .map(|req| VersionReq::parse(req).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn single_range() {
+        let versions = versions_for_ranges(&[range(Some("0.0.0-0"), Some("1.2.3"))]).unwrap();
+        assert_eq!(versions.patched(), reqs(&[">= 1.2.3"]).as_slice());
+        assert!(versions.unaffected().is_empty());
+    }
+
+    #[test]
+    fn overlapping_ranges() {
+        let versions = versions_for_ranges(&[
+            range(Some("2.0.0"), Some("2.1.0")),
+            range(Some("0.5.0"), Some("1.0.0")),
+            range(Some("2.0.5"), Some("2.2.0")),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            versions.patched(),
+            reqs(&[">= 1.0.0, < 2.0.0", ">= 2.2.0"]).as_slice()
+        );
+        assert_eq!(versions.unaffected(), reqs(&["< 0.5.0"]).as_slice());
+    }
+
+    #[test]
+    fn round_trip() {
+        let versions =
+            Versions::new(reqs(&[">= 1.2.3, < 2.0.0", "^2.1.4"]), reqs(&["< 1.0.0"])).unwrap();
+
+        let ranges = ranges_for_advisory(&versions);
+        let converted = versions_for_ranges(&ranges).unwrap();
+
+        for v in &[
+            "0.9.0", "1.0.0", "1.2.2", "1.2.3", "2.0.0", "2.1.3", "2.1.4", "3.0.0",
+        ] {
+            let v = Version::parse(v).unwrap();
+            assert_eq!(versions.is_vulnerable(&v), converted.is_vulnerable(&v));
+        }
+    }
+
+    #[test]
+    fn nothing_affected() {
+        assert!(versions_for_ranges(&[]).is_err());
+    }
+}
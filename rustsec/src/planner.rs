@@ -0,0 +1,823 @@
+//! Planning of lockfile-only updates which fix vulnerable dependencies
+//!
+//! Most vulnerabilities are found in transitive dependencies, where the fix
+//! is to update `Cargo.lock` to a patched release which is compatible with
+//! the version requirements of all of the vulnerable package's dependents,
+//! i.e. what `cargo update -p <package> --precise <version>` does.
+
+use crate::{
+    advisory,
+    error::Error,
+    lockfile::{Lockfile, ResolveVersion},
+    package::{self, Package},
+    registry::{self, IndexPackage},
+    vulnerability::Vulnerability,
+    Map,
+};
+use cargo_lock::{
+    dependency::{graph::EdgeDirection, Dependency, Tree},
+    metadata,
+};
+use semver::{Version, VersionReq};
+use std::fmt;
+
+/// Planner for lockfile-only updates of vulnerable dependencies
+pub struct Planner<'a> {
+    /// Lockfile to plan updates for
+    lockfile: &'a Lockfile,
+
+    /// Dependency tree of the lockfile
+    tree: Tree,
+
+    /// Crates.io registry index
+    registry_index: &'a registry::Index,
+}
+
+impl<'a> Planner<'a> {
+    /// Create a new [`Planner`] for the given lockfile, using the given
+    /// crates.io index to look up releases and their version requirements
+    pub fn new(lockfile: &'a Lockfile, registry_index: &'a registry::Index) -> Result<Self, Error> {
+        Ok(Self {
+            lockfile,
+            tree: lockfile.dependency_tree()?,
+            registry_index,
+        })
+    }
+
+    /// Plan updates for the given vulnerabilities.
+    ///
+    /// For each vulnerable package the minimal non-yanked release which fixes
+    /// all of its vulnerabilities and satisfies the version requirements of
+    /// all of its dependents is selected.
+    pub fn plan(&self, vulnerabilities: &[Vulnerability]) -> Plan {
+        // A single update needs to fix all vulnerabilities of a package
+        let mut packages: Map<Dependency, Vec<&Vulnerability>> = Map::new();
+
+        for vulnerability in vulnerabilities {
+            packages
+                .entry(Dependency::from(&vulnerability.package))
+                .or_default()
+                .push(vulnerability);
+        }
+
+        let mut plan = Plan::default();
+
+        for (package, vulnerabilities) in packages {
+            let advisories = vulnerabilities
+                .iter()
+                .map(|vulnerability| vulnerability.advisory.id.clone())
+                .collect();
+
+            match self.select_release(&package, &vulnerabilities) {
+                Ok((release, rewritable)) => plan.updates.push(Update {
+                    package,
+                    version: release.version,
+                    checksum: release.checksum,
+                    rewritable,
+                    advisories,
+                }),
+                Err(reason) => plan.unfixable.push(Unfixable {
+                    package,
+                    advisories,
+                    reason,
+                }),
+            }
+        }
+
+        plan
+    }
+
+    /// Select the release to update the given package to, and determine if
+    /// it can be applied by rewriting the lockfile
+    #[allow(clippy::result_large_err)]
+    fn select_release(
+        &self,
+        package: &Dependency,
+        vulnerabilities: &[&Vulnerability],
+    ) -> Result<(IndexPackage, bool), Reason> {
+        if !is_crates_io(package) {
+            return Err(Reason::NotFromRegistry);
+        }
+
+        let mut releases = self
+            .registry_index
+            .find_all(&package.name)
+            .map_err(|e| Reason::Registry(e.to_string()))?;
+
+        releases.sort_by(|a, b| a.version.cmp(&b.version));
+
+        let current_release = releases
+            .iter()
+            .position(|release| release.version == package.version)
+            .map(|i| releases.remove(i));
+
+        let candidates: Vec<IndexPackage> = releases
+            .into_iter()
+            .filter(|release| {
+                !release.is_yanked
+                    && release.version > package.version
+                    && release.version.pre.is_empty()
+                    && vulnerabilities.iter().all(|vulnerability| {
+                        !vulnerability.versions.is_vulnerable(&release.version)
+                    })
+            })
+            .collect();
+
+        let minimal_version = match candidates.first() {
+            Some(release) => release.version.clone(),
+            None => return Err(Reason::NoPatchedRelease),
+        };
+
+        let requirements = self.dependent_requirements(package)?;
+
+        let selected = candidates.into_iter().find(|release| {
+            requirements.iter().all(|(_, requirement)| {
+                requirement
+                    .as_ref()
+                    .map(|requirement| requirement.matches(&release.version))
+                    .unwrap_or(true)
+            })
+        });
+
+        let release = match selected {
+            Some(release) => release,
+            None => {
+                return Err(requirements
+                    .into_iter()
+                    .find_map(|(dependent, requirement)| {
+                        requirement
+                            .filter(|requirement| !requirement.matches(&minimal_version))
+                            .map(|requirement| Reason::Incompatible {
+                                patched_version: minimal_version.clone(),
+                                dependent,
+                                requirement,
+                            })
+                    })
+                    .unwrap_or(Reason::NoPatchedRelease))
+            }
+        };
+
+        // Requirements of workspace members and other packages which aren't
+        // in the registry are unknown. Leave checking them to `cargo update`
+        // if the release is semver compatible with the locked one, as any
+        // requirement which isn't would need a manifest change anyway.
+        if let Some((dependent, _)) = requirements
+            .iter()
+            .find(|(_, requirement)| requirement.is_none())
+        {
+            return if caret_requirement(&package.version).matches(&release.version) {
+                Ok((release, false))
+            } else {
+                Err(Reason::UnknownRequirement {
+                    dependent: dependent.clone(),
+                })
+            };
+        }
+
+        let rewritable = self.is_rewritable(package, current_release.as_ref(), &release);
+        Ok((release, rewritable))
+    }
+
+    /// Get the version requirements all dependents of the given package have
+    /// on it, or `None` for dependents which aren't in the registry and whose
+    /// requirements are therefore unknown
+    #[allow(clippy::result_large_err)]
+    fn dependent_requirements(
+        &self,
+        package: &Dependency,
+    ) -> Result<Vec<(Dependency, Option<VersionReq>)>, Reason> {
+        let graph = self.tree.graph();
+
+        let node_index = match self.tree.nodes().get(package) {
+            Some(node_index) => *node_index,
+            None => return Ok(vec![]),
+        };
+
+        let mut requirements = vec![];
+
+        for parent_index in graph.neighbors_directed(node_index, EdgeDirection::Incoming) {
+            let dependent = Dependency::from(&graph[parent_index]);
+
+            let requirement = if is_crates_io(&dependent) {
+                let requirement = self
+                    .registry_index
+                    .find(&dependent.name, &dependent.version)
+                    .map_err(|e| Reason::Registry(e.to_string()))?
+                    .dependencies
+                    .into_iter()
+                    .find(|dep| dep.package == package.name && dep.req.matches(&package.version))
+                    .map(|dep| dep.req)
+                    .ok_or_else(|| Reason::UnknownRequirement {
+                        dependent: dependent.clone(),
+                    })?;
+
+                Some(requirement)
+            } else {
+                None
+            };
+
+            requirements.push((dependent, requirement));
+        }
+
+        Ok(requirements)
+    }
+
+    /// Can the update to the given release be applied by rewriting the
+    /// lockfile directly? This is only the case if the release doesn't have
+    /// any dependencies the current release doesn't have, all of its
+    /// dependencies are satisfied by the already locked packages, and the
+    /// release isn't already in the lockfile.
+    fn is_rewritable(
+        &self,
+        package: &Dependency,
+        current_release: Option<&IndexPackage>,
+        release: &IndexPackage,
+    ) -> bool {
+        let current_release = match current_release {
+            Some(current_release) => current_release,
+            None => return false,
+        };
+
+        let locked_package = match self
+            .lockfile
+            .packages
+            .iter()
+            .find(|pkg| Dependency::from(*pkg) == *package)
+        {
+            Some(pkg) => pkg,
+            None => return false,
+        };
+
+        let already_locked = self
+            .lockfile
+            .packages
+            .iter()
+            .any(|pkg| pkg.name == package.name && pkg.version == release.version);
+
+        let new_dependencies = release.dependencies.iter().any(|dep| {
+            !current_release
+                .dependencies
+                .iter()
+                .any(|current_dep| current_dep.package == dep.package)
+        });
+
+        let unsatisfied_dependencies = locked_package.dependencies.iter().any(|locked_dep| {
+            let mut reqs = release
+                .dependencies
+                .iter()
+                .filter(|dep| dep.package == locked_dep.name)
+                .peekable();
+
+            reqs.peek().is_some() && !reqs.any(|dep| dep.req.matches(&locked_dep.version))
+        });
+
+        !already_locked && !new_dependencies && !unsatisfied_dependencies
+    }
+}
+
+/// Planned lockfile-only updates
+#[derive(Clone, Debug, Default)]
+pub struct Plan {
+    /// Updates which fix vulnerabilities
+    pub updates: Vec<Update>,
+
+    /// Vulnerable packages which can't be fixed by updating the lockfile
+    pub unfixable: Vec<Unfixable>,
+}
+
+impl Plan {
+    /// Apply the planned updates to the given lockfile, returning the updates
+    /// which can't be applied by rewriting the lockfile and need to be
+    /// performed with `cargo update` instead.
+    pub fn apply(&self, lockfile: &mut Lockfile) -> Vec<&Update> {
+        let mut pending = vec![];
+
+        for update in &self.updates {
+            if !update.rewritable {
+                pending.push(update);
+                continue;
+            }
+
+            for package in lockfile.packages.iter_mut().chain(lockfile.root.as_mut()) {
+                update.rewrite(package);
+            }
+
+            if lockfile.version == ResolveVersion::V1 {
+                // Checksums are stored in the `[metadata]` table
+                lockfile
+                    .metadata
+                    .remove(&metadata::Key::for_checksum(&update.package));
+            }
+        }
+
+        pending
+    }
+}
+
+/// Update of a vulnerable package to a patched release
+#[derive(Clone, Debug)]
+pub struct Update {
+    /// Vulnerable package (as currently locked)
+    pub package: Dependency,
+
+    /// Patched version to update to
+    pub version: Version,
+
+    /// Checksum of the patched release
+    pub checksum: package::Checksum,
+
+    /// Can this update be applied by rewriting `Cargo.lock` directly?
+    /// Otherwise it needs to be performed with `cargo update`.
+    pub rewritable: bool,
+
+    /// Advisories fixed by this update
+    pub advisories: Vec<advisory::Id>,
+}
+
+impl Update {
+    /// Get the `cargo update` command which performs this update
+    pub fn cargo_update_command(&self) -> String {
+        format!(
+            "cargo update -p {}:{} --precise {}",
+            self.package.name, self.package.version, self.version
+        )
+    }
+
+    /// Rewrite the given locked package, and its reference to the vulnerable
+    /// package (if any), to the patched version
+    fn rewrite(&self, package: &mut Package) {
+        if Dependency::from(&*package) == self.package {
+            package.version = self.version.clone();
+
+            if package.checksum.is_some() {
+                package.checksum = Some(self.checksum.clone());
+            }
+        }
+
+        for dependency in &mut package.dependencies {
+            if *dependency == self.package {
+                dependency.version = self.version.clone();
+            }
+        }
+    }
+}
+
+impl fmt::Display for Update {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} -> {}",
+            self.package.name, self.package.version, self.version
+        )
+    }
+}
+
+/// Vulnerable package which can't be fixed by updating the lockfile
+#[derive(Clone, Debug)]
+pub struct Unfixable {
+    /// Vulnerable package
+    pub package: Dependency,
+
+    /// Advisories which remain unfixed
+    pub advisories: Vec<advisory::Id>,
+
+    /// Reason why the package can't be updated
+    pub reason: Reason,
+}
+
+/// Reasons why a vulnerable package can't be fixed by updating the lockfile
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Reason {
+    /// There's no non-yanked, non-vulnerable release newer than the locked one
+    NoPatchedRelease,
+
+    /// The package is not from the crates.io registry
+    NotFromRegistry,
+
+    /// The crates.io index couldn't be queried
+    Registry(String),
+
+    /// The requirement of a dependent on the vulnerable package couldn't be
+    /// found in the crates.io index, or the dependent isn't in the registry
+    /// and the patched release isn't semver compatible with the locked one
+    UnknownRequirement {
+        /// Dependent package
+        dependent: Dependency,
+    },
+
+    /// The minimal patched release doesn't satisfy the requirement of a
+    /// dependent, and no other patched release does either
+    Incompatible {
+        /// Minimal patched version
+        patched_version: Version,
+
+        /// Dependent package
+        dependent: Dependency,
+
+        /// Requirement of the dependent on the vulnerable package
+        requirement: VersionReq,
+    },
+}
+
+impl Reason {
+    /// Is the package depended upon by a workspace member (or other package
+    /// not in the registry) whose requirement needs to be changed in its
+    /// `Cargo.toml`?
+    pub fn requires_manifest_change(&self) -> bool {
+        match self {
+            Reason::UnknownRequirement { dependent } | Reason::Incompatible { dependent, .. } => {
+                !is_crates_io(dependent)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reason::NoPatchedRelease => write!(f, "no patched release is available"),
+            Reason::NotFromRegistry => write!(f, "package is not from crates.io"),
+            Reason::Registry(msg) => write!(f, "couldn't query crates.io index: {}", msg),
+            Reason::UnknownRequirement { dependent } if is_crates_io(dependent) => write!(
+                f,
+                "couldn't find requirement of {} {} in crates.io index",
+                dependent.name, dependent.version
+            ),
+            Reason::UnknownRequirement { dependent } => write!(
+                f,
+                "no semver compatible patched release, and the requirement of {} {} is unknown",
+                dependent.name, dependent.version
+            ),
+            Reason::Incompatible {
+                patched_version,
+                dependent,
+                requirement,
+            } => write!(
+                f,
+                "patched version {} doesn't satisfy requirement `{}` of {} {}",
+                patched_version, requirement, dependent.name, dependent.version
+            ),
+        }
+    }
+}
+
+/// Is the given package from crates.io?
+fn is_crates_io(package: &Dependency) -> bool {
+    package
+        .source
+        .as_ref()
+        .map(|source| source.is_default_registry())
+        .unwrap_or(false)
+}
+
+/// Default (caret) requirement for the given version, i.e. the versions
+/// which are semver compatible with it
+fn caret_requirement(version: &Version) -> VersionReq {
+    VersionReq::parse(&format!("^{}", version)).unwrap_or(VersionReq::STAR)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Plan, Planner, Reason, Update};
+    use crate::{advisory::Advisory, lockfile::Lockfile, registry, vulnerability::Vulnerability};
+    use cargo_lock::dependency::Dependency;
+    use std::{fs, path::Path};
+
+    const LOCKFILE: &str = r#"# This file is automatically @generated by Cargo.
+# It is not intended for manual editing.
+version = 3
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = [
+ "base64",
+ "smallvec",
+]
+
+[[package]]
+name = "base64"
+version = "0.13.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "904dfeac50f3cdaba28fc6f57fdcddb75f49ed61346676a78c4ffe55877802fd"
+
+[[package]]
+name = "smallvec"
+version = "1.6.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "1a55ca5f3b68e41c979bf8c46a6f1da892ca4db8f94023ce0bd32407573b1ac0"
+"#;
+
+    fn update(lockfile: &Lockfile, name: &str, version: &str, rewritable: bool) -> Update {
+        let package = lockfile
+            .packages
+            .iter()
+            .find(|pkg| pkg.name.as_str() == name)
+            .unwrap();
+
+        Update {
+            package: Dependency::from(package),
+            version: version.parse().unwrap(),
+            checksum: "fe0f37c9e8f3c5a4a66ad655a93c74daac4ad00c441533bf5c6e7990bb42604e"
+                .parse()
+                .unwrap(),
+            rewritable,
+            advisories: vec![],
+        }
+    }
+
+    #[test]
+    fn apply_plan() {
+        let mut lockfile: Lockfile = LOCKFILE.parse().unwrap();
+
+        let plan = Plan {
+            updates: vec![
+                update(&lockfile, "smallvec", "1.6.1", true),
+                update(&lockfile, "base64", "0.13.1", false),
+            ],
+            unfixable: vec![],
+        };
+
+        let pending = plan.apply(&mut lockfile);
+        assert_eq!(pending.len(), 1);
+        assert_eq!(
+            pending[0].cargo_update_command(),
+            "cargo update -p base64:0.13.0 --precise 0.13.1"
+        );
+
+        let smallvec = &lockfile.packages[2];
+        assert_eq!(smallvec.version.to_string(), "1.6.1");
+        assert_eq!(
+            smallvec.checksum.as_ref().unwrap().to_string(),
+            "fe0f37c9e8f3c5a4a66ad655a93c74daac4ad00c441533bf5c6e7990bb42604e"
+        );
+        assert_eq!(
+            lockfile.packages[0].dependencies[1].version.to_string(),
+            "1.6.1"
+        );
+        assert_eq!(lockfile.packages[1].version.to_string(), "0.13.0");
+
+        let reparsed: Lockfile = lockfile.to_string().parse().unwrap();
+        assert_eq!(reparsed.packages[2].version.to_string(), "1.6.1");
+    }
+
+    /// Lockfile whose registry packages are all in the index created by
+    /// `create_index`
+    const PLANNER_LOCKFILE: &str = r#"version = 3
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = [
+ "mid",
+ "other",
+ "pinned",
+ "vuln",
+]
+
+[[package]]
+name = "helper"
+version = "1.0.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "mid"
+version = "1.0.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+dependencies = [
+ "vuln",
+]
+
+[[package]]
+name = "other"
+version = "1.0.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "pinned"
+version = "1.0.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "vuln"
+version = "1.0.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+dependencies = [
+ "helper",
+]
+"#;
+
+    /// Index entry for a release with the given dependencies
+    fn release(name: &str, version: &str, deps: &[(&str, &str)], yanked: bool) -> String {
+        let deps: Vec<String> = deps
+            .iter()
+            .map(|(dep, req)| {
+                format!(
+                    r#"{{"name":"{}","req":"{}","features":[],"optional":false,"default_features":true,"target":null,"kind":"normal"}}"#,
+                    dep, req
+                )
+            })
+            .collect();
+
+        format!(
+            r#"{{"name":"{}","vers":"{}","deps":[{}],"cksum":"{}","features":{{}},"yanked":{}}}"#,
+            name,
+            version,
+            deps.join(","),
+            "00".repeat(32),
+            yanked
+        )
+    }
+
+    /// Create a crates.io index containing the packages in `PLANNER_LOCKFILE`
+    fn create_index(dir: &Path) -> registry::Index {
+        let crates: &[(&str, Vec<String>)] = &[
+            (
+                "he/lp/helper",
+                vec![
+                    release("helper", "1.0.0", &[], false),
+                    release("helper", "1.0.1", &[], false),
+                ],
+            ),
+            (
+                "3/m/mid",
+                vec![release("mid", "1.0.0", &[("vuln", "^1.0")], false)],
+            ),
+            (
+                "ot/he/other",
+                vec![
+                    release("other", "1.0.0", &[], false),
+                    // Adds a dependency which isn't in the lockfile
+                    release("other", "1.1.0", &[("newdep", "^1")], false),
+                ],
+            ),
+            (
+                "pi/nn/pinned",
+                vec![
+                    release("pinned", "1.0.0", &[], false),
+                    release("pinned", "2.0.0", &[], false),
+                ],
+            ),
+            (
+                "vu/ln/vuln",
+                vec![
+                    release("vuln", "1.0.0", &[("helper", "^1")], false),
+                    release("vuln", "1.0.1", &[("helper", "^1")], false),
+                    release("vuln", "1.0.2", &[("helper", "^1")], true),
+                    release("vuln", "1.0.3", &[("helper", "^1")], false),
+                    release("vuln", "1.0.4", &[("helper", "^1")], false),
+                    release("vuln", "2.0.0", &[("helper", "^1")], false),
+                ],
+            ),
+        ];
+
+        for (path, releases) in crates {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, releases.join("\n")).unwrap();
+        }
+
+        registry::Index::open_path(dir).unwrap()
+    }
+
+    fn vulnerability(lockfile: &Lockfile, name: &str, patched: &str) -> Vulnerability {
+        let package = lockfile
+            .packages
+            .iter()
+            .find(|pkg| pkg.name.as_str() == name)
+            .unwrap();
+
+        let advisory: Advisory = format!(
+            "```toml\n[advisory]\nid = \"RUSTSEC-2021-0001\"\npackage = \"{}\"\n\
+             date = \"2021-01-01\"\n\n[versions]\npatched = [\"{}\"]\n```\n\n# Example\n",
+            name, patched
+        )
+        .parse()
+        .unwrap();
+
+        Vulnerability::new(&advisory, package)
+    }
+
+    fn dependency(lockfile: &Lockfile, name: &str) -> Dependency {
+        Dependency::from(
+            lockfile
+                .packages
+                .iter()
+                .find(|pkg| pkg.name.as_str() == name)
+                .unwrap(),
+        )
+    }
+
+    #[test]
+    fn plan_minimal_compatible_release() {
+        let index_dir = tempfile::tempdir().unwrap();
+        let index = create_index(index_dir.path());
+        let lockfile: Lockfile = PLANNER_LOCKFILE.parse().unwrap();
+        let planner = Planner::new(&lockfile, &index).unwrap();
+
+        // 1.0.2 is yanked, so 1.0.3 is the minimal patched release
+        let plan = planner.plan(&[vulnerability(&lockfile, "vuln", ">= 1.0.2")]);
+        assert!(plan.unfixable.is_empty());
+        assert_eq!(plan.updates.len(), 1);
+        assert_eq!(plan.updates[0].to_string(), "vuln 1.0.0 -> 1.0.3");
+
+        // The requirement of `app` (which isn't in the index) is unknown, so
+        // it's left to `cargo update` to check it
+        assert!(!plan.updates[0].rewritable);
+
+        // `helper` is only depended upon by packages in the index
+        let plan = planner.plan(&[vulnerability(&lockfile, "helper", ">= 1.0.1")]);
+        assert!(plan.unfixable.is_empty());
+        assert_eq!(plan.updates[0].to_string(), "helper 1.0.0 -> 1.0.1");
+        assert!(plan.updates[0].rewritable);
+    }
+
+    #[test]
+    fn dependent_requirements() {
+        let index_dir = tempfile::tempdir().unwrap();
+        let index = create_index(index_dir.path());
+        let lockfile: Lockfile = PLANNER_LOCKFILE.parse().unwrap();
+        let planner = Planner::new(&lockfile, &index).unwrap();
+
+        let mut requirements: Vec<_> = planner
+            .dependent_requirements(&dependency(&lockfile, "vuln"))
+            .unwrap()
+            .into_iter()
+            .map(|(dependent, req)| {
+                (
+                    dependent.name.as_str().to_owned(),
+                    req.map(|req| req.to_string()),
+                )
+            })
+            .collect();
+        requirements.sort();
+
+        // The requirement of `app` (which isn't in the index) is unknown
+        assert_eq!(
+            requirements,
+            [
+                ("app".to_owned(), None),
+                ("mid".to_owned(), Some("^1.0".to_owned()))
+            ]
+        );
+    }
+
+    #[test]
+    fn plan_cargo_update() {
+        let index_dir = tempfile::tempdir().unwrap();
+        let index = create_index(index_dir.path());
+        let lockfile: Lockfile = PLANNER_LOCKFILE.parse().unwrap();
+        let planner = Planner::new(&lockfile, &index).unwrap();
+
+        // `other` 1.1.0 depends on a package which isn't in the lockfile
+        let plan = planner.plan(&[vulnerability(&lockfile, "other", ">= 1.1.0")]);
+        assert_eq!(plan.updates.len(), 1);
+        assert!(!plan.updates[0].rewritable);
+        assert_eq!(
+            plan.updates[0].cargo_update_command(),
+            "cargo update -p other:1.0.0 --precise 1.1.0"
+        );
+
+        let mut updated = lockfile.clone();
+        assert_eq!(plan.apply(&mut updated).len(), 1);
+        assert_eq!(updated.to_string(), lockfile.to_string());
+    }
+
+    #[test]
+    fn plan_incompatible_release() {
+        let index_dir = tempfile::tempdir().unwrap();
+        let index = create_index(index_dir.path());
+        let lockfile: Lockfile = PLANNER_LOCKFILE.parse().unwrap();
+        let planner = Planner::new(&lockfile, &index).unwrap();
+
+        let plan = planner.plan(&[vulnerability(&lockfile, "pinned", ">= 2.0.0")]);
+        assert!(plan.updates.is_empty());
+        assert_eq!(plan.unfixable.len(), 1);
+
+        let reason = &plan.unfixable[0].reason;
+        assert_eq!(
+            reason,
+            &Reason::UnknownRequirement {
+                dependent: dependency(&lockfile, "app"),
+            }
+        );
+        assert!(reason.requires_manifest_change());
+
+        // `mid` requires `vuln` ^1.0
+        let plan = planner.plan(&[vulnerability(&lockfile, "vuln", ">= 2.0.0")]);
+        let reason = &plan.unfixable[0].reason;
+        assert_eq!(
+            reason,
+            &Reason::Incompatible {
+                patched_version: "2.0.0".parse().unwrap(),
+                dependent: dependency(&lockfile, "mid"),
+                requirement: "^1.0".parse().unwrap(),
+            }
+        );
+        assert!(!reason.requires_manifest_change());
+
+        let plan = planner.plan(&[vulnerability(&lockfile, "helper", ">= 9.0.0")]);
+        assert_eq!(plan.unfixable[0].reason, Reason::NoPatchedRelease);
+    }
+}
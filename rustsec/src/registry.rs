@@ -4,6 +4,8 @@ use crate::{
     error::{Error, ErrorKind},
     package,
 };
+use semver::VersionReq;
+use std::{convert::TryFrom, path::PathBuf};
 
 /// Crates.io registry index (local copy)
 pub struct Index(crates_index::Index);
@@ -37,6 +39,22 @@ impl Index {
         Ok(Index(index))
     }
 
+    /// Open a copy of the crates.io index which has been checked out at the
+    /// given path (e.g. a local mirror), without fetching it
+    pub fn open_path(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        let path = path.into();
+
+        if !path.is_dir() {
+            fail!(
+                ErrorKind::Registry,
+                "no registry index found at {}",
+                path.display()
+            );
+        }
+
+        Ok(Index(crates_index::Index::new(path)))
+    }
+
     /// Find an entry for a particular package in the index
     pub fn find(
         &self,
@@ -65,7 +83,23 @@ impl Index {
                 )
             })?;
 
-        Ok(IndexPackage::from(crate_release))
+        IndexPackage::try_from(crate_release)
+    }
+
+    /// Find all releases of a particular package in the index.
+    ///
+    /// Releases whose versions can't be parsed are skipped.
+    pub fn find_all(&self, package: &package::Name) -> Result<Vec<IndexPackage>, Error> {
+        let crate_releases = self
+            .0
+            .crate_(package.as_str())
+            .ok_or_else(|| format_err!(ErrorKind::NotFound, "no results for: {}", &package))?;
+
+        Ok(crate_releases
+            .versions()
+            .iter()
+            .filter_map(|crate_release| IndexPackage::try_from(crate_release).ok())
+            .collect())
     }
 }
 
@@ -79,14 +113,57 @@ pub struct IndexPackage {
 
     /// Is this package yanked?
     pub is_yanked: bool,
+
+    /// SHA-256 checksum of the package's `.crate` file
+    pub checksum: package::Checksum,
+
+    /// Normal and build dependencies of this release (dev-dependencies are
+    /// never part of a dependent's `Cargo.lock` and are omitted)
+    pub dependencies: Vec<IndexDependency>,
 }
 
-impl From<&crates_index::Version> for IndexPackage {
-    fn from(crate_release: &crates_index::Version) -> IndexPackage {
-        IndexPackage {
-            package: crate_release.name().parse().unwrap(),
-            version: crate_release.version().parse().unwrap(),
+impl TryFrom<&crates_index::Version> for IndexPackage {
+    type Error = Error;
+
+    fn try_from(crate_release: &crates_index::Version) -> Result<IndexPackage, Error> {
+        let invalid = || {
+            format_err!(
+                ErrorKind::Version,
+                "invalid release in index: {} {}",
+                crate_release.name(),
+                crate_release.version()
+            )
+        };
+
+        Ok(IndexPackage {
+            package: crate_release.name().parse().map_err(|_| invalid())?,
+            version: crate_release.version().parse().map_err(|_| invalid())?,
             is_yanked: crate_release.is_yanked(),
-        }
+            checksum: package::Checksum::from(*crate_release.checksum()),
+            dependencies: crate_release
+                .dependencies()
+                .iter()
+                .filter(|dep| dep.kind() != crates_index::DependencyKind::Dev)
+                .filter_map(|dep| {
+                    Some(IndexDependency {
+                        package: dep.crate_name().parse().ok()?,
+                        req: dep.requirement().parse().ok()?,
+                        is_optional: dep.is_optional(),
+                    })
+                })
+                .collect(),
+        })
     }
 }
+
+/// Dependency of a release in the crates.io registry
+pub struct IndexDependency {
+    /// Name of the package depended upon (i.e. not the renamed name)
+    pub package: package::Name,
+
+    /// Version requirement for the dependency
+    pub req: VersionReq,
+
+    /// Is this an optional dependency?
+    pub is_optional: bool,
+}
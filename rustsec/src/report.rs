@@ -4,17 +4,28 @@
 //! but also provide the core reporting functionality used in general.
 
 use crate::{
-    advisory,
+    advisory::{self, Date},
+    cargo_lock::dependency::Dependency,
     database::{scope, Database, Query},
     lockfile::Lockfile,
     map,
-    platforms::target::{Arch, OS},
+    package::{self, Package},
+    platforms::{
+        target::{Arch, OS},
+        Platform, PlatformReq,
+    },
     vulnerability::Vulnerability,
     warning::{self, Warning},
     Map,
 };
-use serde::{Deserialize, Serialize};
+use semver::{Version, VersionReq};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeSet;
 
+#[cfg(feature = "cargo-metadata")]
+use crate::cargo_metadata::{CargoMetadata, DependencyKind};
+#[cfg(feature = "git")]
+use crate::database::Source;
 #[cfg(feature = "git")]
 use std::time::SystemTime;
 
@@ -36,6 +47,14 @@ pub struct Report {
 
     /// Warnings about dependencies (from e.g. informational advisories)
     pub warnings: WarningInfo,
+
+    /// Advisories which were ignored
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignored: Vec<IgnoredAdvisory>,
+
+    /// Ignore rules which have expired (and were therefore not applied)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub expired_ignores: Vec<IgnoreRule>,
 }
 
 impl Report {
@@ -43,13 +62,51 @@ impl Report {
     pub fn generate(db: &Database, lockfile: &Lockfile, settings: &Settings) -> Self {
         let package_scope = settings.package_scope.as_ref().cloned().unwrap_or_default();
 
-        let vulnerabilities = db
-            .query_vulnerabilities(lockfile, &settings.query(), package_scope)
+        let target_platforms = settings.target_platforms();
+        let today = Date::today();
+        let mut ignored = vec![];
+
+        let mut vulnerabilities =
+            db.query_vulnerabilities(lockfile, &settings.query(), package_scope);
+
+        if let Some(rust_version) = &settings.rust_version {
+            vulnerabilities
+                .extend(db.query_toolchain_vulnerabilities(rust_version, &settings.query()));
+        }
+
+        let vulnerabilities = vulnerabilities
             .into_iter()
-            .filter(|vuln| !settings.ignore.contains(&vuln.advisory.id))
+            .filter(
+                |vuln| match settings.find_ignore(&vuln.advisory.id, &vuln.package, &today) {
+                    Some(ignore) => {
+                        ignored.push(ignore);
+                        false
+                    }
+                    None => true,
+                },
+            )
+            .map(|mut vuln| {
+                vuln.platforms = target_platforms
+                    .iter()
+                    .filter(|platform| match &vuln.affected {
+                        Some(affected) => affected.affects_platform(platform),
+                        None => true,
+                    })
+                    .map(|platform| platform.target_triple.to_owned())
+                    .collect();
+
+                vuln
+            })
             .collect();
 
-        let warnings = find_warnings(db, lockfile, settings);
+        let warnings = find_warnings_and_ignores(db, lockfile, settings, &today, &mut ignored);
+
+        let expired_ignores = settings
+            .ignore_rules
+            .iter()
+            .filter(|rule| rule.is_expired(&today))
+            .cloned()
+            .collect();
 
         Self {
             #[cfg(feature = "git")]
@@ -58,7 +115,70 @@ impl Report {
             settings: settings.clone(),
             vulnerabilities: VulnerabilityInfo::new(vulnerabilities),
             warnings,
+            ignored,
+            expired_ignores,
+        }
+    }
+}
+
+#[cfg(feature = "cargo-metadata")]
+impl Report {
+    /// Generate a report for the given advisory database and lockfile,
+    /// excluding findings for dev-only or build-only dependencies as
+    /// configured in the settings, according to the given `cargo metadata`.
+    pub fn generate_with_metadata(
+        db: &Database,
+        lockfile: &Lockfile,
+        metadata: &CargoMetadata,
+        settings: &Settings,
+    ) -> Self {
+        let mut report = Self::generate(db, lockfile, settings);
+
+        let mut excluded_kinds = vec![];
+
+        if settings.exclude_dev {
+            excluded_kinds.push(DependencyKind::Dev);
+        }
+
+        if settings.exclude_build {
+            excluded_kinds.push(DependencyKind::Build);
         }
+
+        if excluded_kinds.is_empty() {
+            return report;
+        }
+
+        let is_included = |package: &Package| {
+            metadata
+                .package(package)
+                .map(|info| !info.is_only(&excluded_kinds))
+                .unwrap_or(true)
+        };
+
+        report.vulnerabilities = VulnerabilityInfo::new(
+            report
+                .vulnerabilities
+                .list
+                .into_iter()
+                .filter(|vuln| is_included(&vuln.package))
+                .collect(),
+        );
+
+        report.warnings = report
+            .warnings
+            .into_iter()
+            .map(|(kind, warnings)| {
+                let warnings: Vec<_> = warnings
+                    .into_iter()
+                    .filter(|warning| is_included(&warning.package))
+                    .collect();
+
+                (kind, warnings)
+            })
+            .filter(|(_, warnings)| !warnings.is_empty())
+            .collect();
+
+        report
     }
 }
 
@@ -71,17 +191,46 @@ pub struct Settings {
     /// Operating system
     pub target_os: Option<OS>,
 
+    /// Target platforms (i.e. target triples, optionally with wildcards)
+    /// to report affected platforms for
+    #[serde(default)]
+    pub target_platforms: Vec<PlatformReq>,
+
     /// Severity threshold to alert at
     pub severity: Option<advisory::Severity>,
 
     /// List of advisory IDs to ignore
     pub ignore: Vec<advisory::Id>,
 
+    /// Rules for ignoring advisories, with optional reasons, expiry dates
+    /// and package scopes
+    #[serde(default)]
+    pub ignore_rules: Vec<IgnoreRule>,
+
     /// Types of informational advisories to generate warnings for
     pub informational_warnings: Vec<advisory::Informational>,
 
     /// Scope of packages which should be considered for audit
     pub package_scope: Option<scope::Package>,
+
+    /// Version of the Rust toolchain to audit against advisories in the
+    /// `rust` collection (if any)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rust_version: Option<Version>,
+
+    /// Exclude findings for packages which are only used as dev-dependencies.
+    ///
+    /// Requires dependency kinds from `cargo metadata` (see
+    /// `Report::generate_with_metadata`).
+    #[serde(default)]
+    pub exclude_dev: bool,
+
+    /// Exclude findings for packages which are only used as build-dependencies.
+    ///
+    /// Requires dependency kinds from `cargo metadata` (see
+    /// `Report::generate_with_metadata`).
+    #[serde(default)]
+    pub exclude_build: bool,
 }
 
 impl Settings {
@@ -99,12 +248,194 @@ impl Settings {
             query = query.target_os(target_os);
         }
 
+        for platform in self.target_platforms() {
+            query = query.target_platform(platform);
+        }
+
         if let Some(severity) = self.severity {
             query = query.severity(severity);
         }
 
         query
     }
+
+    /// Find how the given advisory is ignored for the given package (if it is)
+    /// according to the rules which haven't expired by the given date
+    fn find_ignore(
+        &self,
+        id: &advisory::Id,
+        package: &Package,
+        today: &Date,
+    ) -> Option<IgnoredAdvisory> {
+        if self.ignore.contains(id) {
+            return Some(IgnoredAdvisory::new(id, package, None));
+        }
+
+        self.ignore_rules
+            .iter()
+            .find(|rule| !rule.is_expired(today) && rule.matches(id, package))
+            .map(|rule| IgnoredAdvisory::new(id, package, rule.reason.clone()))
+    }
+
+    /// Get all known platforms matching the configured target platforms
+    pub fn target_platforms(&self) -> Vec<&'static Platform> {
+        let mut platforms: Vec<&'static Platform> = vec![];
+
+        for req in &self.target_platforms {
+            for platform in req.matching_platforms() {
+                let platform = Platform::find(platform.target_triple).unwrap();
+
+                if !platforms.contains(&platform) {
+                    platforms.push(platform);
+                }
+            }
+        }
+
+        platforms
+    }
+}
+
+/// Rule for ignoring an advisory, optionally only until a given date and/or
+/// for a particular package and versions of it.
+///
+/// Deserializes from either an advisory ID or a table, e.g.:
+///
+/// ```toml
+/// ignore = [
+///     "RUSTSEC-2019-0001",
+///     { id = "RUSTSEC-2020-0002", reason = "not exploitable", expires = "2021-06-30" },
+/// ]
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct IgnoreRule {
+    /// Advisory to ignore
+    pub id: advisory::Id,
+
+    /// Why the advisory is ignored
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+
+    /// Date on which this rule expires (i.e. stops being applied)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires: Option<Date>,
+
+    /// Only ignore the advisory for this package
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package: Option<package::Name>,
+
+    /// Only ignore the advisory for versions of the package matching this
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<VersionReq>,
+}
+
+impl IgnoreRule {
+    /// Create a rule which unconditionally ignores the given advisory
+    pub fn new(id: advisory::Id) -> Self {
+        Self {
+            id,
+            reason: None,
+            expires: None,
+            package: None,
+            version: None,
+        }
+    }
+
+    /// Is this rule just an advisory ID, without a reason, expiry or scope?
+    pub fn is_unconditional(&self) -> bool {
+        self.reason.is_none()
+            && self.expires.is_none()
+            && self.package.is_none()
+            && self.version.is_none()
+    }
+
+    /// Has this rule expired as of the given date?
+    pub fn is_expired(&self, today: &Date) -> bool {
+        match &self.expires {
+            Some(expires) => expires <= today,
+            None => false,
+        }
+    }
+
+    /// Does this rule apply to the given advisory for the given package?
+    pub fn matches(&self, id: &advisory::Id, package: &Package) -> bool {
+        if &self.id != id {
+            return false;
+        }
+
+        if let Some(name) = &self.package {
+            if name != &package.name {
+                return false;
+            }
+        }
+
+        match &self.version {
+            Some(req) => req.matches(&package.version),
+            None => true,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for IgnoreRule {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// Ignore rule as written in a configuration file
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Id(advisory::Id),
+            Rule(RuleRepr),
+        }
+
+        /// Ignore rule as a table
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct RuleRepr {
+            id: advisory::Id,
+            reason: Option<String>,
+            expires: Option<Date>,
+            package: Option<package::Name>,
+            version: Option<VersionReq>,
+        }
+
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Id(id) => Self::new(id),
+            Repr::Rule(rule) => Self {
+                id: rule.id,
+                reason: rule.reason,
+                expires: rule.expires,
+                package: rule.package,
+                version: rule.version,
+            },
+        })
+    }
+}
+
+/// Advisory which was ignored for a particular package
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct IgnoredAdvisory {
+    /// ID of the ignored advisory
+    pub id: advisory::Id,
+
+    /// Name of the package the advisory was ignored for
+    pub package: package::Name,
+
+    /// Version of the package the advisory was ignored for
+    pub version: Version,
+
+    /// Reason given for ignoring the advisory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl IgnoredAdvisory {
+    /// Create a record of the given advisory being ignored for the given package
+    fn new(id: &advisory::Id, package: &Package, reason: Option<String>) -> Self {
+        Self {
+            id: id.clone(),
+            package: package.name.clone(),
+            version: package.version.clone(),
+            reason,
+        }
+    }
 }
 
 /// Information about the advisory database
@@ -122,6 +453,10 @@ pub struct DatabaseInfo {
     /// Date when the advisory database was last committed to
     #[serde(rename = "last-updated", with = "humantime_serde")]
     pub last_updated: Option<SystemTime>,
+
+    /// Databases which were merged into the advisory database
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<SourceInfo>,
 }
 
 #[cfg(feature = "git")]
@@ -132,6 +467,40 @@ impl DatabaseInfo {
             advisory_count: db.iter().count(),
             last_commit: db.latest_commit().map(|c| c.commit_id.clone()),
             last_updated: db.latest_commit().map(|c| c.timestamp),
+            sources: db.sources().iter().map(SourceInfo::new).collect(),
+        }
+    }
+}
+
+/// Information about a database merged into the advisory database
+#[cfg(feature = "git")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SourceInfo {
+    /// Name of the database (e.g. the URL of its git repository)
+    pub name: String,
+
+    /// Number of advisories merged from the database
+    #[serde(rename = "advisory-count")]
+    pub advisory_count: usize,
+
+    /// Git commit hash for the last commit to the database
+    #[serde(rename = "last-commit")]
+    pub last_commit: Option<String>,
+
+    /// Date when the database was last committed to
+    #[serde(rename = "last-updated", with = "humantime_serde")]
+    pub last_updated: Option<SystemTime>,
+}
+
+#[cfg(feature = "git")]
+impl SourceInfo {
+    /// Create information about a merged database
+    pub fn new(source: &Source) -> Self {
+        Self {
+            name: source.name.clone(),
+            advisory_count: source.advisory_count,
+            last_commit: source.latest_commit.as_ref().map(|c| c.commit_id.clone()),
+            last_updated: source.latest_commit.as_ref().map(|c| c.timestamp),
         }
     }
 }
@@ -182,6 +551,17 @@ pub type WarningInfo = Map<warning::Kind, Vec<Warning>>;
 
 /// Find warnings from the given advisory [`Database`] and [`Lockfile`]
 pub fn find_warnings(db: &Database, lockfile: &Lockfile, settings: &Settings) -> WarningInfo {
+    find_warnings_and_ignores(db, lockfile, settings, &Date::today(), &mut vec![])
+}
+
+/// Find warnings, recording the advisories which were ignored
+fn find_warnings_and_ignores(
+    db: &Database,
+    lockfile: &Lockfile,
+    settings: &Settings,
+    today: &Date,
+    ignored: &mut Vec<IgnoredAdvisory>,
+) -> WarningInfo {
     let query = settings.query().informational(true);
     let package_scope = settings.package_scope.as_ref().cloned().unwrap_or_default();
 
@@ -191,7 +571,8 @@ pub fn find_warnings(db: &Database, lockfile: &Lockfile, settings: &Settings) ->
     for advisory_vuln in db.query_vulnerabilities(lockfile, &query, package_scope) {
         let advisory = &advisory_vuln.advisory;
 
-        if settings.ignore.contains(&advisory.id) {
+        if let Some(ignore) = settings.find_ignore(&advisory.id, &advisory_vuln.package, today) {
+            ignored.push(ignore);
             continue;
         }
 
@@ -228,3 +609,146 @@ pub fn find_warnings(db: &Database, lockfile: &Lockfile, settings: &Settings) ->
 
     warnings
 }
+
+/// Differences between the reports for two versions of a lockfile, e.g.
+/// before and after a change
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Diff {
+    /// Findings which are only in the new report
+    pub added: Findings,
+
+    /// Findings which are only in the old report
+    pub removed: Findings,
+
+    /// Findings which are in both reports
+    pub unchanged: Findings,
+
+    /// Packages which are only in the new lockfile
+    pub added_packages: Vec<Package>,
+
+    /// Packages which are only in the old lockfile
+    pub removed_packages: Vec<Package>,
+}
+
+impl Diff {
+    /// Generate reports for the old and new lockfiles and compare them
+    pub fn generate(
+        db: &Database,
+        old_lockfile: &Lockfile,
+        new_lockfile: &Lockfile,
+        settings: &Settings,
+    ) -> Self {
+        let old_report = Report::generate(db, old_lockfile, settings);
+        let new_report = Report::generate(db, new_lockfile, settings);
+        Self::new(&old_report, old_lockfile, &new_report, new_lockfile)
+    }
+
+    /// Compare the reports previously generated for the old and new lockfiles
+    pub fn new(
+        old_report: &Report,
+        old_lockfile: &Lockfile,
+        new_report: &Report,
+        new_lockfile: &Lockfile,
+    ) -> Self {
+        let (added_vulnerabilities, removed_vulnerabilities, unchanged_vulnerabilities) = compare(
+            &old_report.vulnerabilities.list.iter().collect::<Vec<_>>(),
+            &new_report.vulnerabilities.list.iter().collect::<Vec<_>>(),
+            |vuln| (vuln.advisory.id.clone(), Dependency::from(&vuln.package)),
+        );
+
+        let (added_warnings, removed_warnings, unchanged_warnings) = compare(
+            &old_report.warnings.values().flatten().collect::<Vec<_>>(),
+            &new_report.warnings.values().flatten().collect::<Vec<_>>(),
+            |warning| {
+                (
+                    warning.kind,
+                    warning
+                        .advisory
+                        .as_ref()
+                        .map(|advisory| advisory.id.clone()),
+                    Dependency::from(&warning.package),
+                )
+            },
+        );
+
+        let (added_packages, removed_packages, _) = compare(
+            &old_lockfile.packages.iter().collect::<Vec<_>>(),
+            &new_lockfile.packages.iter().collect::<Vec<_>>(),
+            |package| Dependency::from(package),
+        );
+
+        Self {
+            added: Findings {
+                vulnerabilities: added_vulnerabilities,
+                warnings: added_warnings,
+            },
+            removed: Findings {
+                vulnerabilities: removed_vulnerabilities,
+                warnings: removed_warnings,
+            },
+            unchanged: Findings {
+                vulnerabilities: unchanged_vulnerabilities,
+                warnings: unchanged_warnings,
+            },
+            added_packages,
+            removed_packages,
+        }
+    }
+
+    /// Was the given package newly pulled in, i.e. is it absent from the old
+    /// lockfile (rather than only affected by a new finding)?
+    pub fn is_added_package(&self, package: &Package) -> bool {
+        let dependency = Dependency::from(package);
+
+        self.added_packages
+            .iter()
+            .any(|added| Dependency::from(added) == dependency)
+    }
+}
+
+/// Vulnerabilities and warnings found in a report
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Findings {
+    /// Vulnerabilities
+    pub vulnerabilities: Vec<Vulnerability>,
+
+    /// Warnings
+    pub warnings: Vec<Warning>,
+}
+
+impl Findings {
+    /// Are there no findings?
+    pub fn is_empty(&self) -> bool {
+        self.vulnerabilities.is_empty() && self.warnings.is_empty()
+    }
+}
+
+/// Sort items into those which were added, removed or unchanged, identifying
+/// them by the given key
+fn compare<T, K>(old: &[&T], new: &[&T], key: impl Fn(&T) -> K) -> (Vec<T>, Vec<T>, Vec<T>)
+where
+    T: Clone,
+    K: Ord,
+{
+    let old_keys: BTreeSet<K> = old.iter().map(|item| key(item)).collect();
+    let new_keys: BTreeSet<K> = new.iter().map(|item| key(item)).collect();
+
+    let mut added = vec![];
+    let mut unchanged = vec![];
+
+    for &item in new {
+        if old_keys.contains(&key(item)) {
+            unchanged.push(item.clone());
+        } else {
+            added.push(item.clone());
+        }
+    }
+
+    let removed = old
+        .iter()
+        .filter(|item| !new_keys.contains(&key(item)))
+        .map(|&item| item.clone())
+        .collect();
+
+    (added, removed, unchanged)
+}
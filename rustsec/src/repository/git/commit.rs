@@ -2,16 +2,19 @@
 
 use crate::{
     error::{Error, ErrorKind},
-    repository::{git::Repository, signature::Signature},
+    repository::git::Repository,
 };
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+#[cfg(feature = "signatures")]
+use crate::repository::signature::{KeyId, Keyring, Signature};
+
 /// Number of days after which the repo will be considered stale
 /// (90 days)
 const STALE_AFTER: Duration = Duration::from_secs(90 * 86400);
 
 /// Information about a commit to the Git repository
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Commit {
     /// ID (i.e. SHA-1 hash) of the latest commit
     pub commit_id: String,
@@ -25,12 +28,12 @@ pub struct Commit {
     /// Commit time in number of seconds since the UNIX epoch
     pub timestamp: SystemTime,
 
-    /// Signature on the commit (mandatory for Repository::fetch)
-    // TODO: actually verify signatures
-    pub signature: Option<Signature>,
+    /// Raw (ASCII-armored) signature on the commit (mandatory for
+    /// Repository::fetch, and verified by [`Repository::fetch_verified`])
+    pub signature: Option<Vec<u8>>,
 
     /// Signed data to verify along with this commit
-    signed_data: Option<Vec<u8>>,
+    pub(crate) signed_data: Option<Vec<u8>>,
 }
 
 impl Commit {
@@ -46,6 +49,11 @@ impl Commit {
             )
         })?;
 
+        Self::from_oid(repo, oid)
+    }
+
+    /// Get information about the commit with the given ID
+    pub(crate) fn from_oid(repo: &Repository, oid: git2::Oid) -> Result<Self, Error> {
         let commit_id = oid.to_string();
         let commit_object = repo.repo.find_object(oid, Some(git2::ObjectType::Commit))?;
         let commit = commit_object.as_commit().unwrap();
@@ -57,10 +65,7 @@ impl Commit {
             .to_owned();
 
         let (signature, signed_data) = match repo.repo.extract_signature(&oid, None) {
-            Ok((ref sig, ref data)) => (
-                Some(Signature::from_bytes(sig)?),
-                Some(data.as_ref().into()),
-            ),
+            Ok((ref sig, ref data)) => (Some(sig.as_ref().into()), Some(data.as_ref().into())),
             _ => (None, None),
         };
 
@@ -87,6 +92,36 @@ impl Commit {
         self.signed_data.as_ref().map(|bytes| bytes.as_ref())
     }
 
+    /// Verify the signature on this commit using a key from the given
+    /// keyring, returning the ID of the key which signed it
+    #[cfg(feature = "signatures")]
+    pub fn verify_signature(&self, keyring: &Keyring) -> Result<KeyId, Error> {
+        match (&self.signature, self.raw_signed_bytes()) {
+            (Some(signature), Some(signed_data)) => {
+                // Signatures are only parsed when verifying them, so commits
+                // with e.g. SSH signatures can still be used without a keyring
+                Signature::from_bytes(signature)
+                    .and_then(|signature| signature.verify(signed_data, keyring))
+                    .map_err(|e| {
+                        format_err!(
+                            ErrorKind::Signature,
+                            "commit {} ({}): {}",
+                            self.commit_id,
+                            self.summary,
+                            e
+                        )
+                    })
+            }
+            _ => fail!(
+                ErrorKind::Signature,
+                "no signature on commit {}: {} ({})",
+                self.commit_id,
+                self.summary,
+                self.author
+            ),
+        }
+    }
+
     /// Reset the repository's state to match this commit
     pub(crate) fn reset(&self, repo: &Repository) -> Result<(), Error> {
         let commit_object = repo.repo.find_object(
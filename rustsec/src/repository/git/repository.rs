@@ -7,6 +7,9 @@ use crate::{
 };
 use std::path::{Path, PathBuf};
 
+#[cfg(feature = "signatures")]
+use crate::repository::signature::Keyring;
+
 /// Directory under `~/.cargo` where the advisory-db repo will be kept
 const ADVISORY_DB_DIRECTORY: &str = "advisory-db";
 
@@ -46,6 +49,37 @@ impl Repository {
         into_path: P,
         ensure_fresh: bool,
     ) -> Result<Self, Error> {
+        Self::fetch_and_verify(url, into_path, ensure_fresh, |_| Ok(()))
+    }
+
+    /// Create a new [`GitRepository`] with the given URL and path, requiring
+    /// that the latest commit is signed by a key from the given keyring.
+    ///
+    /// Fails with [`ErrorKind::Signature`] if the signature can't be verified.
+    #[cfg(feature = "signatures")]
+    pub fn fetch_verified<P: Into<PathBuf>>(
+        url: &str,
+        into_path: P,
+        ensure_fresh: bool,
+        keyring: &Keyring,
+    ) -> Result<Self, Error> {
+        Self::fetch_and_verify(url, into_path, ensure_fresh, |commit| {
+            commit.verify_signature(keyring).map(|_| ())
+        })
+    }
+
+    /// Fetch the repository, checking the latest commit with the given
+    /// function before checking it out
+    fn fetch_and_verify<P, F>(
+        url: &str,
+        into_path: P,
+        ensure_fresh: bool,
+        verify: F,
+    ) -> Result<Self, Error>
+    where
+        P: Into<PathBuf>,
+        F: Fn(&Commit) -> Result<(), Error>,
+    {
         if !url.starts_with("https://") {
             fail!(
                 ErrorKind::BadParam,
@@ -85,54 +119,33 @@ impl Repository {
             fetch_opts.remote_callbacks(callbacks);
             fetch_opts.proxy_options(proxy_opts);
 
-            if path.exists() {
-                let repo = git2::Repository::open(&path)?;
-                let refspec = LOCAL_REF.to_owned() + ":" + REMOTE_REF;
-
-                // Fetch remote packfiles and update tips
-                let mut remote = repo.remote_anonymous(url)?;
-                remote.fetch(&[refspec.as_str()], Some(&mut fetch_opts), None)?;
-
-                // Get the current remote tip (as an updated local reference)
-                let remote_main_ref = repo.find_reference(REMOTE_REF)?;
-                let remote_target = remote_main_ref.target().unwrap();
-
-                // Set the local main ref to match the remote
-                match repo.find_reference(LOCAL_REF) {
-                    Ok(mut local_main_ref) => {
-                        local_main_ref.set_target(
-                            remote_target,
-                            &format!(
-                                "rustsec: moving `main` to {}: {}",
-                                REMOTE_REF, &remote_target
-                            ),
-                        )?;
-                    }
-                    Err(e) if e.code() == git2::ErrorCode::NotFound => {
-                        // TODO(tarcieri): remove this workaround after repos have migrated
-                        let old_ref = repo.find_reference("refs/heads/master")?;
-                        git2::Branch::wrap(old_ref).rename("main", true)?;
-                        Self::fetch(url, &path, ensure_fresh)?;
-                    }
-                    Err(e) => {
-                        return Err(e.into());
-                    }
-                };
+            // Fetch into the remote ref only, so nothing is checked out (or
+            // moved to) before the fetched commit has been verified
+            let repo = if path.exists() {
+                git2::Repository::open(&path)?
             } else {
-                git2::build::RepoBuilder::new()
-                    .fetch_options(fetch_opts)
-                    .clone(url, &path)?;
-            }
+                git2::Repository::init(&path)?
+            };
+
+            let refspec = LOCAL_REF.to_owned() + ":" + REMOTE_REF;
+
+            // Fetch remote packfiles and update tips
+            let mut remote = repo.remote_anonymous(url)?;
+            remote.fetch(&[refspec.as_str()], Some(&mut fetch_opts), None)?;
 
             Ok(())
         })?;
 
         let repo = Self::open(path)?;
-        let latest_commit = repo.latest_commit()?;
-        latest_commit.reset(&repo)?;
+
+        // Get the current remote tip (as an updated local reference)
+        let remote_target = repo.repo.find_reference(REMOTE_REF)?.target().unwrap();
+        let latest_commit = Commit::from_oid(&repo, remote_target)?;
+
+        // Don't check out unverified commits
+        verify(&latest_commit)?;
 
         // Any commits we fetch should always be signed
-        // TODO: verify signatures against GitHub's public key
         if latest_commit.signature.is_none() {
             fail!(
                 ErrorKind::Repo,
@@ -143,6 +156,25 @@ impl Repository {
             );
         }
 
+        // Set the local main ref to match the remote
+        repo.repo.reference(
+            LOCAL_REF,
+            remote_target,
+            true,
+            &format!(
+                "rustsec: moving `main` to {}: {}",
+                REMOTE_REF, &remote_target
+            ),
+        )?;
+        repo.repo.set_head(LOCAL_REF)?;
+
+        // TODO(tarcieri): remove this workaround after repos have migrated
+        if let Ok(mut old_ref) = repo.repo.find_reference("refs/heads/master") {
+            old_ref.delete()?;
+        }
+
+        latest_commit.reset(&repo)?;
+
         // Ensure that the upstream repository hasn't gone stale
         if ensure_fresh && !latest_commit.is_fresh() {
             fail!(
@@ -1,21 +1,417 @@
 //! Git commit signatures
 
-use crate::error::Error;
+#[cfg(feature = "signatures")]
+mod keyring;
+mod packet;
+
+#[cfg(feature = "signatures")]
+pub use self::keyring::{Keyring, PublicKey};
+
+use self::packet::{Reader, TAG_SIGNATURE};
+use crate::error::{Error, ErrorKind};
+use std::{
+    fmt,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+#[cfg(feature = "signatures")]
+use self::keyring::{KeyMaterial, ALGORITHM_EDDSA};
+
+/// Signature type for signatures over binary documents
+const SIGNATURE_BINARY: u8 = 0x00;
+
+/// Signature creation time subpacket
+const SUBPACKET_CREATION_TIME: u8 = 2;
+
+/// Signature expiration time subpacket
+const SUBPACKET_EXPIRATION_TIME: u8 = 3;
+
+/// Issuer key ID signature subpacket
+const SUBPACKET_ISSUER: u8 = 16;
+
+/// Issuer fingerprint signature subpacket
+const SUBPACKET_ISSUER_FINGERPRINT: u8 = 33;
+
+/// Subpackets which are understood when marked as critical
+const KNOWN_SUBPACKETS: &[u8] = &[
+    SUBPACKET_CREATION_TIME,
+    SUBPACKET_EXPIRATION_TIME,
+    SUBPACKET_ISSUER,
+    SUBPACKET_ISSUER_FINGERPRINT,
+];
 
 /// Digital signatures (in OpenPGP format) on commits to the repository
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Signature(Vec<u8>);
+pub struct Signature {
+    /// Raw (ASCII-armored) signature
+    bytes: Vec<u8>,
+
+    /// Public key algorithm used to create the signature
+    public_key_algorithm: u8,
+
+    /// Hash algorithm used to create the signature
+    hash_algorithm: u8,
+
+    /// Hashed portion of the signature packet, which is appended to the
+    /// signed data when computing the digest
+    hashed_data: Vec<u8>,
+
+    /// Leftmost 16 bits of the signed digest
+    hash_prefix: [u8; 2],
+
+    /// Algorithm-specific signature values
+    values: Vec<Vec<u8>>,
+
+    /// ID of the key which created the signature
+    issuer: Option<KeyId>,
+
+    /// Fingerprint of the key which created the signature
+    issuer_fingerprint: Option<Vec<u8>>,
+
+    /// Time the signature was created (in seconds since the UNIX epoch)
+    created: Option<u32>,
+
+    /// Number of seconds after its creation the signature expires (if ever)
+    expires_after: Option<u32>,
+}
 
 impl Signature {
     /// Parse a signature from a Git commit
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
-        // TODO: actually verify the signature is well-structured
-        Ok(Signature(bytes.into()))
+        let data = packet::dearmor(bytes, "PGP SIGNATURE")?;
+        let packets = packet::parse_packets(&data)?;
+
+        let body = match packets.as_slice() {
+            [packet] if packet.tag == TAG_SIGNATURE => packet.body,
+            _ => fail!(
+                ErrorKind::Parse,
+                "expected a single OpenPGP signature packet"
+            ),
+        };
+
+        let mut reader = Reader::new(body);
+
+        let version = reader.u8()?;
+        if version != 4 {
+            fail!(
+                ErrorKind::Parse,
+                "unsupported signature version: {}",
+                version
+            );
+        }
+
+        let signature_type = reader.u8()?;
+        if signature_type != SIGNATURE_BINARY {
+            fail!(
+                ErrorKind::Parse,
+                "unsupported signature type: {:#04x}",
+                signature_type
+            );
+        }
+
+        let public_key_algorithm = reader.u8()?;
+        let hash_algorithm = reader.u8()?;
+
+        let hashed_len = reader.u16()? as usize;
+        let hashed_subpackets = reader.take(hashed_len)?;
+        let hashed_data = body[..6 + hashed_len].to_vec();
+
+        let unhashed_len = reader.u16()? as usize;
+        let unhashed_subpackets = reader.take(unhashed_len)?;
+
+        let mut hash_prefix = [0u8; 2];
+        hash_prefix.copy_from_slice(reader.take(2)?);
+
+        let mut values = vec![];
+        while !reader.remaining().is_empty() {
+            values.push(reader.mpi()?.to_vec());
+        }
+
+        let mut issuer = None;
+        let mut issuer_fingerprint = None;
+        let mut created = None;
+        let mut expires_after = None;
+
+        // Only trust the issuer fingerprint and times if they're in the hashed area
+        for (hashed, subpackets) in &[(true, hashed_subpackets), (false, unhashed_subpackets)] {
+            for Subpacket {
+                critical,
+                kind,
+                data,
+            } in parse_subpackets(subpackets)?
+            {
+                // Signatures with unknown critical subpackets are in error
+                // (RFC 4880 Section 5.2.3.1)
+                if critical && !KNOWN_SUBPACKETS.contains(&kind) {
+                    fail!(
+                        ErrorKind::Parse,
+                        "unsupported critical signature subpacket: {}",
+                        kind
+                    );
+                }
+
+                match kind {
+                    SUBPACKET_CREATION_TIME if *hashed && data.len() == 4 => {
+                        created = Some(Reader::new(data).u32()?);
+                    }
+                    SUBPACKET_EXPIRATION_TIME if *hashed && data.len() == 4 => {
+                        expires_after = Some(Reader::new(data).u32()?);
+                    }
+                    SUBPACKET_ISSUER if data.len() == 8 && issuer.is_none() => {
+                        let mut id = [0u8; 8];
+                        id.copy_from_slice(data);
+                        issuer = Some(KeyId(u64::from_be_bytes(id)));
+                    }
+                    SUBPACKET_ISSUER_FINGERPRINT if *hashed && data.len() == 21 => {
+                        issuer_fingerprint = Some(data[1..].to_vec());
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        Ok(Signature {
+            bytes: bytes.into(),
+            public_key_algorithm,
+            hash_algorithm,
+            hashed_data,
+            hash_prefix,
+            values,
+            issuer,
+            issuer_fingerprint,
+            created,
+            expires_after,
+        })
+    }
+
+    /// Get the ID of the key which created this signature (if known)
+    pub fn issuer(&self) -> Option<KeyId> {
+        self.issuer.or_else(|| {
+            self.issuer_fingerprint.as_ref().map(|fingerprint| {
+                let mut id = [0u8; 8];
+                id.copy_from_slice(&fingerprint[12..]);
+                KeyId(u64::from_be_bytes(id))
+            })
+        })
+    }
+
+    /// Get the time this signature expires (if ever)
+    pub fn expires(&self) -> Option<SystemTime> {
+        // An expiration time of zero means the signature doesn't expire
+        let expires_after = self.expires_after.filter(|&secs| secs != 0)?;
+
+        // Signatures without a creation time are treated as already expired
+        let created = self.created.unwrap_or(0);
+
+        Some(UNIX_EPOCH + Duration::from_secs(u64::from(created) + u64::from(expires_after)))
+    }
+
+    /// Verify this signature over the given signed data (see
+    /// [`Commit::raw_signed_bytes`]) using a key from the given keyring.
+    ///
+    /// Returns the ID of the key which created the signature on success.
+    ///
+    /// [`Commit::raw_signed_bytes`]: crate::repository::git::Commit::raw_signed_bytes
+    #[cfg(feature = "signatures")]
+    pub fn verify(&self, signed_data: &[u8], keyring: &Keyring) -> Result<KeyId, Error> {
+        let key = match &self.issuer_fingerprint {
+            Some(fingerprint) => keyring.find_by_fingerprint(fingerprint),
+            None => self.issuer.and_then(|id| keyring.find_by_key_id(id)),
+        };
+
+        let key = match (key, self.issuer()) {
+            (Some(key), _) => key,
+            (None, Some(id)) => fail!(
+                ErrorKind::Signature,
+                "signing key {} is not in the trusted keyring",
+                id
+            ),
+            (None, None) => fail!(ErrorKind::Signature, "signature has no issuer"),
+        };
+
+        let digest = self.digest(signed_data)?;
+
+        if digest[..2] != self.hash_prefix {
+            fail!(
+                ErrorKind::Signature,
+                "signature by {} doesn't match signed data",
+                key.key_id()
+            );
+        }
+
+        let valid = match (&key.material, self.public_key_algorithm) {
+            (KeyMaterial::Rsa { n, e }, 1..=3) => self.verify_rsa(n, e, &digest)?,
+            (KeyMaterial::Ed25519(public_key), ALGORITHM_EDDSA) => {
+                self.verify_ed25519(public_key, &digest)?
+            }
+            (KeyMaterial::Unsupported(algorithm), _) => fail!(
+                ErrorKind::Signature,
+                "key {} uses an unsupported public key algorithm: {}",
+                key.key_id(),
+                algorithm
+            ),
+            _ => fail!(
+                ErrorKind::Signature,
+                "public key algorithm {} doesn't match key {}",
+                self.public_key_algorithm,
+                key.key_id()
+            ),
+        };
+
+        if !valid {
+            fail!(
+                ErrorKind::Signature,
+                "invalid signature by key {}",
+                key.key_id()
+            );
+        }
+
+        if let Some(expires) = self.expires() {
+            if expires <= SystemTime::now() {
+                fail!(
+                    ErrorKind::Signature,
+                    "signature by key {} expired at {}",
+                    key.key_id(),
+                    humantime::format_rfc3339_seconds(expires)
+                );
+            }
+        }
+
+        Ok(key.key_id())
+    }
+
+    /// Compute the digest covered by this signature
+    #[cfg(feature = "signatures")]
+    fn digest(&self, signed_data: &[u8]) -> Result<Vec<u8>, Error> {
+        use sha2::{Digest, Sha256, Sha384, Sha512};
+
+        fn hash<D: Digest>(signed_data: &[u8], hashed_data: &[u8]) -> Vec<u8> {
+            let mut hasher = D::new();
+            hasher.update(signed_data);
+            hasher.update(hashed_data);
+
+            // v4 signature trailer
+            hasher.update([0x04, 0xFF]);
+            hasher.update((hashed_data.len() as u32).to_be_bytes());
+            hasher.finalize().to_vec()
+        }
+
+        Ok(match self.hash_algorithm {
+            8 => hash::<Sha256>(signed_data, &self.hashed_data),
+            9 => hash::<Sha384>(signed_data, &self.hashed_data),
+            10 => hash::<Sha512>(signed_data, &self.hashed_data),
+            other => fail!(
+                ErrorKind::Signature,
+                "unsupported signature hash algorithm: {}",
+                other
+            ),
+        })
+    }
+
+    /// Verify an RSA PKCS#1 v1.5 signature over the given digest
+    #[cfg(feature = "signatures")]
+    fn verify_rsa(&self, n: &[u8], e: &[u8], digest: &[u8]) -> Result<bool, Error> {
+        use rsa::{BigUint, Pkcs1v15Sign, RsaPublicKey};
+        use sha2::{Sha256, Sha384, Sha512};
+
+        let scheme = match self.hash_algorithm {
+            8 => Pkcs1v15Sign::new::<Sha256>(),
+            9 => Pkcs1v15Sign::new::<Sha384>(),
+            _ => Pkcs1v15Sign::new::<Sha512>(),
+        };
+
+        let key = RsaPublicKey::new(BigUint::from_bytes_be(n), BigUint::from_bytes_be(e))
+            .map_err(|e| format_err!(ErrorKind::Signature, "invalid RSA key: {}", e))?;
+
+        let value = match self.values.as_slice() {
+            [value] => value,
+            _ => fail!(ErrorKind::Parse, "malformed RSA signature"),
+        };
+
+        Ok(key.verify(scheme, digest, value).is_ok())
+    }
+
+    /// Verify an Ed25519 signature over the given digest
+    #[cfg(feature = "signatures")]
+    fn verify_ed25519(&self, public_key: &[u8; 32], digest: &[u8]) -> Result<bool, Error> {
+        use ed25519_dalek::Signature as Ed25519Signature;
+        use std::convert::TryFrom;
+
+        let (r, s) = match self.values.as_slice() {
+            [r, s] if r.len() <= 32 && s.len() <= 32 => (r, s),
+            _ => fail!(ErrorKind::Parse, "malformed Ed25519 signature"),
+        };
+
+        // MPIs have their leading zeroes stripped
+        let mut bytes = [0u8; 64];
+        bytes[32 - r.len()..32].copy_from_slice(r);
+        bytes[64 - s.len()..].copy_from_slice(s);
+
+        let key = ed25519_dalek::PublicKey::from_bytes(public_key)
+            .map_err(|e| format_err!(ErrorKind::Signature, "invalid Ed25519 key: {}", e))?;
+
+        let signature = match Ed25519Signature::try_from(&bytes[..]) {
+            Ok(signature) => signature,
+            Err(_) => return Ok(false),
+        };
+
+        Ok(key.verify_strict(digest, &signature).is_ok())
     }
 }
 
 impl AsRef<[u8]> for Signature {
     fn as_ref(&self) -> &[u8] {
-        self.0.as_ref()
+        self.bytes.as_ref()
+    }
+}
+
+/// OpenPGP key ID (i.e. the low 64 bits of a v4 fingerprint)
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct KeyId(pub u64);
+
+impl fmt::Display for KeyId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016X}", self.0)
+    }
+}
+
+/// Signature subpacket
+struct Subpacket<'a> {
+    /// Must the subpacket be understood to verify the signature?
+    critical: bool,
+
+    /// Type of subpacket
+    kind: u8,
+
+    /// Subpacket data
+    data: &'a [u8],
+}
+
+/// Parse signature subpackets
+fn parse_subpackets(bytes: &[u8]) -> Result<Vec<Subpacket<'_>>, Error> {
+    let mut reader = Reader::new(bytes);
+    let mut subpackets = vec![];
+
+    while !reader.remaining().is_empty() {
+        let len = match reader.u8()? {
+            len @ 0..=191 => len as usize,
+            first @ 192..=254 => ((first as usize - 192) << 8) + reader.u8()? as usize + 192,
+            255 => reader.u32()? as usize,
+        };
+
+        if len == 0 {
+            fail!(ErrorKind::Parse, "empty signature subpacket");
+        }
+
+        let data = reader.take(len)?;
+
+        // The high bit of the type flags a subpacket as critical
+        subpackets.push(Subpacket {
+            critical: data[0] & 0x80 != 0,
+            kind: data[0] & 0x7f,
+            data: &data[1..],
+        });
     }
+
+    Ok(subpackets)
 }
@@ -0,0 +1,196 @@
+//! Keyrings of trusted OpenPGP public keys
+
+use super::{
+    packet::{self, Reader},
+    KeyId,
+};
+use crate::{
+    error::{Error, ErrorKind},
+    fs,
+};
+use sha1::{Digest, Sha1};
+use std::path::Path;
+
+/// Public-key packet tag
+const TAG_PUBLIC_KEY: u8 = 6;
+
+/// Public-subkey packet tag
+const TAG_PUBLIC_SUBKEY: u8 = 14;
+
+/// OpenPGP public key algorithm identifier for RSA (encrypt or sign)
+const ALGORITHM_RSA: u8 = 1;
+
+/// OpenPGP public key algorithm identifier for RSA (sign only)
+const ALGORITHM_RSA_SIGN_ONLY: u8 = 3;
+
+/// OpenPGP public key algorithm identifier for EdDSA
+pub(super) const ALGORITHM_EDDSA: u8 = 22;
+
+/// Object identifier of the Ed25519 curve as used by OpenPGP
+const ED25519_OID: &[u8] = &[0x2B, 0x06, 0x01, 0x04, 0x01, 0xDA, 0x47, 0x0F, 0x01];
+
+/// Keyring of OpenPGP public keys which are trusted to sign commits
+/// to the advisory database.
+///
+/// Keyrings are loaded from either binary or ASCII-armored transferable
+/// public keys (e.g. the output of `gpg --export --armor`), and include all
+/// primary keys and subkeys they contain. Keys are trusted as-is: the
+/// self-signatures binding user IDs and subkeys aren't checked.
+#[derive(Clone, Debug, Default)]
+pub struct Keyring {
+    keys: Vec<PublicKey>,
+}
+
+impl Keyring {
+    /// Load a keyring from the file at the given path
+    pub fn load_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+
+        Self::from_bytes(&fs::read(path)?).map_err(|e| {
+            format_err!(
+                ErrorKind::Signature,
+                "couldn't load keyring from {}: {}",
+                path.display(),
+                e
+            )
+        })
+    }
+
+    /// Parse a keyring from binary or ASCII-armored OpenPGP public keys
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let dearmored;
+
+        let bytes = if bytes.starts_with(b"-----BEGIN") {
+            dearmored = packet::dearmor(bytes, "PGP PUBLIC KEY BLOCK")?;
+            dearmored.as_slice()
+        } else {
+            bytes
+        };
+
+        let keys = packet::parse_packets(bytes)?
+            .into_iter()
+            .filter(|packet| packet.tag == TAG_PUBLIC_KEY || packet.tag == TAG_PUBLIC_SUBKEY)
+            .map(|packet| PublicKey::from_packet_body(packet.body))
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        if keys.is_empty() {
+            fail!(ErrorKind::Parse, "no public keys found in keyring");
+        }
+
+        Ok(Self { keys })
+    }
+
+    /// Iterate over the keys in this keyring
+    pub fn keys(&self) -> impl Iterator<Item = &PublicKey> {
+        self.keys.iter()
+    }
+
+    /// Find the key with the given (v4) fingerprint
+    pub fn find_by_fingerprint(&self, fingerprint: &[u8]) -> Option<&PublicKey> {
+        self.keys
+            .iter()
+            .find(|key| key.fingerprint[..] == *fingerprint)
+    }
+
+    /// Find the key with the given 64-bit key ID
+    pub fn find_by_key_id(&self, key_id: KeyId) -> Option<&PublicKey> {
+        self.keys.iter().find(|key| key.key_id() == key_id)
+    }
+}
+
+/// OpenPGP (v4) public key
+#[derive(Clone, Debug)]
+pub struct PublicKey {
+    /// Fingerprint of the key
+    fingerprint: [u8; 20],
+
+    /// Key material
+    pub(super) material: KeyMaterial,
+}
+
+impl PublicKey {
+    /// Parse the body of a public key or public subkey packet
+    fn from_packet_body(body: &[u8]) -> Result<Self, Error> {
+        let mut reader = Reader::new(body);
+
+        let version = reader.u8()?;
+        if version != 4 {
+            fail!(
+                ErrorKind::Parse,
+                "unsupported public key version: {}",
+                version
+            );
+        }
+
+        let _creation_time = reader.u32()?;
+
+        let material = match reader.u8()? {
+            ALGORITHM_RSA | ALGORITHM_RSA_SIGN_ONLY => KeyMaterial::Rsa {
+                n: reader.mpi()?.to_vec(),
+                e: reader.mpi()?.to_vec(),
+            },
+            ALGORITHM_EDDSA => {
+                let oid_len = reader.u8()? as usize;
+                let oid = reader.take(oid_len)?;
+                let point = reader.mpi()?;
+
+                // Native point format: 0x40 prefix followed by the key
+                match point.split_first() {
+                    Some((0x40, key)) if oid == ED25519_OID && key.len() == 32 => {
+                        let mut bytes = [0u8; 32];
+                        bytes.copy_from_slice(key);
+                        KeyMaterial::Ed25519(bytes)
+                    }
+                    _ => KeyMaterial::Unsupported(ALGORITHM_EDDSA),
+                }
+            }
+            other => KeyMaterial::Unsupported(other),
+        };
+
+        // v4 fingerprint: SHA-1 of the framed public key packet body
+        let mut hasher = Sha1::new();
+        hasher.update([0x99]);
+        hasher.update((body.len() as u16).to_be_bytes());
+        hasher.update(body);
+
+        let mut fingerprint = [0u8; 20];
+        fingerprint.copy_from_slice(&hasher.finalize());
+
+        Ok(Self {
+            fingerprint,
+            material,
+        })
+    }
+
+    /// Get the fingerprint of this key
+    pub fn fingerprint(&self) -> &[u8] {
+        &self.fingerprint
+    }
+
+    /// Get the key ID of this key
+    pub fn key_id(&self) -> KeyId {
+        let mut id = [0u8; 8];
+        id.copy_from_slice(&self.fingerprint[12..]);
+        KeyId(u64::from_be_bytes(id))
+    }
+}
+
+/// Public key material
+#[derive(Clone, Debug)]
+pub(super) enum KeyMaterial {
+    /// RSA public key
+    Rsa {
+        /// Modulus
+        n: Vec<u8>,
+
+        /// Public exponent
+        e: Vec<u8>,
+    },
+
+    /// Ed25519 public key
+    Ed25519([u8; 32]),
+
+    /// Key using an unsupported algorithm (which can't be used to verify
+    /// signatures)
+    Unsupported(u8),
+}
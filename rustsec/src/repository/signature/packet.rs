@@ -0,0 +1,218 @@
+//! Minimal OpenPGP (RFC 4880) message parsing: ASCII armor, packet framing
+//! and the primitive types used inside packets.
+
+use crate::error::{Error, ErrorKind};
+
+/// Signature packet tag
+pub(super) const TAG_SIGNATURE: u8 = 2;
+
+/// Initial value of the CRC-24 checksum used by ASCII armor
+const CRC24_INIT: u32 = 0x00B7_04CE;
+
+/// Generator of the CRC-24 checksum used by ASCII armor
+const CRC24_POLY: u32 = 0x0186_4CFB;
+
+/// OpenPGP packet
+#[derive(Debug)]
+pub(super) struct Packet<'a> {
+    /// Packet tag
+    pub(super) tag: u8,
+
+    /// Packet body
+    pub(super) body: &'a [u8],
+}
+
+/// Decode the binary data in the ASCII-armored block(s) of the given kind
+/// (e.g. `PGP SIGNATURE`) contained in the given bytes.
+///
+/// Multiple concatenated blocks are decoded into a single packet stream.
+pub(super) fn dearmor(bytes: &[u8], kind: &str) -> Result<Vec<u8>, Error> {
+    let text = std::str::from_utf8(bytes)?;
+    let begin = format!("-----BEGIN {}-----", kind);
+    let end = format!("-----END {}-----", kind);
+
+    let mut lines = text.lines().map(str::trim);
+    let mut decoded = vec![];
+    let mut found = false;
+
+    while lines.any(|line| line == begin) {
+        found = true;
+
+        // Skip armor headers (e.g. `Version: ...`), which end with a blank line
+        let mut body = String::new();
+        let mut in_headers = true;
+        let mut checksum = None;
+        let mut terminated = false;
+
+        for line in &mut lines {
+            if line == end {
+                terminated = true;
+                break;
+            }
+
+            if in_headers {
+                if line.is_empty() {
+                    in_headers = false;
+                } else if !line.contains(": ") {
+                    // No headers present
+                    in_headers = false;
+                    body.push_str(line);
+                }
+            } else if let Some(crc) = line.strip_prefix('=') {
+                checksum = Some(crc.to_owned());
+            } else {
+                body.push_str(line);
+            }
+        }
+
+        if !terminated {
+            fail!(
+                ErrorKind::Parse,
+                "unterminated ASCII armor: missing `{}`",
+                end
+            );
+        }
+
+        let data = base64::decode(&body)
+            .map_err(|e| format_err!(ErrorKind::Parse, "invalid ASCII armor: {}", e))?;
+
+        if let Some(checksum) = checksum {
+            let expected = base64::decode(&checksum)
+                .map_err(|e| format_err!(ErrorKind::Parse, "invalid armor checksum: {}", e))?;
+
+            let actual = crc24(&data).to_be_bytes();
+
+            if expected != actual[1..] {
+                fail!(ErrorKind::Parse, "ASCII armor checksum mismatch");
+            }
+        }
+
+        decoded.extend_from_slice(&data);
+    }
+
+    if !found {
+        fail!(ErrorKind::Parse, "missing `{}`", begin);
+    }
+
+    Ok(decoded)
+}
+
+/// Parse the given bytes as a sequence of OpenPGP packets
+pub(super) fn parse_packets(mut bytes: &[u8]) -> Result<Vec<Packet<'_>>, Error> {
+    let mut packets = vec![];
+
+    while !bytes.is_empty() {
+        let mut reader = Reader::new(bytes);
+        let header = reader.u8()?;
+
+        if header & 0x80 == 0 {
+            fail!(
+                ErrorKind::Parse,
+                "invalid OpenPGP packet header: {:#04x}",
+                header
+            );
+        }
+
+        let (tag, len) = if header & 0x40 != 0 {
+            // New format packet
+            let len = match reader.u8()? {
+                len @ 0..=191 => len as usize,
+                first @ 192..=223 => ((first as usize - 192) << 8) + reader.u8()? as usize + 192,
+                255 => reader.u32()? as usize,
+                _ => fail!(
+                    ErrorKind::Parse,
+                    "unsupported partial body length in OpenPGP packet"
+                ),
+            };
+
+            (header & 0x3f, len)
+        } else {
+            // Old format packet
+            let len = match header & 0x03 {
+                0 => reader.u8()? as usize,
+                1 => reader.u16()? as usize,
+                2 => reader.u32()? as usize,
+                _ => reader.remaining().len(),
+            };
+
+            ((header >> 2) & 0x0f, len)
+        };
+
+        let body = reader.take(len)?;
+        packets.push(Packet { tag, body });
+        bytes = reader.remaining();
+    }
+
+    Ok(packets)
+}
+
+/// Reader for the primitive types OpenPGP packets are composed of
+#[derive(Debug)]
+pub(super) struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    /// Create a new reader for the given bytes
+    pub(super) fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Bytes which haven't been read yet
+    pub(super) fn remaining(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Read the given number of bytes
+    pub(super) fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if len > self.bytes.len() {
+            fail!(ErrorKind::Parse, "truncated OpenPGP packet");
+        }
+
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    /// Read a single octet
+    pub(super) fn u8(&mut self) -> Result<u8, Error> {
+        Ok(self.take(1)?[0])
+    }
+
+    /// Read a big endian two-octet scalar
+    pub(super) fn u16(&mut self) -> Result<u16, Error> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    /// Read a big endian four-octet scalar
+    pub(super) fn u32(&mut self) -> Result<u32, Error> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Read a multiprecision integer, returning its big endian magnitude
+    pub(super) fn mpi(&mut self) -> Result<&'a [u8], Error> {
+        let bits = self.u16()? as usize;
+        self.take((bits + 7) >> 3)
+    }
+}
+
+/// Compute the CRC-24 checksum of the given data (RFC 4880 Section 6.1)
+fn crc24(data: &[u8]) -> u32 {
+    let mut crc = CRC24_INIT;
+
+    for &byte in data {
+        crc ^= (byte as u32) << 16;
+
+        for _ in 0..8 {
+            crc <<= 1;
+
+            if crc & 0x0100_0000 != 0 {
+                crc ^= CRC24_POLY;
+            }
+        }
+    }
+
+    crc & 0x00FF_FFFF
+}
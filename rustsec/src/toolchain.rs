@@ -0,0 +1,138 @@
+//! Versions of the Rust toolchain, for auditing against advisories in the
+//! `rust` collection (e.g. for `std` or `rustdoc`).
+//!
+//! The version can be parsed from the output of `rustc -V` or from a
+//! `rust-toolchain` / `rust-toolchain.toml` file which pins a specific
+//! release. This module only parses them: running `rustc` is left to the
+//! caller.
+//!
+//! Pre-release versions (e.g. nightly and beta toolchains) are treated as the
+//! last stable release before them, since advisories are filed against stable
+//! releases and a fix may not have landed in a pre-release yet.
+
+use crate::error::{Error, ErrorKind};
+use semver::Version;
+use serde::Deserialize;
+
+/// Parse the toolchain version from the output of `rustc -V`,
+/// e.g. `rustc 1.52.1 (9bc8c42bb 2021-05-09)`
+pub fn parse_rustc_version(output: &str) -> Result<Version, Error> {
+    let version = output
+        .trim()
+        .strip_prefix("rustc ")
+        .and_then(|rest| rest.split_whitespace().next())
+        .ok_or_else(|| format_err!(ErrorKind::Parse, "invalid rustc version: {}", output))?;
+
+    parse_version(version)
+        .ok_or_else(|| format_err!(ErrorKind::Parse, "invalid rustc version: {}", output))
+}
+
+/// Parse the toolchain version pinned by a `rust-toolchain.toml` file (or a
+/// legacy `rust-toolchain` file containing only the channel).
+///
+/// Returns `None` if the file names a channel rather than a specific
+/// release (e.g. `stable` or `nightly-2021-05-01`).
+pub fn parse_toolchain_file(contents: &str) -> Result<Option<Version>, Error> {
+    /// `rust-toolchain.toml` file
+    #[derive(Deserialize)]
+    struct ToolchainFile {
+        toolchain: ToolchainSection,
+    }
+
+    /// `[toolchain]` section of a `rust-toolchain.toml` file
+    #[derive(Deserialize)]
+    struct ToolchainSection {
+        channel: Option<String>,
+    }
+
+    let channel = if contents.contains('[') {
+        let file: ToolchainFile = toml::from_str(contents)?;
+
+        match file.toolchain.channel {
+            Some(channel) => channel,
+            None => return Ok(None),
+        }
+    } else {
+        contents.trim().to_owned()
+    };
+
+    // Channels may include a host triple, e.g. `1.52.1-x86_64-unknown-linux-gnu`
+    let release = channel.split('-').next().unwrap_or_default();
+    Ok(parse_version(release))
+}
+
+/// Parse a toolchain release (e.g. `1.52.1`, `1.52` or `1.54.0-nightly`).
+///
+/// Pre-releases are mapped to the previous stable minor release, e.g.
+/// `1.54.0-nightly` is parsed as `1.53.0`.
+fn parse_version(release: &str) -> Option<Version> {
+    let mut release = release.splitn(2, '-');
+    let version = release.next()?;
+    let pre_release = release.next().is_some();
+    let mut parts = version.split('.').map(|part| part.parse::<u64>().ok());
+
+    let major = parts.next()??;
+    let minor = parts.next()??;
+    let patch = match parts.next() {
+        Some(patch) => patch?,
+        None => 0,
+    };
+
+    if parts.next().is_some() {
+        return None;
+    }
+
+    if pre_release {
+        Some(Version::new(major, minor.saturating_sub(1), 0))
+    } else {
+        Some(Version::new(major, minor, patch))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_rustc_version, parse_toolchain_file};
+    use semver::Version;
+
+    #[test]
+    fn rustc_version() {
+        assert_eq!(
+            parse_rustc_version("rustc 1.52.1 (9bc8c42bb 2021-05-09)\n").unwrap(),
+            Version::new(1, 52, 1)
+        );
+        assert_eq!(
+            parse_rustc_version("rustc 1.55.0-nightly (868c702d0 2021-06-30)").unwrap(),
+            Version::new(1, 54, 0)
+        );
+        assert_eq!(
+            parse_rustc_version("rustc 1.54.0-beta.3 (6b3a6e4e4 2021-07-03)").unwrap(),
+            Version::new(1, 53, 0)
+        );
+        assert!(parse_rustc_version("cargo 1.52.0").is_err());
+    }
+
+    #[test]
+    fn toolchain_file() {
+        assert_eq!(
+            parse_toolchain_file("[toolchain]\nchannel = \"1.52.1\"\n").unwrap(),
+            Some(Version::new(1, 52, 1))
+        );
+        assert_eq!(
+            parse_toolchain_file("1.48\n").unwrap(),
+            Some(Version::new(1, 48, 0))
+        );
+        assert_eq!(
+            parse_toolchain_file("1.52.1-x86_64-unknown-linux-gnu").unwrap(),
+            Some(Version::new(1, 52, 1))
+        );
+        assert_eq!(parse_toolchain_file("nightly-2021-05-01").unwrap(), None);
+        assert_eq!(
+            parse_toolchain_file("[toolchain]\nchannel = \"stable\"\n").unwrap(),
+            None
+        );
+        assert_eq!(
+            parse_toolchain_file("[toolchain]\ncomponents = [\"rustfmt\"]\n").unwrap(),
+            None
+        );
+    }
+}
@@ -6,6 +6,7 @@ use crate::{
     package::Package,
 };
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// A vulnerable package and the associated advisory
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -21,6 +22,15 @@ pub struct Vulnerability {
 
     /// Vulnerable package
     pub package: Package,
+
+    /// Target triples of the audited platforms which are affected
+    /// (if any target platforms were specified)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub platforms: Vec<String>,
+
+    /// Are any of the affected functions referenced? (if analyzed)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reachability: Option<Reachability>,
 }
 
 impl Vulnerability {
@@ -31,6 +41,8 @@ impl Vulnerability {
             versions: advisory.versions.clone(),
             affected: advisory.affected.clone(),
             package: package.clone(),
+            platforms: vec![],
+            reachability: None,
         }
     }
 
@@ -54,3 +66,43 @@ impl Vulnerability {
         })
     }
 }
+
+/// Whether the functions affected by a vulnerability are referenced by the
+/// crates which depend on the vulnerable package
+#[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Reachability {
+    /// At least one affected function is referenced
+    #[serde(rename = "reachable")]
+    Reachable,
+
+    /// None of the affected functions are referenced.
+    ///
+    /// References are found syntactically, so this doesn't prove the affected
+    /// functions are never called: calls through trait objects, function
+    /// pointers or code generated by macros aren't detected.
+    #[serde(rename = "not-referenced")]
+    NotReferenced,
+
+    /// Reachability couldn't be determined (e.g. the advisory doesn't list
+    /// affected functions, the sources of a dependent crate are unavailable,
+    /// or a dependent renames or publicly re-exports the vulnerable crate)
+    #[serde(rename = "unknown")]
+    Unknown,
+}
+
+impl Reachability {
+    /// Get a string representing this reachability
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Reachability::Reachable => "reachable",
+            Reachability::NotReferenced => "not referenced",
+            Reachability::Unknown => "unknown",
+        }
+    }
+}
+
+impl fmt::Display for Reachability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
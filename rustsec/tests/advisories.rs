@@ -78,7 +78,10 @@ fn parse_cvss_vector_string() {
         rustsec::advisory::Severity::Critical
     );
 
-    let cvss = advisory.metadata.cvss.unwrap();
+    let cvss = match advisory.metadata.cvss.unwrap() {
+        cvss::Cvss::V3(vector) => vector.base,
+        other => panic!("unexpected CVSS version: {}", other),
+    };
     assert_eq!(cvss.av.unwrap(), cvss::v3::base::av::AttackVector::Network);
     assert_eq!(cvss.ac.unwrap(), cvss::v3::base::ac::AttackComplexity::Low);
     assert_eq!(
@@ -93,6 +96,46 @@ fn parse_cvss_vector_string() {
     assert_eq!(cvss.score().value(), 10.0);
 }
 
+/// Parsing of CVSS v4.0 severity vector strings
+#[test]
+fn parse_cvss_v4_vector_string() {
+    let cvss_v4 = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N";
+    let advisory_data = std::fs::read_to_string("./tests/support/example_advisory_v4.md")
+        .unwrap()
+        .replace("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H", cvss_v4);
+
+    let advisory: rustsec::Advisory = advisory_data.parse().unwrap();
+    assert_eq!(
+        advisory.severity().unwrap(),
+        rustsec::advisory::Severity::Critical
+    );
+
+    let cvss = advisory.metadata.cvss.unwrap();
+    assert!(matches!(cvss, cvss::Cvss::V4(_)));
+    assert_eq!(cvss.to_string(), cvss_v4);
+    assert_eq!(cvss.score(), 9.3);
+}
+
+/// Parsing of CVSS v3.1 vector strings with Temporal and Environmental metrics
+#[test]
+fn parse_cvss_v3_temporal_vector_string() {
+    let cvss_v3 = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P/RL:O/RC:C";
+    let advisory_data = std::fs::read_to_string(EXAMPLE_V3_ADVISORY_PATH)
+        .unwrap()
+        .replace("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H", cvss_v3);
+
+    let advisory: rustsec::Advisory = advisory_data.parse().unwrap();
+    assert_eq!(
+        advisory.severity().unwrap(),
+        rustsec::advisory::Severity::High
+    );
+
+    let cvss = advisory.metadata.cvss.unwrap();
+    assert!(matches!(cvss, cvss::Cvss::V3(_)));
+    assert_eq!(cvss.to_string(), cvss_v3);
+    assert_eq!(cvss.score(), 8.8);
+}
+
 /// Parsing of patched version reqs
 #[test]
 fn parse_patched_version_reqs() {
@@ -0,0 +1,112 @@
+//! Tests for determining dependency kinds from `cargo metadata` output
+
+#![cfg(feature = "cargo-metadata")]
+#![warn(rust_2018_idioms, unused_qualifications)]
+
+use rustsec::{
+    cargo_metadata::{CargoMetadata, DependencyKind},
+    lockfile::Lockfile,
+    report, Database, ErrorKind, Report,
+};
+use std::path::Path;
+
+/// `cargo metadata` output for a project with normal, dev and build dependencies
+const METADATA_PATH: &str = "./tests/support/cargo_metadata.json";
+
+/// Lockfile for the same project
+const LOCKFILE_PATH: &str = "./tests/support/cargo_metadata.lock";
+
+/// Advisory database containing advisories for `vulnlib`, `testlib` and `helper`
+const DB_PATH: &str = "./tests/support/advisory-db";
+
+fn load_metadata() -> CargoMetadata {
+    CargoMetadata::load_file(METADATA_PATH).unwrap()
+}
+
+fn kinds(metadata: &CargoMetadata, name: &str) -> Vec<DependencyKind> {
+    metadata
+        .iter()
+        .find(|(package_name, _, _)| package_name.as_str() == name)
+        .map(|(_, _, info)| info.kinds.iter().cloned().collect())
+        .unwrap()
+}
+
+#[test]
+fn dependency_kinds() {
+    let metadata = load_metadata();
+
+    assert_eq!(kinds(&metadata, "app"), [DependencyKind::Normal]);
+    assert_eq!(kinds(&metadata, "vulnlib"), [DependencyKind::Normal]);
+    assert_eq!(kinds(&metadata, "testlib"), [DependencyKind::Dev]);
+    assert_eq!(kinds(&metadata, "buildlib"), [DependencyKind::Build]);
+    assert_eq!(kinds(&metadata, "helper"), [DependencyKind::Build]);
+    assert_eq!(
+        kinds(&metadata, "sharedlib"),
+        [DependencyKind::Normal, DependencyKind::Dev]
+    );
+}
+
+#[test]
+fn package_features() {
+    let metadata = load_metadata();
+    let lockfile = Lockfile::load(LOCKFILE_PATH).unwrap();
+    let vulnlib = lockfile
+        .packages
+        .iter()
+        .find(|package| package.name.as_str() == "vulnlib")
+        .unwrap();
+
+    let info = metadata.package(vulnlib).unwrap();
+    assert!(!info.is_dev_only());
+    assert_eq!(
+        info.features.iter().map(String::as_str).collect::<Vec<_>>(),
+        ["default", "std"]
+    );
+}
+
+#[test]
+fn missing_resolve() {
+    let err = r#"{"packages": [], "workspace_members": [], "resolve": null}"#
+        .parse::<CargoMetadata>()
+        .unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::Parse);
+}
+
+#[test]
+fn exclude_dependency_kinds() {
+    let db = Database::open(Path::new(DB_PATH)).unwrap();
+    let lockfile = Lockfile::load(LOCKFILE_PATH).unwrap();
+    let metadata = load_metadata();
+
+    let vulnerable_packages = |settings: &report::Settings| {
+        let report = Report::generate_with_metadata(&db, &lockfile, &metadata, settings);
+        let mut names: Vec<_> = report
+            .vulnerabilities
+            .list
+            .iter()
+            .map(|vuln| vuln.package.name.as_str().to_owned())
+            .collect();
+
+        names.sort();
+        names
+    };
+
+    assert_eq!(
+        vulnerable_packages(&report::Settings::default()),
+        ["helper", "testlib", "vulnlib"]
+    );
+
+    let settings = report::Settings {
+        exclude_dev: true,
+        ..Default::default()
+    };
+    assert_eq!(vulnerable_packages(&settings), ["helper", "vulnlib"]);
+
+    let settings = report::Settings {
+        exclude_dev: true,
+        exclude_build: true,
+        ..Default::default()
+    };
+    assert_eq!(vulnerable_packages(&settings), ["vulnlib"]);
+}
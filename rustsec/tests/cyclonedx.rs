@@ -0,0 +1,121 @@
+//! Tests for generating CycloneDX SBOMs
+
+#![warn(rust_2018_idioms, unused_qualifications)]
+
+use rustsec::{cyclonedx::Bom, database::scope, lockfile::Lockfile, report, Database, Report};
+use serde_json::{json, Value};
+use std::path::Path;
+
+/// Advisory database containing advisories for `dup` and `std`
+const DB_PATH: &str = "./tests/support/advisory-db";
+
+/// Lockfile containing a path dependency and a crates.io package with the
+/// same name and version
+const LOCKFILE: &str = r#"version = 3
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = [
+ "dup 1.0.0",
+ "dup 1.0.0 (registry+https://github.com/rust-lang/crates.io-index)",
+]
+
+[[package]]
+name = "dup"
+version = "1.0.0"
+
+[[package]]
+name = "dup"
+version = "1.0.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "904dfeac50f3cdaba28fc6f57fdcddb75f49ed61346676a78c4ffe55877802fd"
+"#;
+
+fn generate_bom() -> Value {
+    let db = Database::open(Path::new(DB_PATH)).unwrap();
+    let lockfile: Lockfile = LOCKFILE.parse().unwrap();
+
+    let settings = report::Settings {
+        package_scope: Some(scope::Registry::All.into()),
+        rust_version: Some("1.52.0".parse().unwrap()),
+        ..Default::default()
+    };
+
+    let report = Report::generate(&db, &lockfile, &settings);
+    serde_json::to_value(Bom::new(&lockfile, &report)).unwrap()
+}
+
+fn refs(values: &Value, key: &str) -> Vec<Value> {
+    values
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|value| value[key].clone())
+        .collect()
+}
+
+#[test]
+fn components() {
+    let bom = generate_bom();
+
+    assert_eq!(bom["bomFormat"], "CycloneDX");
+    assert_eq!(bom["specVersion"], "1.4");
+
+    assert_eq!(
+        refs(&bom["components"], "bom-ref"),
+        [
+            json!("pkg:cargo/app@0.1.0?local=true"),
+            json!("pkg:cargo/dup@1.0.0?local=true"),
+            json!("pkg:cargo/dup@1.0.0"),
+            json!("pkg:generic/rust-lang/std@1.52.0"),
+        ]
+    );
+
+    let components = bom["components"].as_array().unwrap();
+    assert_eq!(components[2]["hashes"][0]["alg"], "SHA-256");
+    assert!(components[1].get("hashes").is_none());
+    assert_eq!(components[3]["type"], "framework");
+}
+
+#[test]
+fn dependencies() {
+    let bom = generate_bom();
+
+    assert_eq!(
+        bom["dependencies"][0]["dependsOn"],
+        json!(["pkg:cargo/dup@1.0.0?local=true", "pkg:cargo/dup@1.0.0"])
+    );
+}
+
+#[test]
+fn vulnerability_affects() {
+    let bom = generate_bom();
+    let vulnerabilities = &bom["vulnerabilities"];
+
+    assert_eq!(
+        refs(vulnerabilities, "id"),
+        [json!("RUSTSEC-2021-0004"), json!("RUSTSEC-2021-0005")]
+    );
+
+    assert_eq!(
+        refs(&vulnerabilities[0]["affects"], "ref"),
+        [
+            json!("pkg:cargo/dup@1.0.0?local=true"),
+            json!("pkg:cargo/dup@1.0.0"),
+        ]
+    );
+    assert_eq!(
+        refs(&vulnerabilities[1]["affects"], "ref"),
+        [json!("pkg:generic/rust-lang/std@1.52.0")]
+    );
+
+    // Every affected component is described by the BOM
+    let components = refs(&bom["components"], "bom-ref");
+
+    for vulnerability in vulnerabilities.as_array().unwrap() {
+        for bom_ref in refs(&vulnerability["affects"], "ref") {
+            assert!(components.contains(&bom_ref), "{}", bom_ref);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Tests for comparing the reports for two lockfiles
+
+#![warn(rust_2018_idioms, unused_qualifications)]
+
+use rustsec::{
+    lockfile::Lockfile,
+    report::{self, Diff},
+    Database,
+};
+use std::path::Path;
+
+/// Lockfile containing `vulnlib` 1.0.0, `testlib` 0.2.0 and `helper` 0.5.0
+const LOCKFILE_PATH: &str = "./tests/support/cargo_metadata.lock";
+
+/// Advisory database containing advisories for `vulnlib`, `testlib` and `helper`
+const DB_PATH: &str = "./tests/support/advisory-db";
+
+fn load_db() -> Database {
+    Database::open(Path::new(DB_PATH)).unwrap()
+}
+
+/// Load the lockfile without the given package
+fn lockfile_without(name: &str) -> Lockfile {
+    let mut lockfile = Lockfile::load(LOCKFILE_PATH).unwrap();
+    lockfile
+        .packages
+        .retain(|package| package.name.as_str() != name);
+    lockfile
+}
+
+fn vulnerable_packages(findings: &report::Findings) -> Vec<&str> {
+    findings
+        .vulnerabilities
+        .iter()
+        .map(|vuln| vuln.package.name.as_str())
+        .collect()
+}
+
+#[test]
+fn diff_lockfiles() {
+    let db = load_db();
+
+    let old_lockfile = lockfile_without("helper");
+    let new_lockfile = lockfile_without("testlib");
+
+    let diff = Diff::generate(&db, &old_lockfile, &new_lockfile, &Default::default());
+
+    assert_eq!(vulnerable_packages(&diff.added), ["helper"]);
+    assert_eq!(vulnerable_packages(&diff.removed), ["testlib"]);
+    assert_eq!(vulnerable_packages(&diff.unchanged), ["vulnlib"]);
+    assert_eq!(
+        diff.added.vulnerabilities[0].advisory.id.as_str(),
+        "RUSTSEC-2021-0003"
+    );
+
+    let added_packages: Vec<_> = diff
+        .added_packages
+        .iter()
+        .map(|package| package.name.as_str())
+        .collect();
+    assert_eq!(added_packages, ["helper"]);
+
+    let removed_packages: Vec<_> = diff
+        .removed_packages
+        .iter()
+        .map(|package| package.name.as_str())
+        .collect();
+    assert_eq!(removed_packages, ["testlib"]);
+
+    assert!(diff.is_added_package(&diff.added.vulnerabilities[0].package));
+    assert!(!diff.is_added_package(&diff.unchanged.vulnerabilities[0].package));
+}
+
+#[test]
+fn diff_identical_lockfiles() {
+    let db = load_db();
+    let lockfile = Lockfile::load(LOCKFILE_PATH).unwrap();
+
+    let diff = Diff::generate(&db, &lockfile, &lockfile, &Default::default());
+
+    assert!(diff.added.is_empty());
+    assert!(diff.removed.is_empty());
+    assert_eq!(
+        vulnerable_packages(&diff.unchanged),
+        ["helper", "testlib", "vulnlib"]
+    );
+    assert!(diff.added_packages.is_empty());
+    assert!(diff.removed_packages.is_empty());
+}
@@ -0,0 +1,176 @@
+//! Tests for fixing vulnerable dependencies in workspace manifests
+
+#![cfg(feature = "fix")]
+#![warn(rust_2018_idioms, unused_qualifications)]
+
+use rustsec::{lockfile::Lockfile, registry, Advisory, Fixer, Vulnerability};
+use std::{fs, path::Path};
+
+/// Workspace whose root declares `base64` in `[workspace.dependencies]`,
+/// which `member-a` and `member-b` inherit, and whose members both depend on
+/// `smallvec` directly
+const WORKSPACE_PATH: &str = "./tests/support/fix_workspace";
+
+const LOCKFILE: &str = r#"version = 3
+
+[[package]]
+name = "base64"
+version = "0.13.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "smallvec"
+version = "1.6.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+"#;
+
+/// Copy the fixture workspace into the given directory
+fn copy_workspace(src: &Path, dst: &Path) {
+    fs::create_dir_all(dst).unwrap();
+
+    for entry in fs::read_dir(src).unwrap() {
+        let path = entry.unwrap().path();
+        let target = dst.join(path.file_name().unwrap());
+
+        if path.is_dir() {
+            copy_workspace(&path, &target);
+        } else {
+            fs::copy(&path, &target).unwrap();
+        }
+    }
+}
+
+/// Create a crates.io index with releases of `base64` and `smallvec`,
+/// including some whose versions aren't valid semver (which are skipped)
+fn create_index(dir: &Path) -> registry::Index {
+    let release = |name: &str, version: &str| {
+        format!(
+            r#"{{"name":"{}","vers":"{}","deps":[],"cksum":"{}","features":{{}},"yanked":false}}"#,
+            name,
+            version,
+            "00".repeat(32)
+        )
+    };
+
+    for (path, name, versions) in &[
+        (
+            "ba/se/base64",
+            "base64",
+            ["0.13", "0.13.0", "0.13.1", "0.20.0"],
+        ),
+        (
+            "sm/al/smallvec",
+            "smallvec",
+            ["1.6.0", "1.6", "1.6.1", "2.0.0"],
+        ),
+    ] {
+        let path = dir.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+
+        let releases: Vec<_> = versions.iter().map(|v| release(name, v)).collect();
+        fs::write(path, releases.join("\n")).unwrap();
+    }
+
+    registry::Index::open_path(dir).unwrap()
+}
+
+fn vulnerability(name: &str, patched: &str) -> Vulnerability {
+    let lockfile: Lockfile = LOCKFILE.parse().unwrap();
+    let package = lockfile
+        .packages
+        .iter()
+        .find(|pkg| pkg.name.as_str() == name)
+        .unwrap();
+
+    let advisory: Advisory = format!(
+        "```toml\n[advisory]\nid = \"RUSTSEC-2021-0001\"\npackage = \"{}\"\n\
+         date = \"2021-01-01\"\n\n[versions]\npatched = [\"{}\"]\n```\n\n# Example\n",
+        name, patched
+    )
+    .parse()
+    .unwrap();
+
+    Vulnerability::new(&advisory, package)
+}
+
+fn read(path: &Path) -> String {
+    fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn fix_workspace_dependency() {
+    let dir = tempfile::tempdir().unwrap();
+    let workspace = dir.path().join("workspace");
+    copy_workspace(Path::new(WORKSPACE_PATH), &workspace);
+
+    let index_dir = tempfile::tempdir().unwrap();
+    let mut fixer =
+        Fixer::new(workspace.join("Cargo.toml"), create_index(index_dir.path())).unwrap();
+
+    let fix = fixer
+        .fix(&vulnerability("base64", ">= 0.13.1"), false)
+        .unwrap();
+
+    assert_eq!(fix.version.to_string(), "0.13.1");
+    assert_eq!(fix.manifests.len(), 1);
+    assert!(fix.manifests[0].ends_with("workspace/Cargo.toml"));
+
+    assert!(read(&workspace.join("Cargo.toml")).contains(r#"base64 = "0.13.1""#));
+
+    // Members inheriting the dependency are left unchanged
+    for member in &["member-a", "member-b"] {
+        let manifest = read(&workspace.join(member).join("Cargo.toml"));
+        assert!(manifest.contains("base64 = { workspace = true }"));
+    }
+}
+
+#[test]
+fn fix_member_dependencies() {
+    let dir = tempfile::tempdir().unwrap();
+    let workspace = dir.path().join("workspace");
+    copy_workspace(Path::new(WORKSPACE_PATH), &workspace);
+
+    let index_dir = tempfile::tempdir().unwrap();
+    let mut fixer =
+        Fixer::new(workspace.join("Cargo.toml"), create_index(index_dir.path())).unwrap();
+
+    let fix = fixer
+        .fix(&vulnerability("smallvec", ">= 1.6.1"), false)
+        .unwrap();
+
+    assert_eq!(fix.version.to_string(), "1.6.1");
+    assert_eq!(fix.manifests.len(), 2);
+
+    // Including target-specific dev-dependencies
+    for member in &["member-a", "member-b"] {
+        let manifest = read(&workspace.join(member).join("Cargo.toml"));
+        assert!(manifest.contains(r#"smallvec = "1.6.1""#), "{}", manifest);
+    }
+}
+
+#[test]
+fn fix_dry_run() {
+    let dir = tempfile::tempdir().unwrap();
+    let workspace = dir.path().join("workspace");
+    copy_workspace(Path::new(WORKSPACE_PATH), &workspace);
+
+    let index_dir = tempfile::tempdir().unwrap();
+    let mut fixer =
+        Fixer::new(workspace.join("Cargo.toml"), create_index(index_dir.path())).unwrap();
+
+    fixer
+        .fix(&vulnerability("base64", ">= 0.13.1"), true)
+        .unwrap();
+    fixer
+        .fix(&vulnerability("smallvec", ">= 1.6.1"), true)
+        .unwrap();
+
+    assert_eq!(
+        read(&workspace.join("Cargo.toml")),
+        read(&Path::new(WORKSPACE_PATH).join("Cargo.toml"))
+    );
+    assert_eq!(
+        read(&workspace.join("member-a/Cargo.toml")),
+        read(&Path::new(WORKSPACE_PATH).join("member-a/Cargo.toml"))
+    );
+}
@@ -0,0 +1,123 @@
+//! Tests for ignoring advisories when generating reports
+
+#![warn(rust_2018_idioms, unused_qualifications)]
+
+use rustsec::{
+    lockfile::Lockfile,
+    report::{self, IgnoreRule},
+    Database, Report,
+};
+use std::path::Path;
+
+/// Lockfile containing `vulnlib` 1.0.0, `testlib` 0.2.0 and `helper` 0.5.0
+const LOCKFILE_PATH: &str = "./tests/support/cargo_metadata.lock";
+
+/// Advisory database containing advisories for `vulnlib`, `testlib` and `helper`
+const DB_PATH: &str = "./tests/support/advisory-db";
+
+fn load_db() -> Database {
+    Database::open(Path::new(DB_PATH)).unwrap()
+}
+
+fn rule(id: &str) -> IgnoreRule {
+    IgnoreRule::new(id.parse().unwrap())
+}
+
+#[test]
+fn ignore_rules() {
+    let db = load_db();
+    let lockfile = Lockfile::load(LOCKFILE_PATH).unwrap();
+
+    let settings = report::Settings {
+        ignore: vec!["RUSTSEC-2021-0001".parse().unwrap()],
+        ignore_rules: vec![
+            IgnoreRule {
+                reason: Some("only used in tests".to_owned()),
+                expires: Some("2099-12-31".parse().unwrap()),
+                ..rule("RUSTSEC-2021-0002")
+            },
+            IgnoreRule {
+                expires: Some("2021-06-30".parse().unwrap()),
+                ..rule("RUSTSEC-2021-0003")
+            },
+        ],
+        ..Default::default()
+    };
+
+    let report = Report::generate(&db, &lockfile, &settings);
+
+    let vulnerable: Vec<_> = report
+        .vulnerabilities
+        .list
+        .iter()
+        .map(|vuln| vuln.package.name.as_str())
+        .collect();
+    assert_eq!(vulnerable, ["helper"]);
+
+    let mut ignored: Vec<_> = report
+        .ignored
+        .iter()
+        .map(|ignored| {
+            (
+                ignored.id.as_str(),
+                ignored.package.as_str(),
+                ignored.reason.as_deref(),
+            )
+        })
+        .collect();
+    ignored.sort();
+    assert_eq!(
+        ignored,
+        [
+            ("RUSTSEC-2021-0001", "vulnlib", None),
+            ("RUSTSEC-2021-0002", "testlib", Some("only used in tests")),
+        ]
+    );
+
+    assert_eq!(report.expired_ignores, [settings.ignore_rules[1].clone()]);
+}
+
+#[test]
+fn ignore_rule_package_scope() {
+    let db = load_db();
+    let lockfile = Lockfile::load(LOCKFILE_PATH).unwrap();
+
+    // Number of vulnerabilities found in `vulnlib`
+    let vulnerable_count = |rule: IgnoreRule| {
+        let settings = report::Settings {
+            ignore_rules: vec![rule],
+            ..Default::default()
+        };
+
+        Report::generate(&db, &lockfile, &settings)
+            .vulnerabilities
+            .list
+            .iter()
+            .filter(|vuln| vuln.package.name.as_str() == "vulnlib")
+            .count()
+    };
+
+    assert_eq!(
+        vulnerable_count(IgnoreRule {
+            package: Some("vulnlib".parse().unwrap()),
+            version: Some("< 2".parse().unwrap()),
+            ..rule("RUSTSEC-2021-0001")
+        }),
+        0
+    );
+    assert_eq!(
+        vulnerable_count(IgnoreRule {
+            package: Some("vulnlib".parse().unwrap()),
+            version: Some(">= 2".parse().unwrap()),
+            ..rule("RUSTSEC-2021-0001")
+        }),
+        1
+    );
+    assert_eq!(
+        vulnerable_count(IgnoreRule {
+            package: Some("otherlib".parse().unwrap()),
+            ..rule("RUSTSEC-2021-0001")
+        }),
+        1
+    );
+}
@@ -99,3 +99,30 @@ fn invalid_example() {
     let invalid_section = lint.errors()[6].to_string();
     assert_eq!(invalid_section, "invalid key `invalid-section` in toplevel");
 }
+
+/// Advisory for a crate in a private registry
+#[test]
+fn private_registry_source() {
+    let advisory = include_str!("./support/example_advisory_v3.md");
+
+    let private = advisory.replacen(
+        "package = \"base\"",
+        "package = \"base\"\nsource = \"registry+https://my-registry.example.com/index\"",
+        1,
+    );
+    let lint = rustsec::advisory::Linter::lint_string(&private).unwrap();
+    assert_eq!(lint.errors(), &[]);
+    assert!(lint.advisory().metadata.source.is_some());
+
+    let crates_io = advisory.replacen(
+        "package = \"base\"",
+        "package = \"base\"\nsource = \"registry+https://github.com/rust-lang/crates.io-index\"",
+        1,
+    );
+    let lint = rustsec::advisory::Linter::lint_string(&crates_io).unwrap();
+    assert_eq!(lint.errors().len(), 1);
+    assert_eq!(
+        lint.errors()[0].to_string(),
+        "invalid value `\"registry+https://github.com/rust-lang/crates.io-index\"` for key `source` in [advisory]: source shouldn't be explicit for crates.io"
+    );
+}
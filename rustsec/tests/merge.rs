@@ -0,0 +1,50 @@
+//! Tests for merging advisory databases
+
+#![cfg(feature = "git")]
+#![warn(rust_2018_idioms, unused_qualifications)]
+
+use rustsec::{report::DatabaseInfo, Database};
+use std::path::Path;
+
+/// Databases to merge, which both contain `RUSTSEC-2001-2101`
+const MERGE_PATH: &str = "./tests/support/merge";
+
+fn open(name: &str) -> Database {
+    Database::open(&Path::new(MERGE_PATH).join(name)).unwrap()
+}
+
+/// Advisories with an ID already in the database are skipped
+#[test]
+fn merge_skips_duplicate_ids() {
+    let mut db = Database::default();
+    assert!(db.merge("primary", open("primary")).is_empty());
+
+    let skipped = db.merge("secondary", open("secondary"));
+    let skipped: Vec<_> = skipped.iter().map(|id| id.as_str()).collect();
+    assert_eq!(skipped, ["RUSTSEC-2001-2101"]);
+
+    let ids: Vec<_> = db.iter().map(|advisory| advisory.id().as_str()).collect();
+    assert_eq!(ids, ["RUSTSEC-2001-2101", "RUSTSEC-2001-2102"]);
+
+    // The advisory from the first database takes precedence
+    let advisory = db.get(&"RUSTSEC-2001-2101".parse().unwrap()).unwrap();
+    assert!(advisory.description().starts_with("You have no chance"));
+}
+
+/// Each merged database is reported as a source
+#[test]
+fn merge_reports_sources() {
+    let mut db = Database::default();
+    db.merge("primary", open("primary"));
+    db.merge("secondary", open("secondary"));
+
+    let info = DatabaseInfo::new(&db);
+    assert_eq!(info.advisory_count, 2);
+
+    let sources: Vec<_> = info
+        .sources
+        .iter()
+        .map(|source| (source.name.as_str(), source.advisory_count))
+        .collect();
+    assert_eq!(sources, [("primary", 1), ("secondary", 1)]);
+}
@@ -0,0 +1,173 @@
+//! Tests for importing advisories in the OSV format
+
+#![cfg(feature = "osv-import")]
+#![warn(rust_2018_idioms, unused_qualifications)]
+
+use rustsec::{
+    advisory::{Category, Severity},
+    database::Query,
+    osv::OsvAdvisory,
+    Collection, Database,
+};
+use semver::Version;
+use std::path::Path;
+
+/// Directory containing OSV advisories to use for tests
+const OSV_DB_PATH: &str = "./tests/support/osv";
+
+fn load_db() -> Database {
+    Database::open_osv(Path::new(OSV_DB_PATH)).unwrap().0
+}
+
+fn version(v: &str) -> Version {
+    Version::parse(v).unwrap()
+}
+
+/// Advisories for other ecosystems are skipped
+#[test]
+fn open_osv_database() {
+    let ids: Vec<_> = load_db()
+        .iter()
+        .map(|advisory| advisory.id().as_str().to_owned())
+        .collect();
+
+    assert_eq!(
+        ids,
+        [
+            "RUSTSEC-2019-0001",
+            "GHSA-5wg8-7c9q-794v",
+            "GHSA-2p3q-8r4v-9w6x"
+        ]
+    );
+}
+
+/// Advisories affecting more than one crate, or with crates.io versions which
+/// aren't valid semver, are skipped and reported
+#[test]
+fn skip_multiple_crates() {
+    let (db, skipped) = Database::open_osv(Path::new(OSV_DB_PATH)).unwrap();
+    assert_eq!(db.iter().count(), 3);
+
+    let skipped: Vec<_> = skipped.iter().map(|id| id.as_str()).collect();
+    assert_eq!(skipped, ["GHSA-4h6j-8m9p-3q5r", "GHSA-7rf8-7q7v-5w2m"]);
+
+    let osv = OsvAdvisory::load_file(Path::new(OSV_DB_PATH).join("multi/GHSA-7rf8-7q7v-5w2m.json"))
+        .unwrap();
+    assert!(osv.affects_crates_io());
+    assert!(osv.affects_multiple_crates());
+    assert!(osv.into_rustsec().is_err());
+}
+
+/// GitHub-style advisory with `ECOSYSTEM` ranges spread over multiple entries
+#[test]
+fn convert_github_advisory() {
+    let db = load_db();
+    let advisory = db.get(&"GHSA-5wg8-7c9q-794v".parse().unwrap()).unwrap();
+
+    assert_eq!(advisory.metadata.package.as_str(), "tokio");
+    assert_eq!(advisory.metadata.collection, Some(Collection::Crates));
+    assert_eq!(advisory.title(), "Data race in tokio");
+    assert_eq!(advisory.date().as_str(), "2022-06-17");
+    assert_eq!(advisory.metadata.aliases[0].as_str(), "CVE-2021-45710");
+    assert_eq!(advisory.severity(), Some(Severity::Medium));
+    assert_eq!(
+        advisory.metadata.url.as_ref().unwrap().as_str(),
+        "https://nvd.nist.gov/vuln/detail/CVE-2021-45710"
+    );
+    assert_eq!(advisory.metadata.references.len(), 1);
+
+    for (v, vulnerable) in &[
+        ("0.1.0", true),
+        ("1.8.3", true),
+        ("1.8.4", false),
+        ("1.9.0", true),
+        ("1.13.0", true),
+        ("1.13.1", false),
+    ] {
+        assert_eq!(
+            advisory.versions.is_vulnerable(&version(v)),
+            *vulnerable,
+            "{}",
+            v
+        );
+    }
+
+    let query = Query::crate_scope().package_version(
+        "tokio".parse::<rustsec::package::Name>().unwrap(),
+        version("1.10.0"),
+    );
+    assert_eq!(db.query(&query).len(), 1);
+}
+
+/// Versions of packages in other ecosystems (and commit hashes in `GIT`
+/// ranges) aren't parsed as semver
+#[test]
+fn convert_mixed_ecosystem_advisory() {
+    let osv = OsvAdvisory::load_file(Path::new(OSV_DB_PATH).join("pypi/GHSA-2p3q-8r4v-9w6x.json"))
+        .unwrap();
+    assert!(osv.affects_crates_io());
+    assert!(!osv.affects_multiple_crates());
+
+    let advisory = osv.into_rustsec().unwrap();
+    assert_eq!(advisory.metadata.package.as_str(), "example-bindings");
+    assert_eq!(advisory.versions.patched()[0].to_string(), ">=0.3.0");
+}
+
+/// Advisory exported from the RustSec database
+#[test]
+fn convert_rustsec_advisory() {
+    let osv =
+        OsvAdvisory::load_file(Path::new(OSV_DB_PATH).join("RUSTSEC-2019-0001.json")).unwrap();
+    assert_eq!(osv.id().as_str(), "RUSTSEC-2019-0001");
+
+    let advisory = osv.into_rustsec().unwrap();
+    assert_eq!(advisory.metadata.package.as_str(), "ammonia");
+    assert_eq!(advisory.metadata.categories, [Category::DenialOfService]);
+    assert_eq!(
+        advisory.metadata.url.as_ref().unwrap().as_str(),
+        "https://github.com/rust-ammonia/ammonia/blob/master/CHANGELOG.md#210"
+    );
+    assert!(advisory.metadata.references.is_empty());
+    assert_eq!(advisory.versions.patched()[0].to_string(), ">=2.1.0");
+    assert!(advisory.versions.unaffected().is_empty());
+
+    let functions: Vec<_> = advisory
+        .affected
+        .unwrap()
+        .functions
+        .keys()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(functions, ["ammonia::clean"]);
+}
+
+/// Advisories which don't affect crates.io can't be converted
+#[test]
+fn reject_other_ecosystems() {
+    let osv = OsvAdvisory::load_file(Path::new(OSV_DB_PATH).join("npm/GHSA-c2qf-rxjj-qqgw.json"))
+        .unwrap();
+
+    assert!(!osv.affects_crates_io());
+    assert!(osv.into_rustsec().is_err());
+}
+
+/// Advisories with colliding IDs are skipped when merging databases
+#[test]
+fn merge_databases() {
+    let mut db = Database::default();
+    assert!(db.merge("osv", load_db()).is_empty());
+
+    let (github_db, _) = Database::open_osv(&Path::new(OSV_DB_PATH).join("github")).unwrap();
+    let skipped = db.merge("github", github_db);
+    assert_eq!(skipped.len(), 1);
+    assert_eq!(skipped[0].as_str(), "GHSA-5wg8-7c9q-794v");
+
+    assert_eq!(db.iter().count(), 3);
+
+    let sources: Vec<_> = db
+        .sources()
+        .iter()
+        .map(|source| (source.name.as_str(), source.advisory_count))
+        .collect();
+    assert_eq!(sources, [("osv", 3), ("github", 0)]);
+}
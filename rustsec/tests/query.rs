@@ -2,7 +2,7 @@
 
 #![warn(rust_2018_idioms, unused_qualifications)]
 
-use rustsec::{advisory::Severity, database::Query, package};
+use rustsec::{advisory::Severity, database::Query, package, platforms::Platform, report};
 
 /// Load example advisory from the filesystem
 fn load_advisory() -> rustsec::Advisory {
@@ -40,3 +40,58 @@ fn matches_severity() {
     let query_matches = Query::new().severity(Severity::Critical);
     assert!(query_matches.matches(&advisory));
 }
+
+#[test]
+fn matches_source() {
+    let crates_io: package::SourceId = "registry+https://github.com/rust-lang/crates.io-index"
+        .parse()
+        .unwrap();
+    let private: package::SourceId = "registry+https://my-registry.example.com/index"
+        .parse()
+        .unwrap();
+    let git: package::SourceId = "git+https://github.com/example/base#0123456789"
+        .parse()
+        .unwrap();
+
+    let mut advisory = load_advisory();
+    assert!(Query::new()
+        .package_source(crates_io.clone())
+        .matches(&advisory));
+    assert!(Query::new().package_source(git.clone()).matches(&advisory));
+    assert!(!Query::new()
+        .package_source(private.clone())
+        .matches(&advisory));
+
+    advisory.metadata.source = Some(private.clone());
+    assert!(Query::new().package_source(private).matches(&advisory));
+    assert!(!Query::new().package_source(crates_io).matches(&advisory));
+    assert!(!Query::new().package_source(git).matches(&advisory));
+}
+
+#[test]
+fn matches_target_platform() {
+    let advisory = load_advisory();
+    let windows = Platform::find("i686-pc-windows-msvc").unwrap();
+    let linux = Platform::find("x86_64-unknown-linux-gnu").unwrap();
+
+    assert!(Query::new().target_platform(windows).matches(&advisory));
+    assert!(!Query::new().target_platform(linux).matches(&advisory));
+    assert!(Query::new()
+        .target_platform(linux)
+        .target_platform(windows)
+        .matches(&advisory));
+}
+
+#[test]
+fn report_target_platforms() {
+    let settings = report::Settings {
+        target_platforms: vec!["*-pc-windows-msvc".parse().unwrap()],
+        ..Default::default()
+    };
+
+    let platforms = settings.target_platforms();
+    assert!(platforms.len() > 1);
+    assert!(platforms
+        .iter()
+        .all(|platform| platform.target_triple.ends_with("-pc-windows-msvc")));
+}
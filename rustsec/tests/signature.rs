@@ -0,0 +1,132 @@
+//! OpenPGP commit signature verification tests
+
+#![cfg(feature = "signatures")]
+#![warn(rust_2018_idioms, unused_qualifications)]
+
+use rustsec::{
+    repository::signature::{KeyId, Keyring, Signature},
+    ErrorKind,
+};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Keyring containing the RSA and Ed25519 test keys
+const KEYRING_PATH: &str = "./tests/support/signature/keyring.asc";
+
+/// Data signed by the test signatures (an unsigned git commit object)
+const SIGNED_DATA: &[u8] = include_bytes!("support/signature/signed_data");
+
+/// Signature by the RSA test key
+const RSA_SIGNATURE: &[u8] = include_bytes!("support/signature/rsa.asc");
+
+/// Signature by the Ed25519 test key
+const ED25519_SIGNATURE: &[u8] = include_bytes!("support/signature/ed25519.asc");
+
+/// Signature by a key which isn't in the test keyring
+const UNTRUSTED_SIGNATURE: &[u8] = include_bytes!("support/signature/untrusted.asc");
+
+/// Keyring containing the Ed25519 key which created the signatures below
+const EXPIRY_KEYRING_PATH: &str = "./tests/support/signature/expiry_key.asc";
+
+/// Signature created on 2020-01-01 which expired a day later
+const EXPIRED_SIGNATURE: &[u8] = include_bytes!("support/signature/expired.asc");
+
+/// Signature which expires on 2099-12-31
+const EXPIRING_SIGNATURE: &[u8] = include_bytes!("support/signature/expiring.asc");
+
+/// Signature with a critical notation subpacket
+const CRITICAL_SIGNATURE: &[u8] = include_bytes!("support/signature/critical.asc");
+
+fn keyring() -> Keyring {
+    Keyring::load_file(KEYRING_PATH).unwrap()
+}
+
+#[test]
+fn load_keyring() {
+    let key_ids: Vec<_> = keyring().keys().map(|key| key.key_id()).collect();
+    assert_eq!(
+        key_ids,
+        [KeyId(0xEB75_947C_4A19_9E9C), KeyId(0x9C0B_5766_4B21_21DB)]
+    );
+}
+
+#[test]
+fn verify_rsa_signature() {
+    let signature = Signature::from_bytes(RSA_SIGNATURE).unwrap();
+    assert_eq!(signature.issuer(), Some(KeyId(0xEB75_947C_4A19_9E9C)));
+    assert_eq!(
+        signature.verify(SIGNED_DATA, &keyring()).unwrap(),
+        KeyId(0xEB75_947C_4A19_9E9C)
+    );
+}
+
+#[test]
+fn verify_ed25519_signature() {
+    let signature = Signature::from_bytes(ED25519_SIGNATURE).unwrap();
+    assert_eq!(
+        signature.verify(SIGNED_DATA, &keyring()).unwrap(),
+        KeyId(0x9C0B_5766_4B21_21DB)
+    );
+}
+
+#[test]
+fn reject_tampered_data() {
+    let mut tampered = SIGNED_DATA.to_vec();
+    tampered.extend_from_slice(b"evil\n");
+
+    for signature in &[RSA_SIGNATURE, ED25519_SIGNATURE] {
+        let signature = Signature::from_bytes(signature).unwrap();
+        let err = signature.verify(&tampered, &keyring()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Signature);
+    }
+}
+
+#[test]
+fn reject_untrusted_key() {
+    let signature = Signature::from_bytes(UNTRUSTED_SIGNATURE).unwrap();
+    let err = signature.verify(SIGNED_DATA, &keyring()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Signature);
+}
+
+#[test]
+fn reject_malformed_signature() {
+    let err = Signature::from_bytes(b"-----BEGIN PGP SIGNATURE-----\n\nAAAA\n").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Parse);
+}
+
+#[test]
+fn reject_unknown_critical_subpacket() {
+    let err = Signature::from_bytes(CRITICAL_SIGNATURE).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Parse);
+}
+
+#[test]
+fn reject_expired_signature() {
+    let signature = Signature::from_bytes(EXPIRED_SIGNATURE).unwrap();
+    assert_eq!(
+        signature.expires(),
+        Some(UNIX_EPOCH + Duration::from_secs(1_577_836_800 + 86_400))
+    );
+
+    let keyring = Keyring::load_file(EXPIRY_KEYRING_PATH).unwrap();
+    let err = signature.verify(SIGNED_DATA, &keyring).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Signature);
+    assert!(err.to_string().contains("expired"), "{}", err);
+}
+
+#[test]
+fn verify_unexpired_signature() {
+    let signature = Signature::from_bytes(EXPIRING_SIGNATURE).unwrap();
+    assert!(signature.expires().unwrap() > SystemTime::now());
+
+    let keyring = Keyring::load_file(EXPIRY_KEYRING_PATH).unwrap();
+    assert_eq!(
+        signature.verify(SIGNED_DATA, &keyring).unwrap(),
+        KeyId(0x34DC_1D53_0D55_BECE)
+    );
+
+    // Signatures without an expiration time never expire
+    assert_eq!(
+        Signature::from_bytes(RSA_SIGNATURE).unwrap().expires(),
+        None
+    );
+}
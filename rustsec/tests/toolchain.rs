@@ -0,0 +1,44 @@
+//! Tests for auditing the Rust toolchain
+
+#![warn(rust_2018_idioms, unused_qualifications)]
+
+use rustsec::{lockfile::Lockfile, report, Collection, Database, Report, Version};
+use std::path::Path;
+
+/// Lockfile whose packages are unrelated to the toolchain
+const LOCKFILE_PATH: &str = "./tests/support/cargo_metadata.lock";
+
+/// Advisory database containing advisories for `std` (patched in 1.52.1) and
+/// `rustdoc` (patched in 1.40.0)
+const DB_PATH: &str = "./tests/support/advisory-db";
+
+#[test]
+fn toolchain_vulnerabilities() {
+    let db = Database::open(Path::new(DB_PATH)).unwrap();
+    let lockfile = Lockfile::load(LOCKFILE_PATH).unwrap();
+
+    // Vulnerabilities in the toolchain, rather than in crates
+    let toolchain_vulnerabilities = |settings: &report::Settings| {
+        Report::generate(&db, &lockfile, settings)
+            .vulnerabilities
+            .list
+            .into_iter()
+            .filter(|vuln| vuln.advisory.collection == Some(Collection::Rust))
+            .collect::<Vec<_>>()
+    };
+
+    assert!(toolchain_vulnerabilities(&report::Settings::default()).is_empty());
+
+    let settings = report::Settings {
+        rust_version: Some(Version::new(1, 52, 0)),
+        ..Default::default()
+    };
+
+    let vulnerabilities = toolchain_vulnerabilities(&settings);
+    assert_eq!(vulnerabilities.len(), 1);
+
+    let vuln = &vulnerabilities[0];
+    assert_eq!(vuln.advisory.id.as_str(), "RUSTSEC-2021-0005");
+    assert_eq!(vuln.package.name.as_str(), "std");
+    assert_eq!(vuln.package.version, Version::new(1, 52, 0));
+}